@@ -11,24 +11,31 @@ use crate::{
   },
   win::HWND,
   Result,
-  Error
+  Error,
+  OperationError
 };
 use super::{
+  IfcImportOptions,
   Project,
-  Version
+  ProjectType,
+  Selection,
+  UI,
+  Version,
+  View
 };
 
 const CLASS_NAME: &str = "Renga.Application.1";
 
 /// Represents entire Renga application.
 /// 
-/// Currently, you can obtain instance of this struct only by using [Application::new] or [Application::new_hidden] methods.
-/// Accessing running instances of Renga is still in development.
+/// Out-of-process instances are started with [Application::new] or [Application::new_hidden] methods.
+/// Plugins access Renga process which loaded them with [Application::host].
 /// 
 /// See [Official documentation](https://help.rengabim.com/api/interface_i_application.html)
 pub struct Application {
   locale: String,
   handle: Dispatch,
+  owned: bool,
   _com: ComRuntime
 }
 
@@ -44,7 +51,7 @@ impl Application {
   /// ```
   /// If your Renga distribution comes from official installer, you can skip this step - Renga will be automatically registered.
   pub fn new() -> Result<Self> {
-    let mut this = Self::init()?;
+    let mut this = Self::init(true)?;
     log::debug!("Renga Application initialized");
     this
       .set_enabled(true)?
@@ -58,7 +65,7 @@ impl Application {
   /// 
   /// See [Application::new]
   pub fn new_hidden() -> Result<Self> {
-    let mut this = Self::init()?;
+    let mut this = Self::init(true)?;
     log::debug!("Renga Application initialized (hidden)");
     this
       .set_enabled(true)?
@@ -66,6 +73,16 @@ impl Application {
     Ok(this)
   }
 
+  /// Connects to Renga application, which hosts current process.
+  /// 
+  /// Must be called only from plugins, see [crate::plugin]. Unlike [Application::new],
+  /// dropping returned instance neither closes the project nor quits Renga.
+  pub fn host() -> Result<Self> {
+    let this = Self::init(false)?;
+    log::debug!("Renga Application connected (host)");
+    Ok(this)
+  }
+
   /// Tries to close Renga application.
   ///
   /// See [Application::quit]
@@ -187,6 +204,89 @@ impl Application {
     Ok(project.unwrap())
   }
 
+  /// Creates new project from template file (`.rnt`).
+  /// 
+  /// Can return error in the following cases:
+  /// - [crate::Error::NonexistentPath]: Template path is invalid;
+  /// - [crate::Error::InvalidOperation]: File is not a Renga template;
+  /// - [crate::Error::Operation]: Renga failed to create project;
+  /// - [crate::Error::Internal]: Internal error happened;
+  /// 
+  /// See [Application::new_project]
+  pub fn new_project_from_template(&mut self, path: &Path) -> Result<Project> {
+    if !path.exists() {
+      return Err(Error::NonexistentPath(format!("Template path does not exist: {}", path.display())));
+    }
+    ProjectType::Template.validate_path(path)?;
+    let path = path.to_string_lossy().into_owned();
+    let code = self.handle.call("CreateProjectFromTemplate", Some(vec![path.into()]))?.as_int()?;
+    OperationError::check(code)?;
+    match self.get_project()? {
+      Some(project) => Ok(project),
+      None => Err(Error::Internal("Failed to create project from template".to_owned()))
+    }
+  }
+
+  /// Imports IFC file as a new project.
+  /// 
+  /// Currently opened project is closed before import. 
+  /// 
+  /// Can return error in the following cases:
+  /// - [crate::Error::NonexistentPath]: IFC file does not exist;
+  /// - [crate::Error::UnsavedChanges]: Opened project has unsaved changes and 
+  ///   [IfcImportOptions::discard_unsaved_changes] is not set;
+  /// - [crate::Error::Operation]: Renga failed to import file;
+  /// - [crate::Error::Internal]: Internal error happened;
+  pub fn import_ifc(&mut self, path: &Path, options: &IfcImportOptions) -> Result<Project> {
+    if !path.exists() {
+      return Err(Error::NonexistentPath(format!("IFC file does not exist: {}", path.display())));
+    }
+    if let Some(mut project) = self.project()? {
+      if project.has_unsaved_changes()? && !options.discard_unsaved_changes {
+        return Err(Error::UnsavedChanges);
+      }
+      project.close(true)?;
+    }
+    let path = path.to_string_lossy().into_owned();
+    let code = self.handle.call("ImportIfcProject", Some(vec![path.into()]))?.as_int()?;
+    OperationError::check(code)?;
+    match self.get_project()? {
+      Some(project) => Ok(project),
+      None => Err(Error::Internal("Failed to import IFC project".to_owned()))
+    }
+  }
+
+  /// Returns selection of model objects in the user interface.
+  /// 
+  /// Can return error in the following cases:
+  /// - [crate::Error::InvalidOperation]: No project is opened;
+  /// - [crate::Error::Internal]: Internal error happened;
+  pub fn selection(&mut self) -> Result<Selection> {
+    let objects = match self.project()? {
+      Some(project) => project.model()?.objects()?,
+      None => return Err(Error::InvalidOperation("No project is opened".to_owned()))
+    };
+    Selection::new(self.handle.get("Selection")?.into_dispatch()?, objects)
+  }
+
+  /// Returns currently active view (tab) of the user interface.
+  /// 
+  /// Can return error in the following cases:
+  /// - [crate::Error::InvalidOperation]: No project is opened;
+  /// - [crate::Error::Internal]: Internal error happened;
+  pub fn active_view(&mut self) -> Result<View> {
+    let objects = match self.project()? {
+      Some(project) => project.model()?.objects()?,
+      None => return Err(Error::InvalidOperation("No project is opened".to_owned()))
+    };
+    View::new(self.handle.get("ActiveView")?.into_dispatch()?, objects)
+  }
+
+  /// Returns user interface of the application, used to add actions, panels and context menus.
+  pub fn ui(&self) -> Result<UI> {
+    UI::new(self.handle.get("UI")?.into_dispatch()?)
+  }
+
   /// Returns native handle of the main window.
   pub fn native_window_handle(&self) -> Result<HWND> {
     let intptr = self.handle.call("GetMainWindowHandle", None)?.as_int()?;
@@ -201,7 +301,7 @@ impl Application {
   //   Ok(self.handle.call("HasProject", None)?.as_bool()?)
   // }
 
-  fn init() -> Result<Self> {
+  fn init(owned: bool) -> Result<Self> {
     let _com = ComRuntime::new()?;
     let handle = Dispatch::from_class_name(CLASS_NAME)?;
     Ok(Self {
@@ -211,6 +311,7 @@ impl Application {
         .into_string()
         .unwrap_or("C".to_owned()),
       handle,
+      owned,
       _com
     })
   }
@@ -222,14 +323,8 @@ impl Application {
   }
 
   // Properties left:
-  // ActiveView       [get]
-  // Selection        [get]
-  // UI               [get]
   // 
   // Methods left:
-  // CreateIfcExportSettings
-  // CreateProjectFromTemplate
-  // ImportIfcProject
   // LastError
   // SetLastError
 }
@@ -245,18 +340,23 @@ impl Debug for Application {
 /// - Closes project if it exists, discarding any changes.
 /// - Closes Renga application.
 /// 
+/// Host application of a plugin is left untouched, see [Application::host].
+/// 
 /// This function never fails or panics. If any error occurs, it will be logged.
 impl Drop for Application {
   fn drop(&mut self) {
+    if !self.owned {
+      return;
+    }
     let _ = self
       .project()
       .map(|pr| {
         let _ = pr
-          .map(|mut p| { let _ = p
-            .close(true)
-            .inspect_err(|e| log::error!("failed to close project: {e:?}")
-          );
-        });
+          .map(|mut p| {
+            if let Err(e) = p.close(true) {
+              log::error!("failed to close project: {e:?}");
+            }
+          });
     });
     self.quit();
   }
@@ -0,0 +1,310 @@
+use crate::{
+  geometry::Placement3D,
+  native::Dispatch,
+  Result,
+  Error
+};
+use super::{
+  Entity,
+  Model,
+  ModelObject,
+  ObjectNode,
+  Project,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Represents assembly definition: a reusable group of model objects with its own model.
+///
+/// Can be obtained using [crate::Project::assemblies] method. Placed copies of an assembly
+/// are [AssemblyInstance]s.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_assembly.html)
+#[derive(Debug, Clone)]
+pub struct Assembly {
+  pub entity: Entity,
+  handle: Dispatch
+}
+
+impl Assembly {
+  /// Creates new instance of Assembly from assembly entity.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if entity is not an assembly.
+  pub fn new(entity: Entity) -> Result<Self> {
+    let handle = entity
+      .interface("IAssembly")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{entity} is not an assembly")))?;
+    Ok(Self { entity, handle })
+  }
+
+  /// Returns model of the assembly, containing its objects in local coordinates.
+  pub fn model(&self) -> Result<Model> {
+    let handle = self
+      .handle
+      .call("GetModel", None)?
+      .into_dispatch()?;
+    Model::new(handle)
+  }
+}
+
+/// Represents placed copy of an [Assembly] in a model.
+///
+/// Can be obtained using [crate::Model::assembly_instances] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_assembly_instance.html)
+#[derive(Debug, Clone)]
+pub struct AssemblyInstance {
+  /// Identifier of the instance model object.
+  pub id: i32,
+
+  /// Globally unique identifier of the instance model object.
+  pub unique_id: UUID,
+  pub name: String,
+
+  /// Identifier of the [Assembly] entity.
+  pub assembly_id: i32,
+
+  /// Placement of the assembly coordinate system in the model.
+  pub placement: Placement3D
+}
+
+impl AssemblyInstance {
+  /// Creates new instance of AssemblyInstance from model object and its native `IAssemblyInstance` handle.
+  pub fn new(object: ModelObject, handle: Dispatch) -> Result<Self> {
+    Ok(Self {
+      assembly_id: handle
+        .get("AssemblyId")?
+        .as_int()?,
+      placement: object
+        .placement()?
+        .unwrap_or_default(),
+      id: object.id,
+      unique_id: object.unique_id,
+      name: object.name
+    })
+  }
+}
+
+/// In-memory snapshot of assembly definitions with their objects and local placements.
+///
+/// Placements are composed in pure Rust, so nested assemblies of any depth are flattened
+/// by [AssemblyLibrary::flatten] without calls to Renga.
+///
+/// For example, to list world placements of all objects inside assembly instances:
+/// ```no_run
+/// use renga_api_rs as renga;
+///
+/// let mut app = renga::Application::new().unwrap();
+/// let project = app.project().unwrap().unwrap();
+/// let library = renga::AssemblyLibrary::from_project(&project).unwrap();
+/// for instance in project.model().unwrap().assembly_instances().unwrap() {
+///   for object in library.flatten(instance.assembly_id, &instance.placement).unwrap() {
+///     println!("{}: {:?}", object.object.name, object.placement.origin);
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct AssemblyLibrary {
+  pub assemblies: Vec<AssemblyNode>
+}
+
+/// Assembly definition node of the [AssemblyLibrary].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct AssemblyNode {
+  pub id: i32,
+  pub unique_id: UUID,
+  pub name: String,
+  pub parts: Vec<AssemblyPart>
+}
+
+/// Object inside an assembly definition.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct AssemblyPart {
+  pub object: ObjectNode,
+
+  /// Placement of the object in the assembly coordinate system.
+  pub placement: Placement3D,
+
+  /// Identifier of the nested assembly, if the object is an assembly instance.
+  pub assembly_id: Option<i32>
+}
+
+/// Object of an assembly instance with its placement in world coordinates.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct FlatObject {
+  pub object: ObjectNode,
+  pub placement: Placement3D,
+
+  /// Identifiers of nested assembly instances the object is reached through, outermost first.
+  pub instance_path: Vec<i32>
+}
+
+impl AssemblyLibrary {
+  /// Builds snapshot of all assemblies of the project.
+  ///
+  /// Objects without placement (e.g. walls, defined by their baselines) get identity placement.
+  pub fn from_project(project: &Project) -> Result<Self> {
+    let mut assemblies = Vec::new();
+    for assembly in project.assemblies()? {
+      let mut parts = Vec::new();
+      for object in assembly.model()?.objects()?.into_vec()? {
+        let assembly_id = match object.interface("IAssemblyInstance")? {
+          Some(handle) => Some(handle.get("AssemblyId")?.as_int()?),
+          None => None
+        };
+        parts.push(AssemblyPart {
+          placement: object.placement()?.unwrap_or_default(),
+          object: object.into(),
+          assembly_id
+        });
+      }
+      assemblies.push(AssemblyNode {
+        id: assembly.entity.id,
+        unique_id: assembly.entity.unique_id,
+        name: assembly.entity.name,
+        parts
+      });
+    }
+    Ok(Self::from_parts(assemblies))
+  }
+
+  pub fn from_parts(assemblies: Vec<AssemblyNode>) -> Self {
+    Self { assemblies }
+  }
+
+  /// Returns assembly node with given identifier.
+  pub fn assembly(&self, id: i32) -> Option<&AssemblyNode> {
+    self.assemblies.iter().find(|assembly| assembly.id == id)
+  }
+
+  /// Returns objects of the assembly placed with `placement`, expanding nested assemblies.
+  ///
+  /// Nested assembly instances themselves are not included, only their objects.
+  /// Returns [crate::Error::InvalidOperation] if an assembly is unknown or contains itself.
+  pub fn flatten(&self, assembly_id: i32, placement: &Placement3D) -> Result<Vec<FlatObject>> {
+    let mut objects = Vec::new();
+    self.flatten_into(assembly_id, placement, &mut Vec::new(), &mut vec![assembly_id], &mut objects)?;
+    Ok(objects)
+  }
+
+  fn flatten_into(&self, assembly_id: i32, placement: &Placement3D, path: &mut Vec<i32>, stack: &mut Vec<i32>, objects: &mut Vec<FlatObject>) -> Result<()> {
+    let assembly = self
+      .assembly(assembly_id)
+      .ok_or_else(|| Error::InvalidOperation(format!("Assembly #{assembly_id} does not exist")))?;
+    for part in &assembly.parts {
+      let world = placement.compose(&part.placement);
+      let Some(nested) = part.assembly_id else {
+        objects.push(FlatObject {
+          object: part.object.clone(),
+          placement: world,
+          instance_path: path.clone()
+        });
+        continue;
+      };
+      if stack.contains(&nested) {
+        return Err(Error::InvalidOperation(format!("Assembly '{}' contains itself", assembly.name)));
+      }
+      path.push(part.object.id);
+      stack.push(nested);
+      self.flatten_into(nested, &world, path, stack, objects)?;
+      stack.pop();
+      path.pop();
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::FRAC_PI_2;
+  use test_context::test_context;
+  use crate::*;
+  use crate::geometry::{
+    ApproxEq,
+    Point3D,
+    Vector3D
+  };
+
+  fn part(id: i32, origin: Point3D, assembly_id: Option<i32>) -> AssemblyPart {
+    AssemblyPart {
+      object: ObjectNode {
+        id,
+        unique_id: UUID::default(),
+        object_type: UUID::default(),
+        name: format!("object {id}")
+      },
+      placement: Placement3D::new(origin, Vector3D::X, Vector3D::Z),
+      assembly_id
+    }
+  }
+
+  fn assembly(id: i32, parts: Vec<AssemblyPart>) -> AssemblyNode {
+    AssemblyNode {
+      id,
+      unique_id: UUID::default(),
+      name: format!("assembly {id}"),
+      parts
+    }
+  }
+
+  #[test]
+  fn test_flatten_nested() -> anyhow::Result<()> {
+    // Frame (1) holds a column (10) and two beams (2) at x = 0 and x = 6000;
+    // each beam assembly holds a plate (20) shifted by 100 along its X axis.
+    let library = AssemblyLibrary::from_parts(vec![
+      assembly(1, vec![
+        part(10, Point3D::new(0.0, 0.0, 0.0), None),
+        part(11, Point3D::new(0.0, 0.0, 3000.0), Some(2)),
+        part(12, Point3D::new(6000.0, 0.0, 3000.0), Some(2))
+      ]),
+      assembly(2, vec![part(20, Point3D::new(100.0, 0.0, 0.0), None)])
+    ]);
+    // Instance rotated by 90 degrees around Z
+    let rotated = Vector3D::new(FRAC_PI_2.cos(), FRAC_PI_2.sin(), 0.0);
+    let placement = Placement3D::new(Point3D::new(1000.0, 0.0, 0.0), rotated, Vector3D::Z);
+    let objects = library.flatten(1, &placement)?;
+
+    assert_eq!(objects.iter().map(|o| o.object.id).collect::<Vec<_>>(), vec![10, 20, 20]);
+    assert_eq!(objects[1].instance_path, vec![11]);
+    assert_eq!(objects[2].instance_path, vec![12]);
+    assert!(objects[0].placement.origin.approx_eq(&Point3D::new(1000.0, 0.0, 0.0)));
+    assert!(objects[1].placement.origin.approx_eq(&Point3D::new(1000.0, 100.0, 3000.0)));
+    assert!(objects[2].placement.origin.approx_eq(&Point3D::new(1000.0, 6100.0, 3000.0)));
+    assert!(objects[2].placement.axis_x.approx_eq(&rotated));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_flatten_invalid() {
+    let library = AssemblyLibrary::from_parts(vec![
+      assembly(1, vec![part(10, Point3D::default(), Some(2))]),
+      assembly(2, vec![part(20, Point3D::default(), Some(1))]),
+      assembly(3, vec![part(30, Point3D::default(), Some(4))])
+    ]);
+
+    assert!(matches!(library.flatten(1, &Placement3D::default()), Err(Error::InvalidOperation(_))));
+    assert!(matches!(library.flatten(3, &Placement3D::default()), Err(Error::InvalidOperation(_))));
+    assert!(library.assembly(4).is_none());
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_assemblies(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let library = AssemblyLibrary::from_project(&ctx.project)?;
+
+    assert_eq!(library.assemblies.len(), ctx.project.assemblies()?.len());
+    assert!(ctx.project.model()?.assembly_instances()?.is_empty());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use crate::{
+  mesh::{
+    Mesh,
+    MeshGrouping,
+    MeshNode
+  },
+  native::{
+    records,
+    Dispatch
+  },
+  Result,
+  Error
+};
+use super::{
+  ObjectMaterial,
+  Project,
+  UUID
+};
+
+/// Triangulated grid of an exported object.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_grid.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedGrid {
+  /// Native grid type, e.g. wall core or insulation.
+  pub grid_type: i32,
+
+  /// Triangle mesh of the grid.
+  pub mesh: Mesh
+}
+
+/// Geometry of a single model object, exported by [DataExporter::objects_3d].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_exported_object3_d.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedObject {
+  /// Identifier of the model object.
+  pub model_object_id: i32,
+
+  /// Type of the model object.
+  pub object_type: UUID,
+
+  /// Grids of all object meshes.
+  pub grids: Vec<ExportedGrid>
+}
+
+impl ExportedObject {
+  /// Returns all grids of the object merged into a single mesh.
+  pub fn merged_mesh(&self) -> Mesh {
+    let mut mesh = Mesh::default();
+    for grid in &self.grids {
+      mesh.append(&grid.mesh);
+    }
+    mesh
+  }
+}
+
+/// Represents project data exporter.
+///
+/// Can be obtained using [crate::Project::data_exporter] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_data_exporter.html)
+#[derive(Debug, Clone)]
+pub struct DataExporter {
+  handle: Dispatch
+}
+
+impl DataExporter {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IDataExporter handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns triangulated geometry of all model objects.
+  pub fn objects_3d(&self) -> Result<Vec<ExportedObject>> {
+    let collection = self
+      .handle
+      .call("GetObjects3D", None)?
+      .into_dispatch()?;
+    let count = collection.get("Count")?.as_int()?;
+    let mut objects = Vec::with_capacity(count.max(0) as usize);
+    for index in 0..count {
+      let object = collection
+        .call("Get", Some(vec![index.into()]))?
+        .into_dispatch()?;
+      objects.push(Self::read_object(&object)?);
+    }
+    Ok(objects)
+  }
+
+  /// Returns named meshes of all model objects, ready to be written with [crate::mesh::write_file].
+  ///
+  /// Nodes are named by model object unique ids and carry names of object materials.
+  /// With [MeshGrouping::PerMaterial], objects with the same material are merged.
+  pub fn mesh_nodes(&self, project: &Project, grouping: MeshGrouping) -> Result<Vec<MeshNode>> {
+    let objects = project.model()?.objects()?;
+    let materials: HashMap<i32, String> = project
+      .material_manager()?
+      .materials()?
+      .into_iter()
+      .map(|material| (material.id, material.name))
+      .collect();
+    let layered_materials: HashMap<i32, String> = project
+      .layered_material_manager()?
+      .layered_materials()?
+      .into_iter()
+      .map(|material| (material.id, material.name))
+      .collect();
+
+    let mut nodes = Vec::new();
+    for exported in self.objects_3d()? {
+      let Some(object) = objects.get_by_id(exported.model_object_id)? else {
+        continue;
+      };
+      let material = match object.material()? {
+        Some(ObjectMaterial::Material(id)) => materials.get(&id),
+        Some(ObjectMaterial::Layered(id)) => layered_materials.get(&id),
+        None => None
+      };
+      nodes.push(MeshNode::new(
+        &object.unique_id.to_string(),
+        material.map(String::as_str),
+        exported.merged_mesh()
+      ));
+    }
+    Ok(match grouping {
+      MeshGrouping::PerObject => nodes,
+      MeshGrouping::PerMaterial => MeshNode::group_by_material(&nodes)
+    })
+  }
+
+  fn read_object(handle: &Dispatch) -> Result<ExportedObject> {
+    let mut grids = Vec::new();
+    let mesh_count = handle.get("MeshCount")?.as_int()?;
+    for mesh_index in 0..mesh_count {
+      let mesh = handle
+        .call("GetMesh", Some(vec![mesh_index.into()]))?
+        .into_dispatch()?;
+      let grid_count = mesh.get("GridCount")?.as_int()?;
+      for grid_index in 0..grid_count {
+        let grid = mesh
+          .call("GetGrid", Some(vec![grid_index.into()]))?
+          .into_dispatch()?;
+        grids.push(Self::read_grid(&grid)?);
+      }
+    }
+    Ok(ExportedObject {
+      model_object_id: handle
+        .get("ModelObjectId")?
+        .as_int()?,
+      object_type: handle
+        .get("ModelObjectTypeS")?
+        .into_string()?
+        .parse()?,
+      grids
+    })
+  }
+
+  fn read_grid(handle: &Dispatch) -> Result<ExportedGrid> {
+    let vertex_count = handle.get("VertexCount")?.as_int()?;
+    let normal_count = handle.get("NormalCount")?.as_int()?;
+    let triangle_count = handle.get("TriangleCount")?.as_int()?;
+    let mut mesh = Mesh::default();
+    for index in 0..vertex_count {
+      let vertex = unsafe { records::FloatPoint3D::from_variant(&handle.call("GetVertex", Some(vec![index.into()]))?)? };
+      mesh.vertices.push([vertex.x, vertex.y, vertex.z]);
+    }
+    if normal_count == vertex_count {
+      for index in 0..normal_count {
+        let normal = unsafe { records::FloatVector3D::from_variant(&handle.call("GetNormal", Some(vec![index.into()]))?)? };
+        mesh.normals.push([normal.x, normal.y, normal.z]);
+      }
+    }
+    for index in 0..triangle_count {
+      let triangle = unsafe { records::Triangle::from_variant(&handle.call("GetTriangle", Some(vec![index.into()]))?)? };
+      mesh.triangles.push([triangle.v0, triangle.v1, triangle.v2]);
+    }
+    Ok(ExportedGrid {
+      grid_type: handle.get("GridType")?.as_int()?,
+      mesh
+    })
+  }
+}
+
+impl TryFrom<Dispatch> for DataExporter {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_objects_3d(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let exporter = ctx.project.data_exporter()?;
+    let objects = exporter.objects_3d()?;
+
+    for object in &objects {
+      let mesh = object.merged_mesh();
+      assert!(mesh.triangles.iter().flatten().all(|index| (*index as usize) < mesh.vertices.len()));
+    }
+
+    Ok(())
+  }
+}
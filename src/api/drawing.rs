@@ -0,0 +1,221 @@
+use std::{
+  collections::HashSet,
+  fmt::Display,
+  path::{
+    Path,
+    PathBuf
+  }
+};
+use crate::{
+  native::Dispatch,
+  Result,
+  Error
+};
+use super::UUID;
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Drawing (sheet) of the project.
+///
+/// Can be obtained using [crate::Project::drawings] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_drawing.html)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Drawing {
+  /// Identifier of the drawing.
+  pub id: i32,
+
+  /// Globally unique identifier of the drawing.
+  pub unique_id: UUID,
+
+  /// Name of the drawing.
+  pub name: String,
+
+  /// Name of the sheet format (page format style), e.g. `A3`.
+  pub sheet_format: Option<String>
+}
+
+/// File format of exported drawings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum DrawingFormat {
+  Pdf,
+  OpenXps
+}
+
+impl DrawingFormat {
+  /// Returns file extension without leading dot.
+  pub fn extension(&self) -> &'static str {
+    match self {
+      Self::Pdf => "pdf",
+      Self::OpenXps => "oxps"
+    }
+  }
+
+  /// Returns name of native `IProject` export method.
+  pub(crate) fn method(&self) -> &'static str {
+    match self {
+      Self::Pdf => "ExportDrawingsToPdfS",
+      Self::OpenXps => "ExportDrawingsToOpenXpsS"
+    }
+  }
+}
+
+impl Drawing {
+  /// Reads drawing from native `IDrawing` handle. Sheet format is resolved by the caller.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IDrawing handle is null".to_owned()));
+    }
+    Ok(Self {
+      id: handle
+        .get("Id")?
+        .as_int()?,
+      unique_id: handle
+        .get("UniqueIdS")?
+        .into_string()?
+        .parse()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      sheet_format: None
+    })
+  }
+
+  /// Renders file name for this drawing from template.
+  ///
+  /// Supported placeholders: `{index}` (one-based position in the exported selection), `{id}`,
+  /// `{unique_id}`, `{name}` and `{sheet_format}`. Characters not allowed in Windows file
+  /// names are replaced with `_`, leading whitespace and trailing dots and whitespace are removed.
+  /// If nothing is left, identifier of the drawing is used. Extension is not appended.
+  pub fn file_name(&self, template: &str, index: usize) -> String {
+    let name = template
+      .replace("{index}", &index.to_string())
+      .replace("{id}", &self.id.to_string())
+      .replace("{unique_id}", &self.unique_id.to_string())
+      .replace("{name}", &self.name)
+      .replace("{sheet_format}", self.sheet_format.as_deref().unwrap_or(""))
+      .chars()
+      .map(|c| match c {
+        '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+        c if c.is_control() => '_',
+        c => c
+      })
+      .collect::<String>();
+    let name = name
+      .trim_start()
+      .trim_end_matches(|c: char| c == '.' || c.is_whitespace());
+    match name.is_empty() {
+      true => self.id.to_string(),
+      false => name.to_owned()
+    }
+  }
+}
+
+/// Returns paths of files [crate::Project::export_drawings_each] writes `drawings` to.
+///
+/// Extension is appended to the rendered name, so dots in drawing names are kept. Repeated
+/// names (compared case-insensitively, as on Windows) get ` (2)`, ` (3)` etc. suffixes.
+pub(crate) fn export_paths(drawings: &[Drawing], directory: &Path, template: &str, format: DrawingFormat) -> Vec<PathBuf> {
+  let mut used = HashSet::new();
+  drawings
+    .iter()
+    .enumerate()
+    .map(|(index, drawing)| {
+      let name = drawing.file_name(template, index + 1);
+      let mut unique = name.clone();
+      let mut counter = 1;
+      while !used.insert(unique.to_lowercase()) {
+        counter += 1;
+        unique = format!("{name} ({counter})");
+      }
+      directory.join(format!("{unique}.{}", format.extension()))
+    })
+    .collect()
+}
+
+impl Display for Drawing {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "<drawing #{}: {}>", self.id, self.name)
+  }
+}
+
+impl TryFrom<Dispatch> for Drawing {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  fn drawing() -> Drawing {
+    Drawing {
+      id: 7,
+      unique_id: "4329112A-6B65-48D9-9DA8-ABF1F8F36327".parse().unwrap(),
+      name: "Plan: level 1/2".to_owned(),
+      sheet_format: Some("A3".to_owned())
+    }
+  }
+
+  #[test]
+  fn test_file_name() {
+    let drawing = drawing();
+
+    assert_eq!(drawing.file_name("{index}-{name}", 3), "3-Plan_ level 1_2");
+    assert_eq!(drawing.file_name("{sheet_format}_{id}", 1), "A3_7");
+    assert_eq!(
+      drawing.file_name("{unique_id}", 1).to_lowercase(),
+      "4329112a-6b65-48d9-9da8-abf1f8f36327"
+    );
+    assert_eq!(drawing.file_name("{name}. . ", 1), "Plan_ level 1_2");
+    assert_eq!(drawing.file_name("{index}.", 2), "2");
+    assert_eq!(drawing.file_name(" {unknown}", 1), "{unknown}");
+    assert_eq!(Drawing { name: String::new(), ..drawing.clone() }.file_name("{name}", 1), "7");
+    assert_eq!(drawing.file_name("...", 1), "7");
+  }
+
+  #[test]
+  fn test_export_paths() {
+    let section = |id: i32, name: &str| Drawing {
+      id,
+      name: name.to_owned(),
+      ..drawing()
+    };
+    let drawings = [
+      section(1, "Section 1.1"),
+      section(2, "Section 1.2"),
+      section(3, "section 1.1"),
+      section(4, "Section 1.1")
+    ];
+    let directory = std::path::Path::new("out");
+    let paths = super::export_paths(&drawings, directory, "{name}", DrawingFormat::Pdf);
+
+    assert_eq!(paths, vec![
+      directory.join("Section 1.1.pdf"),
+      directory.join("Section 1.2.pdf"),
+      directory.join("section 1.1 (2).pdf"),
+      directory.join("Section 1.1 (3).pdf")
+    ]);
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_export_empty_selection(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("renga-api-rs-empty.pdf");
+    let result = ctx.project.export_drawings(&[], &path, DrawingFormat::Pdf, OverwritePolicy::Overwrite);
+
+    assert!(matches!(result, Err(Error::Operation(OperationError::NothingToExport))));
+    assert!(ctx.project.drawings()?.iter().all(|drawing| drawing.id > 0));
+
+    Ok(())
+  }
+}
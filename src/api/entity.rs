@@ -4,7 +4,10 @@ use crate::{
   Result,
   Error
 };
-use super::UUID;
+use super::{
+  ParameterContainer,
+  UUID
+};
 
 #[derive(Debug, Clone)]
 pub struct Entity {
@@ -12,7 +15,7 @@ pub struct Entity {
   pub name: String,
   pub type_id: UUID,
   pub unique_id: UUID,
-  _handle: Dispatch
+  handle: Dispatch
 }
 
 impl Default for Entity {
@@ -22,7 +25,7 @@ impl Default for Entity {
       name: String::default(),
       type_id: UUID::default(),
       unique_id: UUID::default(),
-      _handle: ().into()
+      handle: ().into()
     }
   }
 }
@@ -51,9 +54,26 @@ impl Entity {
         .trim_start_matches("{")
         .trim_end_matches("}")
         .parse()?,
-      _handle: handle 
+      handle 
     }) 
   }
+
+  /// Returns container of style parameters of this entity.
+  /// 
+  /// Can return [crate::Error::Internal] if entity has no parameters.
+  pub fn parameters(&self) -> Result<ParameterContainer> {
+    self
+      .interface("IParameterContainer")?
+      .ok_or_else(|| Error::Internal(format!("{self} has no parameters")))?
+      .try_into()
+  }
+
+  /// Queries native interface of the entity by its name.
+  ///
+  /// Returns `None` if entity does not support requested interface.
+  pub(crate) fn interface(&self, name: &str) -> Result<Option<Dispatch>> {
+    self.handle.query_interface(name)
+  }
 }
 
 impl Display for Entity {
@@ -65,7 +85,7 @@ impl Display for Entity {
   }
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntityCollection {
   handle: Dispatch
 }
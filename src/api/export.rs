@@ -0,0 +1,44 @@
+use std::path::Path;
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// What to do when export target file already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum OverwritePolicy {
+  /// Replace existing file.
+  Overwrite,
+
+  /// Fail with [crate::OperationError::FileAlreadyExists].
+  #[default]
+  Fail,
+
+  /// Leave existing file untouched and report [ExportStatus::Skipped].
+  Skip
+}
+
+/// Outcome of a successful export call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportStatus {
+  /// File was written.
+  Exported,
+
+  /// File already existed and was left untouched, see [OverwritePolicy::Skip].
+  Skipped
+}
+
+impl OverwritePolicy {
+  /// Returns `true` if export to `path` must be skipped under this policy.
+  pub(crate) fn skips(&self, path: &Path) -> bool {
+    *self == Self::Skip && path.exists()
+  }
+
+  /// Returns value of native `overwrite` flag.
+  pub(crate) fn overwrite(&self) -> bool {
+    *self == Self::Overwrite
+  }
+}
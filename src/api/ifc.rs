@@ -0,0 +1,260 @@
+use crate::{
+  native::{
+    constants,
+    Dispatch,
+    Variant
+  },
+  Result,
+  Error
+};
+use super::UUID;
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// IFC schema version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum IfcVersion {
+  Ifc2x3,
+  #[default]
+  Ifc4,
+  Ifc4x3
+}
+
+impl IfcVersion {
+  /// Converts enum to native `IfcVersion` value.
+  pub fn to_native(&self) -> i32 {
+    match self {
+      Self::Ifc2x3 => constants::IFC_VERSION_2X3,
+      Self::Ifc4 => constants::IFC_VERSION_4,
+      Self::Ifc4x3 => constants::IFC_VERSION_4X3
+    }
+  }
+}
+
+/// Settings of IFC export, see [crate::Project::export_ifc].
+///
+/// Settings can be stored in configuration files with `serde` feature enabled.
+/// Missing fields take default values:
+/// ```no_run
+/// use renga_api_rs as renga;
+///
+/// let settings = renga::IfcExportSettings::new()
+///   .with_version(renga::IfcVersion::Ifc2x3)
+///   .with_quantities(false)
+///   .with_object_types(&["4329112A-6B65-48D9-9DA8-ABF1F8F36327".parse().unwrap()]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct IfcExportSettings {
+  /// IFC schema version.
+  pub version: IfcVersion,
+
+  /// Export Renga property sets (`Pset_*`).
+  pub property_sets: bool,
+
+  /// Export user-defined properties.
+  pub user_properties: bool,
+
+  /// Export base quantities (`Qto_*`).
+  pub quantities: bool,
+
+  /// Object types to export. Empty list means all objects.
+  pub object_types: Vec<UUID>
+}
+
+impl Default for IfcExportSettings {
+  fn default() -> Self {
+    Self {
+      version: IfcVersion::default(),
+      property_sets: true,
+      user_properties: true,
+      quantities: true,
+      object_types: Vec::new()
+    }
+  }
+}
+
+impl IfcExportSettings {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_version(mut self, version: IfcVersion) -> Self {
+    self.version = version;
+    self
+  }
+
+  pub fn with_property_sets(mut self, enabled: bool) -> Self {
+    self.property_sets = enabled;
+    self
+  }
+
+  pub fn with_user_properties(mut self, enabled: bool) -> Self {
+    self.user_properties = enabled;
+    self
+  }
+
+  pub fn with_quantities(mut self, enabled: bool) -> Self {
+    self.quantities = enabled;
+    self
+  }
+
+  /// Restricts export to objects of given types.
+  pub fn with_object_types(mut self, object_types: &[UUID]) -> Self {
+    self.object_types = object_types.to_vec();
+    self
+  }
+
+  /// Returns `true` if objects of given type are exported.
+  pub fn exports(&self, object_type: &UUID) -> bool {
+    self.object_types.is_empty() || self.object_types.contains(object_type)
+  }
+
+  /// Writes settings into native `IIfcExportSettings` handle.
+  pub(crate) fn apply(&self, handle: &Dispatch) -> Result<()> {
+    if handle.is_null() {
+      return Err(Error::Internal("IIfcExportSettings handle is null".to_owned()));
+    }
+    handle.set("Version", self.version.to_native().into())?;
+    handle.set("ExportPropertySets", self.property_sets.into())?;
+    handle.set("ExportUserProperties", self.user_properties.into())?;
+    handle.set("ExportQuantities", self.quantities.into())?;
+    if !self.object_types.is_empty() {
+      let ids: Vec<String> = self
+        .object_types
+        .iter()
+        .map(UUID::to_string)
+        .collect();
+      handle.call("SetObjectTypesS", Some(vec![Variant::from_strings(&ids)?]))?;
+    }
+    Ok(())
+  }
+}
+
+/// Options of IFC import, see [crate::Application::import_ifc].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct IfcImportOptions {
+  /// Close currently opened project even if it has unsaved changes.
+  pub discard_unsaved_changes: bool
+}
+
+impl IfcImportOptions {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_discard_unsaved_changes(mut self, discard: bool) -> Self {
+    self.discard_unsaved_changes = discard;
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  fn wall() -> UUID {
+    "4329112A-6B65-48D9-9DA8-ABF1F8F36327".parse().unwrap()
+  }
+
+  #[test]
+  fn test_builder() {
+    let settings = IfcExportSettings::new()
+      .with_version(IfcVersion::Ifc2x3)
+      .with_quantities(false)
+      .with_object_types(&[wall()]);
+
+    assert_eq!(settings.version, IfcVersion::Ifc2x3);
+    assert!(settings.property_sets);
+    assert!(!settings.quantities);
+    assert!(settings.exports(&wall()));
+    assert!(!settings.exports(&UUID::default()));
+    assert!(IfcExportSettings::default().exports(&UUID::default()));
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_serde() -> anyhow::Result<()> {
+    let settings: IfcExportSettings = serde_json::from_str(r#"{
+      "version": "Ifc2x3",
+      "user_properties": false,
+      "object_types": ["4329112A-6B65-48D9-9DA8-ABF1F8F36327"]
+    }"#)?;
+
+    assert_eq!(settings, IfcExportSettings::new()
+      .with_version(IfcVersion::Ifc2x3)
+      .with_user_properties(false)
+      .with_object_types(&[wall()])
+    );
+    assert_eq!(serde_json::from_str::<IfcExportSettings>(&serde_json::to_string(&settings)?)?, settings);
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_export_ifc(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("renga-api-rs-export.ifc");
+    let settings = IfcExportSettings::new().with_version(IfcVersion::Ifc4);
+    ctx.project.export_ifc(&path, Some(&settings), OverwritePolicy::Overwrite)?;
+
+    assert!(path.exists());
+    assert_eq!(
+      ctx.project.export_ifc(&path, None, OverwritePolicy::Skip)?,
+      ExportStatus::Skipped
+    );
+    assert!(matches!(
+      ctx.project.export_ifc(&path, None, OverwritePolicy::Fail),
+      Err(Error::Operation(OperationError::FileAlreadyExists))
+    ));
+    assert!(matches!(
+      ctx.project.export_ifc(&path, Some(&settings.with_object_types(&[UUID::default()])), OverwritePolicy::Overwrite),
+      Err(Error::Operation(OperationError::NothingToExport))
+    ));
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_import_ifc_unsaved_changes(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("renga-api-rs-import.ifc");
+    ctx.project.export_ifc(&path, None, OverwritePolicy::Overwrite)?;
+
+    let file = external_file("style_category.rst")?;
+    ctx.project.transact(|transaction| transaction.import_category(Category::Equipment, &file))?;
+
+    assert!(matches!(
+      ctx.app.import_ifc(&path, &IfcImportOptions::default()),
+      Err(Error::UnsavedChanges)
+    ));
+    assert!(ctx.project.has_unsaved_changes()?);
+
+    let project = ctx.app.import_ifc(&path, &IfcImportOptions::new().with_discard_unsaved_changes(true))?;
+    assert!(!project.has_unsaved_changes()?);
+
+    Ok(())
+  }
+
+  #[test_context(RengaContext)]
+  #[test]
+  fn test_import_ifc_nonexistent(ctx: &mut RengaContext) -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("renga-api-rs-missing.ifc");
+
+    assert!(matches!(
+      ctx.app.import_ifc(&path, &IfcImportOptions::default()),
+      Err(Error::NonexistentPath(_))
+    ));
+
+    Ok(())
+  }
+}
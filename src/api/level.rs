@@ -0,0 +1,237 @@
+use std::fmt::Display;
+use crate::{
+  geometry::Placement3D,
+  native::Dispatch,
+  Result
+};
+use super::{
+  Model,
+  ModelObject,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Represents building level (storey).
+///
+/// Can be obtained using [Model::levels] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_level.html)
+#[derive(Debug, Clone)]
+pub struct Level {
+  /// Identifier of the level model object.
+  pub id: i32,
+
+  /// Globally unique identifier of the level model object.
+  pub unique_id: UUID,
+
+  /// Name of the level.
+  pub name: String,
+
+  /// Elevation of the level in millimeters.
+  pub elevation: f64,
+
+  /// Placement of the level coordinate system.
+  pub placement: Placement3D,
+  model: Model
+}
+
+impl Level {
+  /// Creates new instance of Level from model object and its native `ILevel` handle.
+  pub fn new(model: Model, object: ModelObject, handle: Dispatch) -> Result<Self> {
+    Ok(Self {
+      id: object.id,
+      unique_id: object.unique_id,
+      name: handle
+        .get("LevelName")?
+        .into_string()?,
+      elevation: handle
+        .get("Elevation")?
+        .as_f64()?,
+      placement: handle
+        .get("Placement")?
+        .into_dispatch()?
+        .try_into()?,
+      model
+    })
+  }
+
+  /// Returns all model objects hosted on this level.
+  ///
+  /// Scans the whole model on every call. To get objects of all levels at once, use
+  /// [Model::objects_by_level].
+  pub fn objects(&self) -> Result<Vec<ModelObject>> {
+    let mut objects = Vec::new();
+    for object in self.model.objects()?.into_vec()? {
+      if object.level_id()? == Some(self.id) {
+        objects.push(object);
+      }
+    }
+    Ok(objects)
+  }
+}
+
+impl Display for Level {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "<level: {} ({:+.0} mm)>", self.name, self.elevation)
+  }
+}
+
+/// In-memory snapshot of the building hierarchy: building → levels → objects.
+///
+/// Taken from the model with [Building::from_model], so it stays valid after the project is
+/// closed. Objects not hosted on any level are not included.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Building {
+  /// Levels of the building, ordered by elevation from bottom to top.
+  pub levels: Vec<LevelNode>
+}
+
+/// Level node of the [Building] tree.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct LevelNode {
+  pub id: i32,
+  pub unique_id: UUID,
+  pub name: String,
+  pub elevation: f64,
+  pub placement: Placement3D,
+  pub objects: Vec<ObjectNode>
+}
+
+/// Model object node of the [Building] tree.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct ObjectNode {
+  pub id: i32,
+  pub unique_id: UUID,
+  pub object_type: UUID,
+  pub name: String
+}
+
+impl Building {
+  /// Builds hierarchy snapshot of the given model.
+  ///
+  /// Objects which are not hosted on any level are omitted.
+  pub fn from_model(model: &Model) -> Result<Self> {
+    let levels = model
+      .levels()?
+      .into_iter()
+      .map(LevelNode::from)
+      .collect();
+    let objects = model
+      .objects_by_level()?
+      .into_iter()
+      .flat_map(|(level_id, objects)| objects
+        .into_iter()
+        .map(move |object| (level_id, ObjectNode::from(object))))
+      .collect();
+    Ok(Self::from_parts(levels, objects))
+  }
+
+  /// Builds hierarchy from levels and `(level id, object)` pairs.
+  ///
+  /// Levels are sorted by elevation. Objects referencing unknown levels are omitted.
+  pub fn from_parts(mut levels: Vec<LevelNode>, objects: Vec<(i32, ObjectNode)>) -> Self {
+    levels.sort_by(|a, b| a.elevation.total_cmp(&b.elevation));
+    for (level_id, object) in objects {
+      if let Some(level) = levels.iter_mut().find(|level| level.id == level_id) {
+        level.objects.push(object);
+      }
+    }
+    Self { levels }
+  }
+
+  /// Returns level node with given identifier.
+  pub fn level(&self, id: i32) -> Option<&LevelNode> {
+    self.levels.iter().find(|level| level.id == id)
+  }
+}
+
+impl From<Level> for LevelNode {
+  fn from(level: Level) -> Self {
+    Self {
+      id: level.id,
+      unique_id: level.unique_id,
+      name: level.name,
+      elevation: level.elevation,
+      placement: level.placement,
+      objects: Vec::new()
+    }
+  }
+}
+
+impl From<ModelObject> for ObjectNode {
+  fn from(object: ModelObject) -> Self {
+    Self {
+      id: object.id,
+      unique_id: object.unique_id,
+      object_type: object.object_type,
+      name: object.name
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  fn level(id: i32, name: &str, elevation: f64) -> LevelNode {
+    LevelNode {
+      id,
+      unique_id: UUID::default(),
+      name: name.to_owned(),
+      elevation,
+      placement: Placement3D::default(),
+      objects: Vec::new()
+    }
+  }
+
+  fn object(id: i32) -> ObjectNode {
+    ObjectNode {
+      id,
+      unique_id: UUID::default(),
+      object_type: UUID::default(),
+      name: format!("object {id}")
+    }
+  }
+
+  #[test]
+  fn test_building_from_parts() {
+    let building = Building::from_parts(
+      vec![level(2, "Level 2", 3000.0), level(1, "Level 1", 0.0)],
+      vec![(1, object(10)), (2, object(11)), (1, object(12)), (3, object(13))]
+    );
+
+    assert_eq!(building.levels.len(), 2);
+    assert_eq!(building.levels[0].name, "Level 1");
+    assert_eq!(building.levels[1].name, "Level 2");
+    assert_eq!(
+      building.level(1).unwrap().objects.iter().map(|o| o.id).collect::<Vec<_>>(),
+      vec![10, 12]
+    );
+    assert_eq!(building.level(2).unwrap().objects.len(), 1);
+    assert!(building.level(3).is_none());
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_levels(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let levels = ctx.project.model()?.levels()?;
+
+    assert!(!levels.is_empty());
+    assert!(levels.windows(2).all(|pair| pair[0].elevation <= pair[1].elevation));
+    let objects = ctx.project.model()?.objects_by_level()?;
+    for level in &levels {
+      assert_eq!(level.objects()?.len(), objects.get(&level.id).map_or(0, Vec::len));
+    }
+
+    Ok(())
+  }
+}
\ No newline at end of file
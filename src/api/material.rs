@@ -0,0 +1,456 @@
+use std::path::Path;
+use crate::{
+  native::Dispatch,
+  Result,
+  Error
+};
+use super::{
+  EntityCollection,
+  ModelObject,
+  ProjectTransaction
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Color {
+  pub r: u8,
+  pub g: u8,
+  pub b: u8
+}
+
+impl Color {
+  pub fn new(r: u8, g: u8, b: u8) -> Self {
+    Self { r, g, b }
+  }
+
+  /// Converts from native `OLE_COLOR` value (`0x00BBGGRR`).
+  pub fn from_ole(value: u32) -> Self {
+    Self {
+      r: (value & 0xFF) as u8,
+      g: ((value >> 8) & 0xFF) as u8,
+      b: ((value >> 16) & 0xFF) as u8
+    }
+  }
+
+  /// Converts to native `OLE_COLOR` value (`0x00BBGGRR`).
+  pub fn to_ole(&self) -> u32 {
+    (self.r as u32) | ((self.g as u32) << 8) | ((self.b as u32) << 16)
+  }
+}
+
+/// Material of the project.
+///
+/// Can be obtained using [MaterialManager::get] or [MaterialManager::materials] methods.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_material.html)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Material {
+  /// Identifier of the material.
+  pub id: i32,
+
+  /// Name of the material.
+  pub name: String,
+
+  /// Density in kg/m³, `None` if not set.
+  pub density: Option<f64>,
+
+  /// Display color.
+  pub color: Color,
+
+  /// Path to texture file.
+  pub texture: Option<String>,
+
+  /// Thermal conductivity in W/(m·K), `None` if not set.
+  pub thermal_conductivity: Option<f64>
+}
+
+impl Material {
+  /// Reads material from native `IMaterial` handle.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IMaterial handle is null".to_owned()));
+    }
+    Ok(Self {
+      id: handle
+        .get("Id")?
+        .as_int()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      density: Some(handle
+        .get("Density")?
+        .as_f64()?)
+        .filter(|density| *density > 0.0),
+      color: Color::from_ole(handle
+        .get("Color")?
+        .as_u32()?),
+      texture: Some(handle
+        .get("TexturePath")?
+        .into_string()?)
+        .filter(|path| !path.is_empty()),
+      thermal_conductivity: Some(handle
+        .get("ThermalConductivity")?
+        .as_f64()?)
+        .filter(|conductivity| *conductivity > 0.0)
+    })
+  }
+}
+
+/// Parameters of a material to be created with [MaterialManager::create].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct NewMaterial {
+  pub name: String,
+  pub density: Option<f64>,
+  pub color: Option<Color>,
+  pub thermal_conductivity: Option<f64>
+}
+
+impl NewMaterial {
+  pub fn new(name: &str) -> Self {
+    Self {
+      name: name.to_owned(),
+      ..Default::default()
+    }
+  }
+
+  pub fn with_density(mut self, density: f64) -> Self {
+    self.density = Some(density);
+    self
+  }
+
+  pub fn with_color(mut self, color: Color) -> Self {
+    self.color = Some(color);
+    self
+  }
+
+  pub fn with_thermal_conductivity(mut self, value: f64) -> Self {
+    self.thermal_conductivity = Some(value);
+    self
+  }
+}
+
+/// Single layer of a [LayeredMaterial].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct MaterialLayer {
+  /// Identifier of the layer material.
+  pub material_id: i32,
+
+  /// Thickness of the layer in millimeters.
+  pub thickness: f64
+}
+
+/// Layered material of the project, e.g. wall or floor construction.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_layered_material.html)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct LayeredMaterial {
+  /// Identifier of the layered material.
+  pub id: i32,
+
+  /// Name of the layered material.
+  pub name: String,
+
+  /// Layers, ordered from the outer side to the inner side.
+  pub layers: Vec<MaterialLayer>
+}
+
+impl LayeredMaterial {
+  /// Reads layered material from native `ILayeredMaterial` handle.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("ILayeredMaterial handle is null".to_owned()));
+    }
+    let layers_handle = handle
+      .get("Layers")?
+      .into_dispatch()?;
+    let count = layers_handle
+      .get("Count")?
+      .as_int()?;
+    let mut layers = Vec::with_capacity(count.max(0) as usize);
+    for index in 0..count {
+      let layer = layers_handle
+        .call("Get", Some(vec![index.into()]))?
+        .into_dispatch()?;
+      layers.push(MaterialLayer {
+        material_id: layer
+          .get("MaterialId")?
+          .as_int()?,
+        thickness: layer
+          .get("Thickness")?
+          .as_f64()?
+      });
+    }
+    Ok(Self {
+      id: handle
+        .get("Id")?
+        .as_int()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      layers
+    })
+  }
+
+  /// Returns total thickness of all layers in millimeters.
+  pub fn thickness(&self) -> f64 {
+    self.layers.iter().map(|layer| layer.thickness).sum()
+  }
+
+  /// Checks that layered material has at least one layer and all layers have positive thickness.
+  pub fn validate_layers(layers: &[MaterialLayer]) -> Result<()> {
+    if layers.is_empty() {
+      return Err(Error::InvalidOperation("Layered material must have at least one layer".to_owned()));
+    }
+    if let Some(layer) = layers.iter().find(|layer| layer.thickness.is_nan() || layer.thickness <= 0.0) {
+      return Err(Error::InvalidOperation(format!(
+        "Layer of material #{} has non-positive thickness {}",
+        layer.material_id,
+        layer.thickness
+      )));
+    }
+    Ok(())
+  }
+}
+
+/// Material assigned to a model object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum ObjectMaterial {
+  /// Identifier of a single material.
+  Material(i32),
+
+  /// Identifier of a layered material.
+  Layered(i32)
+}
+
+impl ModelObject {
+  /// Returns material or layered material of this object.
+  ///
+  /// Returns `None` if object has no material or material is not set.
+  pub fn material(&self) -> Result<Option<ObjectMaterial>> {
+    if let Some(handle) = self.interface("IObjectWithLayeredMaterial")? {
+      let id = handle.get("LayeredMaterialId")?.as_int()?;
+      if id > 0 {
+        return Ok(Some(ObjectMaterial::Layered(id)));
+      }
+    }
+    if let Some(handle) = self.interface("IObjectWithMaterial")? {
+      let id = handle.get("MaterialId")?.as_int()?;
+      if id > 0 {
+        return Ok(Some(ObjectMaterial::Material(id)));
+      }
+    }
+    Ok(None)
+  }
+}
+
+/// Represents project material manager.
+///
+/// Can be obtained using [crate::Project::material_manager] method.
+/// All mutating methods require active [ProjectTransaction].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_material_manager.html)
+#[derive(Debug, Clone)]
+pub struct MaterialManager {
+  collection: EntityCollection,
+  handle: Dispatch
+}
+
+impl MaterialManager {
+  pub fn new(collection: EntityCollection, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IMaterialManager handle is null".to_owned()));
+    }
+    Ok(Self { collection, handle })
+  }
+
+  /// Returns material with given identifier.
+  pub fn get(&self, id: i32) -> Result<Material> {
+    self
+      .handle
+      .call("GetMaterial", Some(vec![id.into()]))?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Returns all materials of the project.
+  pub fn materials(&self) -> Result<Vec<Material>> {
+    self
+      .collection
+      .clone()
+      .into_vec()?
+      .iter()
+      .map(|entity| self.get(entity.id))
+      .collect()
+  }
+
+  /// Returns material with given name or `None` if there is no such material.
+  pub fn find(&self, name: &str) -> Result<Option<Material>> {
+    Ok(self
+      .materials()?
+      .into_iter()
+      .find(|material| material.name == name))
+  }
+
+  /// Creates new material.
+  pub fn create(&mut self, transaction: &ProjectTransaction, material: &NewMaterial) -> Result<Material> {
+    transaction.ensure_active()?;
+    let handle = self
+      .handle
+      .call("CreateMaterial", Some(vec![material.name.as_str().into()]))?
+      .into_dispatch()?;
+    if let Some(density) = material.density {
+      handle.set("Density", density.into())?;
+    }
+    if let Some(color) = material.color {
+      handle.set("Color", color.to_ole().into())?;
+    }
+    if let Some(value) = material.thermal_conductivity {
+      handle.set("ThermalConductivity", value.into())?;
+    }
+    Material::new(handle)
+  }
+
+  /// Imports materials from Renga material library file.
+  ///
+  /// Returns materials that were imported.
+  pub fn import(&mut self, transaction: &ProjectTransaction, path: &Path) -> Result<Vec<Material>> {
+    transaction.ensure_active()?;
+    if !path.exists() {
+      return Err(Error::NonexistentPath(format!("Material library does not exist: {}", path.display())));
+    }
+    let before: Vec<i32> = self.collection.clone().into_vec()?.iter().map(|entity| entity.id).collect();
+    let path = path.to_string_lossy().into_owned();
+    self.handle.call("ImportMaterialsS", Some(vec![path.into()]))?;
+    Ok(self
+      .materials()?
+      .into_iter()
+      .filter(|material| !before.contains(&material.id))
+      .collect())
+  }
+}
+
+/// Represents project layered material manager.
+///
+/// Can be obtained using [crate::Project::layered_material_manager] method.
+/// All mutating methods require active [ProjectTransaction].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_layered_material_manager.html)
+#[derive(Debug, Clone)]
+pub struct LayeredMaterialManager {
+  collection: EntityCollection,
+  handle: Dispatch
+}
+
+impl LayeredMaterialManager {
+  pub fn new(collection: EntityCollection, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("ILayeredMaterialManager handle is null".to_owned()));
+    }
+    Ok(Self { collection, handle })
+  }
+
+  /// Returns layered material with given identifier.
+  pub fn get(&self, id: i32) -> Result<LayeredMaterial> {
+    self
+      .handle
+      .call("GetLayeredMaterial", Some(vec![id.into()]))?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Returns all layered materials of the project.
+  pub fn layered_materials(&self) -> Result<Vec<LayeredMaterial>> {
+    self
+      .collection
+      .clone()
+      .into_vec()?
+      .iter()
+      .map(|entity| self.get(entity.id))
+      .collect()
+  }
+
+  /// Creates new layered material with given layers, ordered from the outer side to the inner side.
+  pub fn create(&mut self, transaction: &ProjectTransaction, name: &str, layers: &[MaterialLayer]) -> Result<LayeredMaterial> {
+    transaction.ensure_active()?;
+    LayeredMaterial::validate_layers(layers)?;
+    let handle = self
+      .handle
+      .call("CreateLayeredMaterial", Some(vec![name.into()]))?
+      .into_dispatch()?;
+    let layers_handle = handle
+      .get("Layers")?
+      .into_dispatch()?;
+    for layer in layers {
+      layers_handle.call("Add", Some(vec![layer.material_id.into(), layer.thickness.into()]))?;
+    }
+    LayeredMaterial::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for Material {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for LayeredMaterial {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test]
+  fn test_color_ole() {
+    let color = Color::new(0x12, 0x34, 0x56);
+
+    assert_eq!(color.to_ole(), 0x00563412);
+    assert_eq!(Color::from_ole(0x00563412), color);
+  }
+
+  #[test]
+  fn test_layers() {
+    let layers = vec![
+      MaterialLayer { material_id: 1, thickness: 20.0 },
+      MaterialLayer { material_id: 2, thickness: 150.0 },
+      MaterialLayer { material_id: 3, thickness: 250.0 }
+    ];
+    let material = LayeredMaterial { id: 1, name: "Wall".to_owned(), layers: layers.clone() };
+
+    assert_eq!(material.thickness(), 420.0);
+    assert!(LayeredMaterial::validate_layers(&layers).is_ok());
+    assert!(LayeredMaterial::validate_layers(&[]).is_err());
+    assert!(LayeredMaterial::validate_layers(&[MaterialLayer { material_id: 1, thickness: 0.0 }]).is_err());
+    assert!(LayeredMaterial::validate_layers(&[MaterialLayer { material_id: 1, thickness: f64::NAN }]).is_err());
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_materials(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let manager = ctx.project.material_manager()?;
+    let materials = manager.materials()?;
+
+    assert!(!materials.is_empty());
+    assert!(materials.iter().all(|material| material.id > 0));
+
+    Ok(())
+  }
+}
\ No newline at end of file
@@ -0,0 +1,315 @@
+use crate::{
+  native::{
+    constants,
+    Dispatch
+  },
+  network::{
+    FlowDirection,
+    NetworkPort,
+    PortRef
+  },
+  Result,
+  Error
+};
+use super::{
+  Entity,
+  ModelObject,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Category of an engineering system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum SystemCategory {
+  DomesticColdWater,
+  DomesticHotWater,
+  Heating,
+  DomesticSewage,
+  FireWater,
+  SupplyAir,
+  ExhaustAir,
+  Electrical,
+  Unknown(i32)
+}
+
+impl SystemCategory {
+  /// Converts native `SystemCategory` value to enum.
+  pub fn from_native(value: i32) -> Self {
+    match value {
+      constants::SYSTEM_CATEGORY_DOMESTIC_COLD_WATER => Self::DomesticColdWater,
+      constants::SYSTEM_CATEGORY_DOMESTIC_HOT_WATER => Self::DomesticHotWater,
+      constants::SYSTEM_CATEGORY_HEATING => Self::Heating,
+      constants::SYSTEM_CATEGORY_DOMESTIC_SEWAGE => Self::DomesticSewage,
+      constants::SYSTEM_CATEGORY_FIRE_WATER => Self::FireWater,
+      constants::SYSTEM_CATEGORY_SUPPLY_AIR => Self::SupplyAir,
+      constants::SYSTEM_CATEGORY_EXHAUST_AIR => Self::ExhaustAir,
+      constants::SYSTEM_CATEGORY_ELECTRICAL => Self::Electrical,
+      other => Self::Unknown(other)
+    }
+  }
+
+  /// Converts enum to native `SystemCategory` value.
+  pub fn to_native(&self) -> i32 {
+    match self {
+      Self::DomesticColdWater => constants::SYSTEM_CATEGORY_DOMESTIC_COLD_WATER,
+      Self::DomesticHotWater => constants::SYSTEM_CATEGORY_DOMESTIC_HOT_WATER,
+      Self::Heating => constants::SYSTEM_CATEGORY_HEATING,
+      Self::DomesticSewage => constants::SYSTEM_CATEGORY_DOMESTIC_SEWAGE,
+      Self::FireWater => constants::SYSTEM_CATEGORY_FIRE_WATER,
+      Self::SupplyAir => constants::SYSTEM_CATEGORY_SUPPLY_AIR,
+      Self::ExhaustAir => constants::SYSTEM_CATEGORY_EXHAUST_AIR,
+      Self::Electrical => constants::SYSTEM_CATEGORY_ELECTRICAL,
+      Self::Unknown(value) => *value
+    }
+  }
+}
+
+/// Converts native `FlowDirection` value. Unknown directions are treated as bidirectional.
+fn flow_direction_from_native(value: i32) -> FlowDirection {
+  match value {
+    constants::FLOW_DIRECTION_INLET => FlowDirection::Inlet,
+    constants::FLOW_DIRECTION_OUTLET => FlowDirection::Outlet,
+    constants::FLOW_DIRECTION_INLET_AND_OUTLET => FlowDirection::InletAndOutlet,
+    other => FlowDirection::Unknown(other)
+  }
+}
+
+/// Represents port of a pipe, duct, fitting, accessory or equipment.
+///
+/// Can be obtained using [ModelObject::ports] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_port.html)
+#[derive(Debug, Clone)]
+pub struct Port {
+  /// Identifier of the port, unique within its object.
+  pub id: UUID,
+  pub name: String,
+
+  /// Identifier of the model object the port belongs to.
+  pub object_id: i32,
+  pub flow_direction: FlowDirection,
+  handle: Dispatch
+}
+
+impl Port {
+  /// Creates new instance of Port from native handle.
+  pub fn new(object_id: i32, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IPort handle is null".to_owned()));
+    }
+    Ok(Self {
+      id: handle
+        .get("IdS")?
+        .into_string()?
+        .parse()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      object_id,
+      flow_direction: flow_direction_from_native(handle
+        .get("FlowDirection")?
+        .as_int()?),
+      handle
+    })
+  }
+
+  /// Returns port of another object, connected to this one, or `None` if port is not connected.
+  pub fn connected_port(&self) -> Result<Option<PortRef>> {
+    let Some(handle) = self
+      .handle
+      .call("GetConnectedPort", None)?
+      .as_optional_dispatch()? else {
+      return Ok(None);
+    };
+    Ok(Some(PortRef {
+      object_id: handle
+        .get("ObjectId")?
+        .as_int()?,
+      port_id: handle
+        .get("IdS")?
+        .into_string()?
+        .parse()?
+    }))
+  }
+
+  /// Returns `true` if port is connected to another port.
+  pub fn is_connected(&self) -> Result<bool> {
+    Ok(self.connected_port()?.is_some())
+  }
+
+  /// Returns snapshot of the port for [crate::network::Network].
+  pub fn to_network_port(&self) -> Result<NetworkPort> {
+    Ok(NetworkPort {
+      id: self.id.clone(),
+      name: self.name.clone(),
+      flow_direction: self.flow_direction,
+      connected_to: self.connected_port()?
+    })
+  }
+}
+
+/// Represents container of ports of a model object.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_port_container.html)
+#[derive(Debug, Clone)]
+pub struct PortContainer {
+  object_id: i32,
+  handle: Dispatch
+}
+
+impl PortContainer {
+  /// Creates new instance of PortContainer from native handle.
+  pub fn new(object_id: i32, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IPortContainer handle is null".to_owned()));
+    }
+    Ok(Self { object_id, handle })
+  }
+
+  /// Returns identifiers of all ports in the container.
+  pub fn ids(&self) -> Result<Vec<UUID>> {
+    Ok(self
+      .handle
+      .call("GetIds", None)?
+      .as_guid_vec()?
+      .into_iter()
+      .map(UUID::from)
+      .collect())
+  }
+
+  /// Returns `true` if container has port with given identifier.
+  pub fn contains(&self, id: &UUID) -> Result<bool> {
+    self
+      .handle
+      .call("ContainsS", Some(vec![id.to_string().into()]))?
+      .as_bool()
+  }
+
+  /// Returns port with given identifier or `None` if there is no such port.
+  pub fn get(&self, id: &UUID) -> Result<Option<Port>> {
+    if !self.contains(id)? {
+      return Ok(None);
+    }
+    let handle = self
+      .handle
+      .call("GetS", Some(vec![id.to_string().into()]))?
+      .into_dispatch()?;
+    Ok(Some(Port::new(self.object_id, handle)?))
+  }
+
+  /// Returns all ports in the container.
+  pub fn into_vec(self) -> Result<Vec<Port>> {
+    let mut ports = Vec::new();
+    for id in self.ids()? {
+      if let Some(port) = self.get(&id)? {
+        ports.push(port);
+      }
+    }
+    Ok(ports)
+  }
+}
+
+/// Represents engineering system: a group of pipes, ducts, fittings and equipment.
+///
+/// Can be obtained using [crate::Model::systems] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_system.html)
+#[derive(Debug, Clone)]
+pub struct EngineeringSystem {
+  /// Identifier of the system model object.
+  pub id: i32,
+
+  /// Globally unique identifier of the system model object.
+  pub unique_id: UUID,
+  pub name: String,
+
+  /// Identifier of the [SystemStyle] of the system.
+  pub style_id: i32,
+  handle: Dispatch
+}
+
+impl EngineeringSystem {
+  /// Creates new instance of EngineeringSystem from model object and its native `ISystem` handle.
+  pub fn new(object: ModelObject, handle: Dispatch) -> Result<Self> {
+    Ok(Self {
+      id: object.id,
+      unique_id: object.unique_id,
+      name: object.name,
+      style_id: handle
+        .get("SystemStyleId")?
+        .as_int()?,
+      handle
+    })
+  }
+
+  /// Returns identifiers of model objects included into the system.
+  pub fn object_ids(&self) -> Result<Vec<i32>> {
+    self
+      .handle
+      .call("GetObjectIds", None)?
+      .as_int_vec()
+  }
+}
+
+/// Style of engineering systems: a [crate::Entity] of [crate::StyleKind::System] with its category.
+///
+/// Can be obtained using [crate::Project::system_styles] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_system_style.html)
+#[derive(Debug, Clone)]
+pub struct SystemStyle {
+  pub entity: Entity,
+  pub category: SystemCategory
+}
+
+impl SystemStyle {
+  /// Creates new instance of SystemStyle from style entity.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if entity is not a system style.
+  pub fn new(entity: Entity) -> Result<Self> {
+    let handle = entity
+      .interface("ISystemStyle")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{entity} is not a system style")))?;
+    Ok(Self {
+      category: SystemCategory::from_native(handle
+        .get("SystemCategory")?
+        .as_int()?),
+      entity
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test]
+  fn test_system_category() {
+    for category in [SystemCategory::DomesticColdWater, SystemCategory::SupplyAir, SystemCategory::Unknown(42)] {
+      assert_eq!(SystemCategory::from_native(category.to_native()), category);
+    }
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_systems(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let model = ctx.project.model()?;
+    let network = model.network()?;
+
+    assert!(model.systems()?.is_empty());
+    assert!(network.is_empty());
+    assert!(network.disconnected_ports().is_empty());
+    for style in ctx.project.system_styles()? {
+      assert!(!style.entity.name.is_empty());
+    }
+
+    Ok(())
+  }
+}
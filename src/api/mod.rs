@@ -1,13 +1,45 @@
 mod application;
 mod project;
+mod project_info;
 mod entity_types;
 mod entity;
 mod uuid;
+mod model;
+mod level;
+mod parameter;
+mod property;
+mod property_set;
+mod quantity;
+mod material;
+mod style;
+mod data_exporter;
+mod export;
+mod drawing;
+mod ifc;
+mod undo;
+mod selection;
+mod view;
+mod user_interface;
+mod mep;
+mod rebar;
+mod profile;
+mod topic;
+mod assembly;
+
+pub mod units;
 
 pub use application::Application;
 pub use project::{
   Project,
-  ProjectTransaction
+  ProjectTransaction,
+  ProjectType
+};
+pub use project_info::{
+  BuildingInfo,
+  LandPlotInfo,
+  PostalAddress,
+  ProjectInfo,
+  ProjectInformation
 };
 pub use semver::Version;
 pub use guid::{
@@ -22,4 +54,124 @@ pub use entity_types::{
 pub use entity::{
   Entity,
   EntityCollection
-};
\ No newline at end of file
+};
+pub use model::{
+  Model,
+  ModelObject,
+  ModelObjectCollection
+};
+pub use level::{
+  Level,
+  Building,
+  LevelNode,
+  ObjectNode
+};
+pub use parameter::{
+  Parameter,
+  ParameterContainer,
+  ParameterDefinition,
+  ParameterValue,
+  ParameterValueType
+};
+pub use property::{
+  Property,
+  PropertyContainer,
+  PropertyDescription,
+  PropertyManager,
+  PropertyType,
+  PropertyValue
+};
+pub use property_set::{
+  PropertyAction,
+  PropertySet,
+  PropertySetEntry
+};
+pub use quantity::{
+  Quantity,
+  QuantityContainer,
+  QuantityType,
+  QuantityValue
+};
+pub use material::{
+  Color,
+  LayeredMaterial,
+  LayeredMaterialManager,
+  Material,
+  MaterialLayer,
+  MaterialManager,
+  NewMaterial,
+  ObjectMaterial
+};
+pub use style::{
+  StyleKind,
+  StyleManager
+};
+pub use data_exporter::{
+  DataExporter,
+  ExportedGrid,
+  ExportedObject
+};
+pub use export::{
+  ExportStatus,
+  OverwritePolicy
+};
+pub use drawing::{
+  Drawing,
+  DrawingFormat
+};
+pub use ifc::{
+  IfcExportSettings,
+  IfcImportOptions,
+  IfcVersion
+};
+pub use undo::{
+  UndoEntry,
+  UndoStack
+};
+pub use selection::{
+  Selection,
+  SelectionChange,
+  SelectionWatcher
+};
+pub use view::{
+  View,
+  ViewType
+};
+pub use user_interface::{
+  Action,
+  ActionConnection,
+  ActionEvent,
+  UI,
+  UiExtension
+};
+pub use mep::{
+  EngineeringSystem,
+  Port,
+  PortContainer,
+  SystemCategory,
+  SystemStyle
+};
+pub use rebar::{
+  RebarStyle,
+  RebarUsage,
+  ReinforcementModel,
+  ReinforcementUnitStyle
+};
+pub use profile::{
+  Profile,
+  ProfileDescriptionManager
+};
+pub use topic::{
+  Topic,
+  TopicEntry,
+  TopicIndex,
+  TopicNode
+};
+pub use assembly::{
+  Assembly,
+  AssemblyInstance,
+  AssemblyLibrary,
+  AssemblyNode,
+  AssemblyPart,
+  FlatObject
+};
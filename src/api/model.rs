@@ -0,0 +1,376 @@
+use std::{
+  collections::HashMap,
+  fmt::Display
+};
+use crate::{
+  geometry::Placement3D,
+  native::Dispatch,
+  Result,
+  Error
+};
+use crate::network::{
+  Network,
+  Node
+};
+use super::{
+  AssemblyInstance,
+  EngineeringSystem,
+  Level,
+  ParameterContainer,
+  PortContainer,
+  PropertyContainer,
+  QuantityContainer,
+  ReinforcementModel,
+  UUID
+};
+
+/// Represents project model - a container of all model objects.
+///
+/// Can be obtained using [crate::Project::model] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_model.html)
+#[derive(Debug, Clone)]
+pub struct Model {
+  handle: Dispatch
+}
+
+impl Model {
+  /// Creates new instance of Model from native handle.
+  ///
+  /// To get an instance of this structure without using native handles, use
+  /// the [crate::Project::model] method.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("Model handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns identifier of the model, used by [crate::Project::start_undoable_transaction].
+  pub fn id(&self) -> Result<UUID> {
+    self
+      .handle
+      .get("IdS")?
+      .into_string()?
+      .parse()
+  }
+
+  /// Returns collection of all objects in the model.
+  pub fn objects(&self) -> Result<ModelObjectCollection> {
+    let handle = self
+      .handle
+      .call("GetObjects", None)?
+      .into_dispatch()?;
+    ModelObjectCollection::new(handle)
+  }
+
+  /// Returns all levels of the model, ordered by elevation from bottom to top.
+  pub fn levels(&self) -> Result<Vec<Level>> {
+    let mut levels = Vec::new();
+    for object in self.objects()?.into_vec()? {
+      if let Some(handle) = object.interface("ILevel")? {
+        levels.push(Level::new(self.clone(), object, handle)?);
+      }
+    }
+    levels.sort_by(|a, b| a.elevation.total_cmp(&b.elevation));
+    Ok(levels)
+  }
+
+  /// Returns model objects grouped by identifier of the level they are hosted on.
+  ///
+  /// Objects which are not hosted on any level are omitted.
+  pub fn objects_by_level(&self) -> Result<HashMap<i32, Vec<ModelObject>>> {
+    let mut levels: HashMap<i32, Vec<ModelObject>> = HashMap::new();
+    for object in self.objects()?.into_vec()? {
+      if let Some(level_id) = object.level_id()? {
+        levels.entry(level_id).or_default().push(object);
+      }
+    }
+    Ok(levels)
+  }
+
+  /// Returns all engineering systems of the model.
+  pub fn systems(&self) -> Result<Vec<EngineeringSystem>> {
+    let mut systems = Vec::new();
+    for object in self.objects()?.into_vec()? {
+      if let Some(handle) = object.interface("ISystem")? {
+        systems.push(EngineeringSystem::new(object, handle)?);
+      }
+    }
+    Ok(systems)
+  }
+
+  /// Returns all assembly instances placed in the model.
+  pub fn assembly_instances(&self) -> Result<Vec<AssemblyInstance>> {
+    let mut instances = Vec::new();
+    for object in self.objects()?.into_vec()? {
+      if let Some(handle) = object.interface("IAssemblyInstance")? {
+        instances.push(AssemblyInstance::new(object, handle)?);
+      }
+    }
+    Ok(instances)
+  }
+
+  /// Builds connectivity graph of all model objects with ports.
+  ///
+  /// See [crate::network] for available queries.
+  pub fn network(&self) -> Result<Network> {
+    let mut network = Network::new();
+    for object in self.objects()?.into_vec()? {
+      let Some(ports) = object.ports()? else {
+        continue;
+      };
+      let ports = ports
+        .into_vec()?
+        .iter()
+        .map(|port| port.to_network_port())
+        .collect::<Result<Vec<_>>>()?;
+      network.add_node(Node::new(object.id, &object.name, ports));
+    }
+    Ok(network)
+  }
+}
+
+/// Represents single object of the project model.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_model_object.html)
+#[derive(Debug, Clone)]
+pub struct ModelObject {
+  /// Identifier of the object, unique within the model.
+  pub id: i32,
+
+  /// Globally unique identifier of the object.
+  pub unique_id: UUID,
+
+  /// Type of the object.
+  pub object_type: UUID,
+
+  /// Name of the object.
+  pub name: String,
+  handle: Dispatch
+}
+
+impl ModelObject {
+  /// Creates new instance of ModelObject from native handle.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("ModelObject handle is null".to_owned()));
+    }
+    Ok(Self {
+      id: handle
+        .get("Id")?
+        .as_int()?,
+      unique_id: handle
+        .get("UniqueIdS")?
+        .into_string()?
+        .parse()?,
+      object_type: handle
+        .get("ObjectTypeS")?
+        .into_string()?
+        .parse()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      handle
+    })
+  }
+
+  /// Returns identifier of the level this object is hosted on.
+  ///
+  /// Returns `None` if object can not be placed on a level (e.g. the level itself).
+  pub fn level_id(&self) -> Result<Option<i32>> {
+    match self.interface("ILevelObject")? {
+      Some(handle) => Ok(Some(handle.get("LevelId")?.as_int()?)),
+      None => Ok(None)
+    }
+  }
+
+  /// Returns placement of this object in the model coordinate system.
+  ///
+  /// Returns `None` if object has no placement of its own, e.g. it is a wall.
+  pub fn placement(&self) -> Result<Option<Placement3D>> {
+    match self.interface("IObjectWithPlacement")? {
+      Some(handle) => Ok(Some(handle
+        .call("GetPlacement", None)?
+        .into_dispatch()?
+        .try_into()?)),
+      None => Ok(None)
+    }
+  }
+
+  /// Returns ports of this object.
+  ///
+  /// Returns `None` if object has no ports, e.g. it is a wall.
+  pub fn ports(&self) -> Result<Option<PortContainer>> {
+    match self.interface("IObjectWithPorts")? {
+      Some(handle) => Ok(Some(PortContainer::new(self.id, handle
+        .call("GetPorts", None)?
+        .into_dispatch()?)?)),
+      None => Ok(None)
+    }
+  }
+
+  /// Returns reinforcement of this object.
+  ///
+  /// Returns `None` if object can not be reinforced.
+  pub fn reinforcement(&self) -> Result<Option<ReinforcementModel>> {
+    match self.interface("IObjectReinforcementModel")? {
+      Some(handle) => Ok(Some(ReinforcementModel::new(self.id, handle)?)),
+      None => Ok(None)
+    }
+  }
+
+  /// Returns container of parameters of this object.
+  pub fn parameters(&self) -> Result<ParameterContainer> {
+    self
+      .handle
+      .call("GetParameters", None)?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Returns container of user-defined properties of this object.
+  /// 
+  /// See [crate::PropertyManager] for registering properties.
+  pub fn properties(&self) -> Result<PropertyContainer> {
+    self
+      .handle
+      .call("GetProperties", None)?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Returns container of computed quantities of this object.
+  pub fn quantities(&self) -> Result<QuantityContainer> {
+    self
+      .handle
+      .call("GetQuantities", None)?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Queries native interface of the object by its name.
+  ///
+  /// Returns `None` if object does not support requested interface.
+  pub(crate) fn interface(&self, name: &str) -> Result<Option<Dispatch>> {
+    self.handle.query_interface(name)
+  }
+}
+
+impl Display for ModelObject {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "<model object #{}: {}>", self.id, match self.name.is_empty() {
+      true => "<unnamed object>",
+      false => self.name.as_str()
+    })
+  }
+}
+
+/// Collection of model objects.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_model_object_collection.html)
+#[derive(Debug, Clone)]
+pub struct ModelObjectCollection {
+  handle: Dispatch
+}
+
+impl ModelObjectCollection {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("ModelObjectCollection handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns object by its index in the collection.
+  pub fn get(&self, index: usize) -> Result<ModelObject> {
+    let idx = index as i32;
+    let handle = self
+      .handle
+      .call("GetByIndex", Some(vec![idx.into()]))?
+      .into_dispatch()?;
+    ModelObject::new(handle)
+  }
+
+  /// Returns object by its identifier or `None` if there is no such object.
+  pub fn get_by_id(&self, id: i32) -> Result<Option<ModelObject>> {
+    match self
+      .handle
+      .call("GetById", Some(vec![id.into()]))?
+      .as_optional_dispatch()? {
+      Some(handle) => Ok(Some(ModelObject::new(handle)?)),
+      None => Ok(None)
+    }
+  }
+
+  /// Returns object by its unique identifier or `None` if there is no such object.
+  pub fn get_by_unique_id(&self, unique_id: &UUID) -> Result<Option<ModelObject>> {
+    match self
+      .handle
+      .call("GetByUniqueIdS", Some(vec![unique_id.to_string().into()]))?
+      .as_optional_dispatch()? {
+      Some(handle) => Ok(Some(ModelObject::new(handle)?)),
+      None => Ok(None)
+    }
+  }
+
+  /// Returns number of objects in the collection.
+  pub fn len(&self) -> Result<usize> {
+    self
+      .handle
+      .get("Count")?
+      .as_int()
+      .map(|count| count as usize)
+  }
+
+  /// Returns `true` if collection contains no objects.
+  pub fn is_empty(&self) -> Result<bool> {
+    Ok(self.len()? == 0)
+  }
+
+  pub fn into_vec(self) -> Result<Vec<ModelObject>> {
+    let len = self.len()?;
+    let mut vec = Vec::with_capacity(len);
+    for i in 0..len {
+      vec.push(self.get(i)?);
+    }
+    Ok(vec)
+  }
+}
+
+impl TryFrom<Dispatch> for ModelObject {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for ModelObjectCollection {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_model_objects(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let objects = ctx
+      .project
+      .model()?
+      .objects()?
+      .into_vec()?;
+
+    for object in &objects {
+      assert!(object.unique_id != UUID::default());
+      assert!(object.object_type != UUID::default());
+    }
+
+    Ok(())
+  }
+}
\ No newline at end of file
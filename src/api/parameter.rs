@@ -0,0 +1,463 @@
+use std::fmt::Display;
+use crate::{
+  native::{
+    constants,
+    Dispatch,
+    Variant
+  },
+  Result,
+  Error
+};
+use super::{
+  ProjectTransaction,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Type of the value stored in a [Parameter].
+///
+/// See [Official documentation](https://help.rengabim.com/api/group___parameter_value_types.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum ParameterValueType {
+  /// Boolean value.
+  Bool,
+
+  /// Integer value.
+  Int,
+
+  /// Floating point value.
+  Double,
+
+  /// String value.
+  String,
+
+  /// Identifier of another entity (e.g. material).
+  Id,
+
+  /// Enumeration item, stored as integer.
+  Enumeration,
+
+  /// Value type unknown to this crate.
+  Unknown(i32)
+}
+
+impl ParameterValueType {
+  /// Converts native `ParameterValueType` value to enum.
+  pub fn from_native(value: i32) -> Self {
+    match value {
+      constants::PARAMETER_TYPE_BOOL => Self::Bool,
+      constants::PARAMETER_TYPE_INT => Self::Int,
+      constants::PARAMETER_TYPE_DOUBLE => Self::Double,
+      constants::PARAMETER_TYPE_STRING => Self::String,
+      constants::PARAMETER_TYPE_ID => Self::Id,
+      constants::PARAMETER_TYPE_ENUMERATION => Self::Enumeration,
+      other => Self::Unknown(other)
+    }
+  }
+}
+
+/// Typed value of a [Parameter].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum ParameterValue {
+  Bool(bool),
+  Int(i32),
+  Double(f64),
+  String(String),
+  Id(i32),
+  Enumeration(i32)
+}
+
+impl ParameterValue {
+  /// Returns type of this value.
+  pub fn value_type(&self) -> ParameterValueType {
+    match self {
+      Self::Bool(_) => ParameterValueType::Bool,
+      Self::Int(_) => ParameterValueType::Int,
+      Self::Double(_) => ParameterValueType::Double,
+      Self::String(_) => ParameterValueType::String,
+      Self::Id(_) => ParameterValueType::Id,
+      Self::Enumeration(_) => ParameterValueType::Enumeration
+    }
+  }
+
+  /// Converts native value to typed value, if possible.
+  fn from_variant(value_type: ParameterValueType, value: &Variant) -> Option<Self> {
+    match value_type {
+      ParameterValueType::Bool => value.as_bool().ok().map(Self::Bool),
+      ParameterValueType::Int => value.as_int().ok().map(Self::Int),
+      ParameterValueType::Double => value.as_f64().ok().map(Self::Double),
+      ParameterValueType::String => value.into_string().ok().map(Self::String),
+      ParameterValueType::Id => value.as_int().ok().map(Self::Id),
+      ParameterValueType::Enumeration => value.as_int().ok().map(Self::Enumeration),
+      ParameterValueType::Unknown(_) => None
+    }
+  }
+
+  /// Returns value as floating point number, if it is numeric.
+  pub fn as_f64(&self) -> Option<f64> {
+    match self {
+      Self::Double(value) => Some(*value),
+      Self::Int(value) => Some(*value as f64),
+      _ => None
+    }
+  }
+}
+
+impl Display for ParameterValue {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Bool(value) => write!(f, "{value}"),
+      Self::Int(value) => write!(f, "{value}"),
+      Self::Double(value) => write!(f, "{value}"),
+      Self::String(value) => write!(f, "{value}"),
+      Self::Id(value) => write!(f, "#{value}"),
+      Self::Enumeration(value) => write!(f, "enum({value})")
+    }
+  }
+}
+
+/// Definition of a [Parameter]: its identifier, name, type and constraints.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_parameter_definition.html)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct ParameterDefinition {
+  /// Unique identifier of the parameter.
+  pub id: UUID,
+
+  /// Name of the parameter.
+  pub name: String,
+
+  /// Type of the parameter value.
+  pub value_type: ParameterValueType,
+
+  /// Minimal allowed value, if parameter is numeric and constrained.
+  pub min: Option<f64>,
+
+  /// Maximal allowed value, if parameter is numeric and constrained.
+  pub max: Option<f64>,
+
+  /// Default value of the parameter, if any.
+  pub default: Option<ParameterValue>
+}
+
+impl ParameterDefinition {
+  /// Reads definition from native `IParameterDefinition` handle.
+  ///
+  /// Range is read for numeric parameters only, unbounded limits are returned as `None`.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IParameterDefinition handle is null".to_owned()));
+    }
+    let value_type = ParameterValueType::from_native(handle
+      .get("ParameterValueType")?
+      .as_int()?
+    );
+    let numeric = matches!(value_type, ParameterValueType::Int | ParameterValueType::Double);
+    let limit = |name: &str| -> Result<Option<f64>> {
+      if !numeric {
+        return Ok(None);
+      }
+      let value = handle
+        .get(name)?
+        .as_f64()?;
+      Ok(Some(value).filter(|value| value.is_finite() && value.abs() < f64::MAX))
+    };
+    let default = ParameterValue::from_variant(value_type, &handle.get("DefaultValue")?);
+    Ok(Self {
+      id: handle
+        .get("IdS")?
+        .into_string()?
+        .parse()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      value_type,
+      min: limit("MinValue")?,
+      max: limit("MaxValue")?,
+      default
+    })
+  }
+
+  /// Checks that given value can be assigned to parameter with this definition.
+  ///
+  /// Can return error in the following cases:
+  /// - [crate::Error::TypeMismatch]: Value type does not match the parameter type;
+  /// - [crate::Error::InvalidOperation]: Value is out of allowed range;
+  pub fn validate(&self, value: &ParameterValue) -> Result<()> {
+    if value.value_type() != self.value_type {
+      return Err(Error::TypeMismatch(format!(
+        "parameter `{}` expects {:?}, got {:?}",
+        self.name,
+        self.value_type,
+        value.value_type()
+      )));
+    }
+    if let Some(number) = value.as_f64() {
+      let below = matches!(self.min, Some(min) if number < min);
+      let above = matches!(self.max, Some(max) if number > max);
+      if below || above {
+        return Err(Error::InvalidOperation(format!(
+          "value {number} of parameter `{}` is out of range [{}, {}]",
+          self.name,
+          self.min.map_or("-inf".to_owned(), |min| min.to_string()),
+          self.max.map_or("+inf".to_owned(), |max| max.to_string())
+        )));
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Represents single parameter of an object or style.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_parameter.html)
+#[derive(Debug, Clone)]
+pub struct Parameter {
+  /// Definition of the parameter.
+  pub definition: ParameterDefinition,
+  handle: Dispatch
+}
+
+impl Parameter {
+  /// Creates new instance of Parameter from native handle.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IParameter handle is null".to_owned()));
+    }
+    Ok(Self {
+      definition: handle
+        .get("Definition")?
+        .into_dispatch()?
+        .try_into()?,
+      handle
+    })
+  }
+
+  /// Returns current value of the parameter or `None` if value is not set.
+  pub fn value(&self) -> Result<Option<ParameterValue>> {
+    if !self.handle.call("HasValue", None)?.as_bool()? {
+      return Ok(None);
+    }
+    let value = match self.definition.value_type {
+      ParameterValueType::Bool => ParameterValue::Bool(self.handle.call("GetBoolValue", None)?.as_bool()?),
+      ParameterValueType::Int => ParameterValue::Int(self.handle.call("GetIntValue", None)?.as_int()?),
+      ParameterValueType::Double => ParameterValue::Double(self.handle.call("GetDoubleValue", None)?.as_f64()?),
+      ParameterValueType::String => ParameterValue::String(self.handle.call("GetStringValue", None)?.into_string()?),
+      ParameterValueType::Id => ParameterValue::Id(self.handle.call("GetIntValue", None)?.as_int()?),
+      ParameterValueType::Enumeration => ParameterValue::Enumeration(self.handle.call("GetIntValue", None)?.as_int()?),
+      ParameterValueType::Unknown(value_type) => {
+        return Err(Error::TypeMismatch(format!("unsupported parameter value type {value_type}")))
+      }
+    };
+    Ok(Some(value))
+  }
+
+  /// Sets value of the parameter.
+  ///
+  /// Can return error in the following cases:
+  /// - [crate::Error::TypeMismatch]: Value type does not match the parameter type;
+  /// - [crate::Error::InvalidOperation]: Value is out of allowed range;
+  pub fn set_value(&mut self, transaction: &ProjectTransaction, value: ParameterValue) -> Result<()> {
+    transaction.ensure_active()?;
+    self.definition.validate(&value)?;
+    match value {
+      ParameterValue::Bool(value) => self.handle.call("SetBoolValue", Some(vec![value.into()]))?,
+      ParameterValue::Int(value)
+        | ParameterValue::Id(value)
+        | ParameterValue::Enumeration(value) => self.handle.call("SetIntValue", Some(vec![value.into()]))?,
+      ParameterValue::Double(value) => self.handle.call("SetDoubleValue", Some(vec![value.into()]))?,
+      ParameterValue::String(value) => self.handle.call("SetStringValue", Some(vec![value.into()]))?
+    };
+    Ok(())
+  }
+}
+
+/// Container of object or style parameters.
+///
+/// Can be obtained using [crate::ModelObject::parameters] or [crate::Entity::parameters] methods.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_parameter_container.html)
+#[derive(Debug, Clone)]
+pub struct ParameterContainer {
+  handle: Dispatch
+}
+
+impl ParameterContainer {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IParameterContainer handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns identifiers of all parameters in the container.
+  pub fn ids(&self) -> Result<Vec<UUID>> {
+    Ok(self
+      .handle
+      .call("GetIds", None)?
+      .as_guid_vec()?
+      .into_iter()
+      .map(UUID::from)
+      .collect())
+  }
+
+  /// Returns `true` if container has parameter with given identifier.
+  pub fn contains(&self, id: &UUID) -> Result<bool> {
+    self
+      .handle
+      .call("ContainsS", Some(vec![id.to_string().into()]))?
+      .as_bool()
+  }
+
+  /// Returns parameter with given identifier or `None` if there is no such parameter.
+  pub fn get(&self, id: &UUID) -> Result<Option<Parameter>> {
+    if !self.contains(id)? {
+      return Ok(None);
+    }
+    let handle = self
+      .handle
+      .call("GetS", Some(vec![id.to_string().into()]))?
+      .into_dispatch()?;
+    Ok(Some(Parameter::new(handle)?))
+  }
+
+  /// Returns parameter with given name or `None` if there is no such parameter.
+  ///
+  /// Name can be qualified with the parameter group, e.g. `Dimensions.Width`, or short, e.g. `Width`.
+  /// Returns [crate::Error::InvalidOperation] if short name is ambiguous.
+  pub fn get_by_name(&self, name: &str) -> Result<Option<Parameter>> {
+    find_by_name(self.to_vec()?, name, |parameter| &parameter.definition.name)
+  }
+
+  /// Returns all parameters in the container.
+  pub fn to_vec(&self) -> Result<Vec<Parameter>> {
+    let ids = self.ids()?;
+    let mut vec = Vec::with_capacity(ids.len());
+    for id in &ids {
+      if let Some(parameter) = self.get(id)? {
+        vec.push(parameter);
+      }
+    }
+    Ok(vec)
+  }
+}
+
+/// Finds item by exact name, falling back to the name without group prefix (`Group.Name`).
+fn find_by_name<T>(items: Vec<T>, name: &str, item_name: impl Fn(&T) -> &str) -> Result<Option<T>> {
+  let short_name = |full: &str| full.rsplit('.').next().unwrap_or(full).to_owned();
+  let mut candidates = Vec::new();
+  for item in items {
+    if item_name(&item) == name {
+      return Ok(Some(item));
+    }
+    if !name.contains('.') && short_name(item_name(&item)) == name {
+      candidates.push(item);
+    }
+  }
+  if candidates.len() > 1 {
+    return Err(Error::InvalidOperation(format!(
+      "parameter name `{name}` is ambiguous: {}",
+      candidates.iter().map(&item_name).collect::<Vec<_>>().join(", ")
+    )));
+  }
+  Ok(candidates.pop())
+}
+
+impl TryFrom<Dispatch> for ParameterDefinition {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for Parameter {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for ParameterContainer {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  fn width() -> ParameterDefinition {
+    ParameterDefinition {
+      id: UUID::default(),
+      name: "Width".to_owned(),
+      value_type: ParameterValueType::Double,
+      min: Some(480.0),
+      max: Some(600.0),
+      default: Some(ParameterValue::Double(600.0))
+    }
+  }
+
+  #[test]
+  fn test_validate() {
+    let definition = width();
+
+    assert!(definition.validate(&ParameterValue::Double(500.0)).is_ok());
+    assert!(definition.validate(&ParameterValue::Double(600.0)).is_ok());
+    assert!(matches!(
+      definition.validate(&ParameterValue::Double(700.0)),
+      Err(Error::InvalidOperation(_))
+    ));
+    assert!(matches!(
+      definition.validate(&ParameterValue::String("500".to_owned())),
+      Err(Error::TypeMismatch(_))
+    ));
+  }
+
+  #[test]
+  fn test_find_by_name() -> anyhow::Result<()> {
+    let names = || vec!["Dimensions.Width", "Dimensions.Height", "Water.ConnectionType", "Gas.ConnectionType", "Mark"];
+    let find = |name: &str| super::find_by_name(names(), name, |name| name);
+
+    assert_eq!(find("Dimensions.Width")?, Some("Dimensions.Width"));
+    assert_eq!(find("Width")?, Some("Dimensions.Width"));
+    assert_eq!(find("Water.ConnectionType")?, Some("Water.ConnectionType"));
+    assert_eq!(find("Mark")?, Some("Mark"));
+    assert_eq!(find("Electric.ConnectionType")?, None);
+    assert_eq!(find("Depth")?, None);
+    assert!(matches!(find("ConnectionType"), Err(Error::InvalidOperation(_))));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_value_type_from_native() {
+    assert_eq!(ParameterValueType::from_native(3), ParameterValueType::Double);
+    assert_eq!(ParameterValueType::from_native(42), ParameterValueType::Unknown(42));
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_style_parameters(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let file = external_file("style_category.rst")?;
+    let category = ctx.project.transact(|transaction| transaction.import_category(Category::Equipment, &file))?;
+
+    let parameters = category.parameters()?;
+    let width = parameters.get_by_name("Width")?.unwrap();
+
+    assert_eq!(width.definition.value_type, ParameterValueType::Double);
+    assert_eq!(width.value()?, Some(ParameterValue::Double(600.0)));
+
+    Ok(())
+  }
+}
\ No newline at end of file
@@ -0,0 +1,171 @@
+use std::path::Path;
+use crate::{
+  geometry::{
+    Region2D,
+    SectionProperties
+  },
+  native::Dispatch,
+  Result,
+  Error
+};
+use super::{
+  Entity,
+  EntityCollection,
+  ProjectTransaction
+};
+
+/// Represents profile: a cross-section of beams, columns and plates, e.g. `I 20B1` from
+/// a steel catalog. Profile is an instance of a [ProfileDescriptionManager::descriptions]
+/// entry with its parameter values.
+///
+/// Can be obtained using [crate::Project::profiles] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_profile.html)
+#[derive(Debug, Clone)]
+pub struct Profile {
+  pub entity: Entity,
+
+  /// Identifier of the profile description this profile is based on.
+  pub description_id: i32,
+  handle: Dispatch
+}
+
+impl Profile {
+  /// Creates new instance of Profile from profile entity.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if entity is not a profile.
+  pub fn new(entity: Entity) -> Result<Self> {
+    let handle = entity
+      .interface("IProfile")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{entity} is not a profile")))?;
+    Ok(Self {
+      description_id: handle
+        .get("ProfileDescriptionId")?
+        .as_int()?,
+      entity,
+      handle
+    })
+  }
+
+  /// Returns section geometry of the profile, splitting arcs into `arc_segments` segments.
+  pub fn region(&self, arc_segments: usize) -> Result<Region2D> {
+    let handle = self
+      .handle
+      .call("GetRegion", None)?
+      .into_dispatch()?;
+    Region2D::from_native(&handle, arc_segments)
+  }
+
+  /// Computes section properties of the profile, see [SectionProperties].
+  pub fn section_properties(&self, arc_segments: usize) -> Result<SectionProperties> {
+    Ok(self.region(arc_segments)?.section_properties())
+  }
+}
+
+/// Represents project profile description manager.
+///
+/// Profile descriptions are parametric section templates (I-beam, channel, tube etc.),
+/// their parameters are available with [Entity::parameters].
+/// Can be obtained using [crate::Project::profile_description_manager] method.
+/// All mutating methods require active [ProjectTransaction].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_profile_description_manager.html)
+#[derive(Debug, Clone)]
+pub struct ProfileDescriptionManager {
+  profiles: EntityCollection,
+  handle: Dispatch
+}
+
+impl ProfileDescriptionManager {
+  pub fn new(profiles: EntityCollection, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IProfileDescriptionManager handle is null".to_owned()));
+    }
+    Ok(Self { profiles, handle })
+  }
+
+  /// Returns all profile descriptions of the project.
+  pub fn descriptions(&self) -> Result<Vec<Entity>> {
+    self
+      .handle
+      .call("GetProfileDescriptions", None)?
+      .into_dispatch()
+      .and_then(EntityCollection::new)
+      .and_then(EntityCollection::into_vec)
+  }
+
+  /// Returns profile description with given name or `None` if there is no such description.
+  pub fn find(&self, name: &str) -> Result<Option<Entity>> {
+    Ok(self
+      .descriptions()?
+      .into_iter()
+      .find(|description| description.name == name))
+  }
+
+  /// Returns all profiles based on given description.
+  pub fn profiles(&self, description: &Entity) -> Result<Vec<Profile>> {
+    Ok(self
+      .all_profiles()?
+      .into_iter()
+      .filter(|profile| profile.description_id == description.id)
+      .collect())
+  }
+
+  /// Imports profiles from Renga profile catalog file.
+  ///
+  /// Returns profiles that were imported.
+  pub fn import(&mut self, transaction: &ProjectTransaction, path: &Path) -> Result<Vec<Profile>> {
+    transaction.ensure_active()?;
+    if !path.exists() {
+      return Err(Error::NonexistentPath(format!("Profile catalog does not exist: {}", path.display())));
+    }
+    let before: Vec<i32> = self.profiles.clone().into_vec()?.iter().map(|entity| entity.id).collect();
+    let path = path.to_string_lossy().into_owned();
+    self.handle.call("ImportProfilesS", Some(vec![path.into()]))?;
+    Ok(self
+      .all_profiles()?
+      .into_iter()
+      .filter(|profile| !before.contains(&profile.entity.id))
+      .collect())
+  }
+
+  fn all_profiles(&self) -> Result<Vec<Profile>> {
+    self
+      .profiles
+      .clone()
+      .into_vec()?
+      .into_iter()
+      .map(Profile::new)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_profiles(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let mut manager = ctx.project.profile_description_manager()?;
+    let descriptions = manager.descriptions()?;
+
+    assert!(!descriptions.is_empty());
+    for profile in ctx.project.profiles()? {
+      assert!(descriptions.iter().any(|description| description.id == profile.description_id));
+      let section = profile.section_properties(64)?;
+      assert!(section.area > 0.0);
+      assert!(section.ix > 0.0 && section.iy > 0.0);
+    }
+
+    let transaction = ctx.project.start_transaction()?;
+    assert!(matches!(
+      manager.import(&transaction, std::path::Path::new("missing.rnprof")),
+      Err(Error::NonexistentPath(_))
+    ));
+    transaction.rollback()?;
+
+    Ok(())
+  }
+}
@@ -1,38 +1,158 @@
-use std::path::{
-  Path, 
-  PathBuf
+use std::{
+  collections::HashMap,
+  ops::Deref,
+  path::{
+    Path, 
+    PathBuf
+  }
 };
 use crate::{
-  native::Dispatch,
+  native::{
+    constants,
+    Dispatch,
+    Variant
+  },
+  reinforcement::RebarSchedule,
+  table,
+  OperationError,
   Result,
   Error
 };
 use super::{
+  drawing,
+  Assembly,
+  BuildingInfo,
   Category, 
+  DataExporter,
+  Drawing,
+  DrawingFormat,
   Entity, 
-  EntityCollection
+  EntityCollection,
+  ExportStatus,
+  IfcExportSettings,
+  LandPlotInfo,
+  LayeredMaterialManager,
+  MaterialManager,
+  Model,
+  OverwritePolicy,
+  Profile,
+  ProfileDescriptionManager,
+  ProjectInfo,
+  ProjectInformation,
+  PropertyManager,
+  RebarStyle,
+  ReinforcementUnitStyle,
+  StyleKind,
+  StyleManager,
+  SystemStyle,
+  Topic,
+  UndoStack,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
 };
 
+/// Type of Renga project file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum ProjectType {
+  /// Regular project (`.rnp`).
+  Project,
+
+  /// Project template (`.rnt`).
+  Template
+}
+
+impl ProjectType {
+  /// Returns file extension without leading dot.
+  pub fn extension(&self) -> &'static str {
+    match self {
+      Self::Project => "rnp",
+      Self::Template => "rnt"
+    }
+  }
+
+  /// Converts native `ProjectType` value to enum.
+  pub fn from_native(value: i32) -> Result<Self> {
+    match value {
+      constants::PROJECT_TYPE_PROJECT => Ok(Self::Project),
+      constants::PROJECT_TYPE_TEMPLATE => Ok(Self::Template),
+      other => Err(Error::TypeMismatch(format!("Unknown project type: {other}")))
+    }
+  }
+
+  /// Converts enum to native `ProjectType` value.
+  pub fn to_native(&self) -> i32 {
+    match self {
+      Self::Project => constants::PROJECT_TYPE_PROJECT,
+      Self::Template => constants::PROJECT_TYPE_TEMPLATE
+    }
+  }
+
+  /// Checks that `path` can be used as a file of this type: parent directory
+  /// must exist and extension must match [ProjectType::extension].
+  pub(crate) fn validate_path(&self, path: &Path) -> Result<()> {
+    if path.file_name().is_none() || path.is_dir() {
+      return Err(Error::InvalidOperation(format!("Not a file path: {}", path.display())));
+    }
+    if let Some(parent) = path.parent() {
+      if !parent.as_os_str().is_empty() && !parent.is_dir() {
+        return Err(Error::NonexistentPath(format!("Directory does not exist: {}", parent.display())));
+      }
+    }
+    let matches = path
+      .extension()
+      .map_or(false, |ext| ext.eq_ignore_ascii_case(self.extension()));
+    match matches {
+      true => Ok(()),
+      false => Err(Error::InvalidOperation(format!(
+        "Expected .{} file: {}", 
+        self.extension(), 
+        path.display()
+      )))
+    }
+  }
+}
+
 /// Represents active Renga project.
 /// 
 /// Provides methods to work with a Renga project. Can be obtained using [crate::Application::project] method.
 /// 
-/// By default, project is immutable. To achieve mutability, you must follow this steps:
-/// - Create new transaction using [Project::start_transaction] method. Store it in a mutable variable.
+/// By default, project is immutable. All mutating methods require a [ProjectTransaction]:
+/// - Create new transaction using [Project::start_transaction] method. Transaction borrows the project 
+///   mutably, so the project can be accessed only through the transaction until it is finished.
 /// - Do something with the project.
 /// - Commit the transaction using [ProjectTransaction::commit] or discard it using [ProjectTransaction::rollback] methods.
+///   Transaction which is dropped without commit is rolled back.
 /// 
 /// For example:
 /// ```no_run
 /// use renga_api_rs as renga;
 /// let mut app = renga::Application::new().unwrap();
 /// let mut project = app.new_project().unwrap();
-/// let mut transaction = project.start_transaction().unwrap();
+/// let transaction = project.start_transaction().unwrap();
 /// 
 /// // do something with the project
 /// transaction.commit().unwrap();
 /// ```
 /// 
+/// Or, using [Project::transact]:
+/// ```no_run
+/// use renga_api_rs as renga;
+/// let mut app = renga::Application::new().unwrap();
+/// let mut project = app.new_project().unwrap();
+/// project.transact(|transaction| {
+///   transaction.set_project_info(&renga::ProjectInfo {
+///     code: Some("2024-017".to_owned()),
+///     ..Default::default()
+///   })
+/// }).unwrap();
+/// ```
+/// 
 /// You can safely clone this struct and use it in multiple threads.
 /// 
 /// See [Official documentation](https://help.rengabim.com/api/interface_i_project.html)
@@ -83,17 +203,34 @@ impl Project {
     }
   }
 
-  // pub fn save_as(&self, path: &Path) -> Result<&Self> {
-  //   let str = path.to_string_lossy().to_string();
-  //   let error = self
-  //     .handle
-  //     .call("SaveAs", Some(vec![str.into()]))?
-  //     .as_int()?;
-  //   if error != 0 {
-  //     return Err(Error::Internal(format!("Failed to save project: error code {error}")));
-  //   }
-  //   Ok(self)
-  // }
+  /// Saves project (or template) to a new file.
+  /// 
+  /// Path is validated before saving, see [ProjectType::extension].
+  /// 
+  /// Can return error in the following cases:
+  /// - [crate::Error::NonexistentPath]: Target directory does not exist;
+  /// - [crate::Error::InvalidOperation]: Path is not a file path or has wrong extension;
+  /// - [crate::Error::Operation]: Renga failed to save file, e.g. 
+  ///   [crate::OperationError::FileAlreadyExists] with [OverwritePolicy::Fail];
+  pub fn save_as(&self, path: &Path, project_type: ProjectType, overwrite: OverwritePolicy) -> Result<ExportStatus> {
+    project_type.validate_path(path)?;
+    if overwrite.skips(path) {
+      return Ok(ExportStatus::Skipped);
+    }
+    let path = path.to_string_lossy().into_owned();
+    let code = self
+      .handle
+      .call("SaveAs", Some(vec![path.into(), project_type.to_native().into(), overwrite.overwrite().into()]))?
+      .as_int()?;
+    OperationError::check(code)?;
+    Ok(ExportStatus::Exported)
+  }
+
+  /// Returns type of the project.
+  #[inline]
+  pub fn project_type(&self) -> Result<ProjectType> {
+    ProjectType::from_native(self.handle.get("ProjectType")?.as_int()?)
+  }
 
   /// Returns `true`` if project has unsaved changes.
   #[inline]
@@ -118,12 +255,53 @@ impl Project {
   /// Creates new transaction.
   /// 
   /// See [ProjectTransaction] for more information.
-  pub fn start_transaction(&mut self) -> Result<ProjectTransaction> {
+  pub fn start_transaction(&mut self) -> Result<ProjectTransaction<'_>> {
     if self.has_transaction()? {
       return Err(Error::InvalidOperation("Project already has an active transaction".to_owned()));
     }
     let handle = self.handle.call("CreateOperation", None)?.into_dispatch()?;
-    ProjectTransaction::new(handle)
+    ProjectTransaction::new(self, handle)
+  }
+
+  /// Runs `f` in a new transaction. 
+  /// 
+  /// Transaction is committed if `f` returns `Ok` and rolled back if it returns `Err` or panics.
+  pub fn transact<T, F>(&mut self, f: F) -> Result<T>
+  where
+    F: FnOnce(&ProjectTransaction<'_>) -> Result<T>
+  {
+    let transaction = self.start_transaction()?;
+    let value = f(&transaction)?;
+    transaction.commit()?;
+    Ok(value)
+  }
+
+  /// Creates new transaction which is recorded in Undo history of the model, 
+  /// so user can undo changes made in it.
+  /// 
+  /// Model identifier can be obtained with [Model::id].
+  /// 
+  /// See [Project::start_transaction] and [Project::undo_stack]
+  pub fn start_undoable_transaction(&mut self, model_id: &UUID) -> Result<ProjectTransaction<'_>> {
+    if self.has_transaction()? {
+      return Err(Error::InvalidOperation("Project already has an active transaction".to_owned()));
+    }
+    let model_id = self.handle.guid_argument("CreateOperationWithUndo", 0, &model_id.into())?;
+    let handle = self
+      .handle
+      .call("CreateOperationWithUndo", Some(vec![model_id]))?
+      .into_dispatch()?;
+    ProjectTransaction::new(self, handle)
+  }
+
+  /// Returns Undo history of the model.
+  pub fn undo_stack(&self, model_id: &UUID) -> Result<UndoStack> {
+    let model_id = self.handle.guid_argument("GetUndoStack", 0, &model_id.into())?;
+    self
+      .handle
+      .call("GetUndoStack", Some(vec![model_id]))?
+      .into_dispatch()?
+      .try_into()
   }
 
   /// Returns `true` if project has an active transaction.
@@ -168,42 +346,382 @@ impl Project {
       .try_into()?)
   }
 
-  // todo: return entity instead of ()
-  pub fn import_category(&mut self, category: Category, path: &Path) -> Result<Entity> {
-    // if !self.has_transaction()? {
-    //   return Err(Error::NoActiveTransaction);
-    // }
-    let category_str = category.to_sanitized_string();
-    let path_str: String = path.to_string_lossy().to_string();
-    let entity: Entity = self
+  /// Returns the project model.
+  /// 
+  /// See [Model] for more information.
+  pub fn model(&self) -> Result<Model> {
+    let handle = self
       .handle
-      .call("ImportCategoryS", Some(vec![category_str.into(), path_str.into()]))?
+      .get("Model")?
+      .into_dispatch()?;
+    Model::new(handle)
+  }
+
+  /// Returns project property manager.
+  /// 
+  /// See [PropertyManager] for more information.
+  pub fn property_manager(&self) -> Result<PropertyManager> {
+    self
+      .handle
+      .get("PropertyManager")?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Returns project material manager.
+  /// 
+  /// See [MaterialManager] for more information.
+  pub fn material_manager(&self) -> Result<MaterialManager> {
+    let collection = self
+      .handle
+      .get("Materials")?
       .into_dispatch()?
       .try_into()?;
-    Ok(entity)
+    let handle = self
+      .handle
+      .get("MaterialManager")?
+      .into_dispatch()?;
+    MaterialManager::new(collection, handle)
+  }
+
+  /// Returns project layered material manager.
+  /// 
+  /// See [LayeredMaterialManager] for more information.
+  pub fn layered_material_manager(&self) -> Result<LayeredMaterialManager> {
+    let collection = self
+      .handle
+      .get("LayeredMaterials")?
+      .into_dispatch()?
+      .try_into()?;
+    let handle = self
+      .handle
+      .get("LayeredMaterialManager")?
+      .into_dispatch()?;
+    LayeredMaterialManager::new(collection, handle)
+  }
+
+  /// Returns project profile description manager.
+  ///
+  /// See [ProfileDescriptionManager] for more information.
+  pub fn profile_description_manager(&self) -> Result<ProfileDescriptionManager> {
+    let profiles = self
+      .handle
+      .get("Profiles")?
+      .into_dispatch()?
+      .try_into()?;
+    let handle = self
+      .handle
+      .get("ProfileDescriptionManager")?
+      .into_dispatch()?;
+    ProfileDescriptionManager::new(profiles, handle)
+  }
+
+  /// Returns all profiles of the project.
+  pub fn profiles(&self) -> Result<Vec<Profile>> {
+    self
+      .entities("Profiles")?
+      .into_iter()
+      .map(Profile::new)
+      .collect()
+  }
+
+  /// Returns style manager for styles of given kind.
+  /// 
+  /// See [StyleManager] for more information.
+  pub fn style_manager(&self, kind: StyleKind) -> Result<StyleManager> {
+    let collection = self
+      .handle
+      .get(kind.collection_property().as_str())?
+      .into_dispatch()?
+      .try_into()?;
+    let handle = self
+      .handle
+      .get(kind.manager_property().as_str())?
+      .into_dispatch()?;
+    StyleManager::new(kind, collection, handle)
+  }
+
+  /// Returns all styles of engineering systems with their categories.
+  pub fn system_styles(&self) -> Result<Vec<SystemStyle>> {
+    self
+      .style_manager(StyleKind::System)?
+      .styles()?
+      .into_iter()
+      .map(SystemStyle::new)
+      .collect()
+  }
+
+  /// Returns all reinforcement grades (steel classes) of the project.
+  pub fn reinforcement_grades(&self) -> Result<Vec<Entity>> {
+    self.entities("ReinforcementGrades")
+  }
+
+  /// Returns all styles of reinforcing bars.
+  pub fn rebar_styles(&self) -> Result<Vec<RebarStyle>> {
+    self
+      .entities("RebarStyles")?
+      .into_iter()
+      .map(RebarStyle::new)
+      .collect()
+  }
+
+  /// Returns all reinforcement styles: reinforcement models assignable to columns, beams, etc.
+  pub fn reinforcement_styles(&self) -> Result<Vec<Entity>> {
+    self.entities("ReinforcementStyles")
+  }
+
+  /// Returns all styles of reinforcement units.
+  ///
+  /// Use [Project::style_manager] with [StyleKind::ReinforcementUnit] to create or edit them.
+  pub fn reinforcement_unit_styles(&self) -> Result<Vec<ReinforcementUnitStyle>> {
+    self
+      .style_manager(StyleKind::ReinforcementUnit)?
+      .styles()?
+      .into_iter()
+      .map(ReinforcementUnitStyle::new)
+      .collect()
+  }
+
+  /// Computes rebar schedule of the whole model.
+  ///
+  /// Includes bars placed directly into model objects and bars of reinforcement units.
+  /// Returns [crate::Error::InvalidOperation] if a bar has unknown style or its style has unknown grade,
+  /// so the schedule never silently misses bars.
+  pub fn rebar_schedule(&self) -> Result<RebarSchedule> {
+    let grades = self.reinforcement_grades()?;
+    let styles = self.rebar_styles()?;
+    let mut units = HashMap::new();
+    for unit in self.reinforcement_unit_styles()? {
+      units.insert(unit.entity.id, unit.rebar_usages()?);
+    }
+    let mut rebars = Vec::new();
+    for object in self.model()?.objects()?.into_vec()? {
+      let Some(reinforcement) = object.reinforcement()? else {
+        continue;
+      };
+      let mut usages = reinforcement.rebar_usages()?;
+      for style_id in reinforcement.unit_style_ids()? {
+        if let Some(unit) = units.get(&style_id) {
+          usages.extend_from_slice(unit);
+        }
+      }
+      for usage in &usages {
+        rebars.push(usage.to_rebar(&styles, &grades)?);
+      }
+    }
+    Ok(RebarSchedule::from_rebars(rebars))
+  }
+
+  /// Returns all assembly definitions of the project.
+  ///
+  /// See [crate::AssemblyLibrary] for flattening assembly instances.
+  pub fn assemblies(&self) -> Result<Vec<Assembly>> {
+    self
+      .entities("Assemblies")?
+      .into_iter()
+      .map(Assembly::new)
+      .collect()
+  }
+
+  /// Returns all topics of the project.
+  ///
+  /// See [crate::TopicIndex] for mapping between topics and entities.
+  pub fn topics(&self) -> Result<Vec<Topic>> {
+    self
+      .entities("Topics")?
+      .into_iter()
+      .map(Topic::new)
+      .collect()
+  }
+
+  /// Returns number of the entity with given unique identifier inside its topic.
+  ///
+  /// Returns `None` if entity is not included into any topic.
+  pub fn entity_number_in_topic(&self, unique_id: &UUID) -> Result<Option<i32>> {
+    let number = self
+      .handle
+      .call("GetEntityNumberInTopicS", Some(vec![unique_id.to_string().into()]))?
+      .as_int()?;
+    match number > 0 {
+      true => Ok(Some(number)),
+      false => Ok(None)
+    }
+  }
+
+  fn entities(&self, property: &str) -> Result<Vec<Entity>> {
+    self
+      .handle
+      .get(property)?
+      .into_dispatch()
+      .and_then(EntityCollection::new)
+      .and_then(EntityCollection::into_vec)
+  }
+
+  /// Returns project data exporter.
+  /// 
+  /// See [DataExporter] for more information.
+  pub fn data_exporter(&self) -> Result<DataExporter> {
+    self
+      .handle
+      .get("DataExporter")?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Returns general information about the project.
+  pub fn project_info(&self) -> Result<ProjectInfo> {
+    ProjectInfo::read(&self.handle.get("ProjectInfo")?.into_dispatch()?)
+  }
+
+  /// Returns information about the building.
+  pub fn building_info(&self) -> Result<BuildingInfo> {
+    BuildingInfo::read(&self.handle.get("BuildingInfo")?.into_dispatch()?)
+  }
+
+  /// Returns information about the land plot.
+  pub fn land_plot_info(&self) -> Result<LandPlotInfo> {
+    LandPlotInfo::read(&self.handle.get("LandPlotInfo")?.into_dispatch()?)
+  }
+
+  /// Returns all drawings of the project.
+  pub fn drawings(&self) -> Result<Vec<Drawing>> {
+    let formats: HashMap<i32, String> = self
+      .handle
+      .get("PageFormatStyles")?
+      .into_dispatch()
+      .and_then(EntityCollection::new)
+      .and_then(EntityCollection::into_vec)?
+      .into_iter()
+      .map(|style| (style.id, style.name))
+      .collect();
+    let collection = self
+      .handle
+      .get("Drawings")?
+      .into_dispatch()?;
+    let count = collection.get("Count")?.as_int()?;
+    let mut drawings = Vec::with_capacity(count.max(0) as usize);
+    for index in 0..count {
+      let handle = collection
+        .call("Get", Some(vec![index.into()]))?
+        .into_dispatch()?;
+      let format_id = handle
+        .get("PageFormatStyleId")?
+        .as_int()?;
+      let mut drawing = Drawing::new(handle)?;
+      drawing.sheet_format = formats.get(&format_id).cloned();
+      drawings.push(drawing);
+    }
+    Ok(drawings)
+  }
+
+  /// Exports given drawings into a single multi-page file.
+  /// 
+  /// Returns [crate::OperationError::NothingToExport] for empty selection. Other native result
+  /// codes are mapped to [crate::OperationError] as well.
+  pub fn export_drawings(&self, drawings: &[Drawing], path: &Path, format: DrawingFormat, overwrite: OverwritePolicy) -> Result<ExportStatus> {
+    if drawings.is_empty() {
+      return Err(OperationError::NothingToExport.into());
+    }
+    if overwrite.skips(path) {
+      return Ok(ExportStatus::Skipped);
+    }
+    let ids: Vec<String> = drawings
+      .iter()
+      .map(|drawing| drawing.unique_id.to_string())
+      .collect();
+    let path = path.to_string_lossy().into_owned();
+    let code = self
+      .handle
+      .call(format.method(), Some(vec![Variant::from_strings(&ids)?, path.into(), overwrite.overwrite().into()]))?
+      .as_int()?;
+    OperationError::check(code)?;
+    Ok(ExportStatus::Exported)
+  }
+
+  /// Exports each drawing into a separate file in `directory`.
+  /// 
+  /// File names are rendered from `template` with [Drawing::file_name], extension is appended
+  /// according to `format`. Repeated names get ` (2)`, ` (3)` etc. suffixes, so drawings never
+  /// overwrite each other. Returns paths and statuses in the order of `drawings`.
+  pub fn export_drawings_each(&self, drawings: &[Drawing], directory: &Path, template: &str, format: DrawingFormat, overwrite: OverwritePolicy) -> Result<Vec<(PathBuf, ExportStatus)>> {
+    if !directory.is_dir() {
+      return Err(Error::NonexistentPath(format!("Export directory does not exist: {}", directory.display())));
+    }
+    drawings
+      .iter()
+      .zip(drawing::export_paths(drawings, directory, template, format))
+      .map(|(drawing, path)| {
+        let status = self.export_drawings(std::slice::from_ref(drawing), &path, format, overwrite)?;
+        Ok((path, status))
+      })
+      .collect()
+  }
+
+  /// Exports project tables (schedules) to CSV files in existing `folder`.
+  /// 
+  /// Files can be read with [crate::table::read_dir]. With [OverwritePolicy::Skip],
+  /// export is skipped if `folder` already contains CSV files.
+  pub fn export_csv(&self, folder: &Path, overwrite: OverwritePolicy) -> Result<ExportStatus> {
+    if !folder.is_dir() {
+      return Err(Error::NonexistentPath(format!("Folder does not exist: {}", folder.display())));
+    }
+    if overwrite == OverwritePolicy::Skip && !table::csv_files(folder)?.is_empty() {
+      return Ok(ExportStatus::Skipped);
+    }
+    let folder = folder.to_string_lossy().into_owned();
+    let code = self
+      .handle
+      .call("ExportToCsv", Some(vec![folder.into(), overwrite.overwrite().into()]))?
+      .as_int()?;
+    OperationError::check(code)?;
+    Ok(ExportStatus::Exported)
+  }
+
+  /// Exports project to IFC file.
+  /// 
+  /// If `settings` is `None`, Renga default export settings are used.
+  /// Returns [crate::OperationError::NothingToExport] if `settings` restrict export to object
+  /// types the model has no objects of. Native result codes are mapped to [crate::OperationError].
+  pub fn export_ifc(&self, path: &Path, settings: Option<&IfcExportSettings>, overwrite: OverwritePolicy) -> Result<ExportStatus> {
+    if overwrite.skips(path) {
+      return Ok(ExportStatus::Skipped);
+    }
+    if let Some(settings) = settings.filter(|settings| !settings.object_types.is_empty()) {
+      let objects = self.model()?.objects()?;
+      let mut exports = false;
+      for index in 0..objects.len()? {
+        if settings.exports(&objects.get(index)?.object_type) {
+          exports = true;
+          break;
+        }
+      }
+      if !exports {
+        return Err(OperationError::NothingToExport.into());
+      }
+    }
+    let path = path.to_string_lossy().into_owned();
+    let code = match settings {
+      Some(settings) => {
+        let handle = self
+          .parent_handle
+          .call("CreateIfcExportSettings", None)?
+          .into_dispatch()?;
+        settings.apply(&handle)?;
+        self
+          .handle
+          .call("ExportToIfc2", Some(vec![path.into(), overwrite.overwrite().into(), handle.into()]))?
+          .as_int()?
+      },
+      None => self
+        .handle
+        .call("ExportToIfc", Some(vec![path.into(), overwrite.overwrite().into()]))?
+        .as_int()?
+    };
+    OperationError::check(code)?;
+    Ok(ExportStatus::Exported)
   }
 
-  // CreateOperationWithUndo ([in] GUID modelId, [out, retval] IOperation **ppOperation)
-  // ExportDrawingsToOpenXpsS ([in] SAFEARRAY(BSTR) drawingIds, [in] BSTR filePath, [in] VARIANT_BOOL overwrite, [out, retval] int *pResult) 
-  // ExportDrawingsToPdf ([in] SAFEARRAY(GUID) drawingIds, [in] BSTR filePath, [in] VARIANT_BOOL overwrite, [out, retval] int *pResult)
-  // ExportDrawingsToPdfS ([in] SAFEARRAY(BSTR) drawingIds, [in] BSTR filePath, [in] VARIANT_BOOL overwrite, [out, retval] int *pResult)
-  // ExportToCsv ([in] BSTR folderPath, [in] VARIANT_BOOL overwrite, [out, retval] int *pResult)
-  // ExportToIfc ([in] BSTR filePath, [in] VARIANT_BOOL overwrite, [out, retval] int *pResult)
-  // ExportToIfc2 ([in] BSTR filePath, [in] VARIANT_BOOL overwrite, [in] IIfcExportSettings *pIfcExportSettings, [out, retval] int *pResult)
-  // GetEntityNumberInTopic ([in] GUID entityId, [out, retval] int *pResult)
-  // GetEntityNumberInTopicS ([in] BSTR entityId, [out, retval] int *pResult)
-  // GetUndoStack ([in] GUID modelId, [out, retval] IUndoStack **ppUndoStack)
-  // SaveAs ([in] BSTR filePath, [in] enum ProjectType projectType, [in] VARIANT_BOOL overwrite, [out, retval] int *pResult)
-
-  // IEntityCollection 	Assemblies [get]
-  // IBeamStyleManager 	BeamStyleManager [get]
-  // IEntityCollection 	BeamStyles [get]
-  // IBuildingInfo 	BuildingInfo [get]
-  // IColumnStyleManager 	ColumnStyleManager [get]
-  // IEntityCollection 	ColumnStyles [get]
-  // IDataExporter 	DataExporter [get]
   // IEntityCollection 	DoorStyles [get]
-  // IDrawingCollection 	Drawings [get]
   // IEntityCollection 	Drawings2 [get]
   // IEntityCollection 	DuctAccessoryStyles [get]
   // IEntityCollection 	DuctFittingStyles [get]
@@ -212,80 +730,143 @@ impl Project {
   // IEntityCollection 	ElectricalConductorStyles [get]
   // IEntityCollection 	ElectricDistributionBoardStyles [get]
   // IEntityCollection 	ElementStyles [get]
-  // IEquipmentStyleManager 	EquipmentStyleManager [get]
-  // IEntityCollection 	EquipmentStyles [get]
   // IEntityCollection 	HoleStyles [get]
   // BSTR 	JournalPath [get]
-  // ILandPlotInfo 	LandPlotInfo [get]
-  // ILayeredMaterialManager 	LayeredMaterialManager [get]
-  // IEntityCollection 	LayeredMaterials [get]
   // IEntityCollection 	LayoutStyles [get]
   // IEntityCollection 	LightingFixtureStyles [get]
-  // IMaterialManager 	MaterialManager [get]
-  // IEntityCollection 	Materials [get]
   // IEntityCollection 	MechanicalEquipmentStyles [get]
-  // IModel 	Model [get]
-  // IEntityCollection 	PageFormatStyles [get]
   // IEntityCollection 	PipeAccessoryStyles [get]
   // IEntityCollection 	PipeFittingStyles [get]
   // IEntityCollection 	PipeStyles [get]
   // IEntityCollection 	PlateStyles [get]
-  // IPlumbingFixtureStyleManager 	PlumbingFixtureStyleManager [get]
-  // IEntityCollection 	PlumbingFixtureStyles [get]
-  // IProfileDescriptionManager 	ProfileDescriptionManager [get]
-  // IEntityCollection 	Profiles [get]
-  // IProjectInfo 	ProjectInfo [get]
-  // ProjectType 	ProjectType [get]
-  // IPropertyManager 	PropertyManager [get]
-  // IEntityCollection 	RebarStyles [get]
-  // IEntityCollection 	ReinforcementGrades [get]
-  // IEntityCollection 	ReinforcementStyles [get]
-  // IReinforcementUnitStyleManager 	ReinforcementUnitStyleManager [get]
-  // IEntityCollection 	ReinforcementUnitStyles [get]
-  // ISystemStyleManager 	SystemStyleManager [get]
-  // IEntityCollection 	SystemStyles [get]
-  // IEntityCollection 	Topics [get]
   // IEntityCollection 	WindowStyles [get]
   // IEntityCollection 	WiringAccessoryStyles [get]
 }
 
 /// Represents project transaction, created by [Project::start_transaction].
-pub struct ProjectTransaction {
-  handle: Dispatch
+/// 
+/// Transaction borrows the project mutably and gives read access to it through [Deref].
+/// Methods of the project which change it are available only on the transaction, and mutating 
+/// methods of other objects (managers, parameters, properties) require a reference to it.
+/// 
+/// Transaction which is dropped without [ProjectTransaction::commit] is rolled back,
+/// so an early return with `?` never leaves an open operation in Renga. Mutating methods
+/// check that the transaction is still active with [ProjectTransaction::ensure_active].
+pub struct ProjectTransaction<'a> {
+  project: &'a mut Project,
+  handle: Dispatch,
+  finished: bool
 }
 
-impl ProjectTransaction {
-  /// Creates new instance of ProjectTransaction from native handle.
+impl<'a> ProjectTransaction<'a> {
+  /// Creates new instance of ProjectTransaction from native `IOperation` handle and starts it.
   /// 
   /// To get an instance of this structure without using native handles, use
   /// the [Project::start_transaction] method.
-  pub fn new(handle: Dispatch) -> Result<Self> {
+  pub fn new(project: &'a mut Project, handle: Dispatch) -> Result<Self> {
     log::trace!("starting transaction");
     if handle.is_null() {
-      return Err(Error::Internal(format!("IOperation handle is null")));
+      return Err(Error::Internal("IOperation handle is null".to_owned()));
     }
-    let mut this = Self { handle };
-    this.start()?;
-    Ok(this)
+    handle.call("Start", None)?;
+    Ok(Self { project, handle, finished: false })
   }
 
   /// Commits changes made in transaction to the project.
-  pub fn commit(&mut self) -> Result<()> {
+  pub fn commit(mut self) -> Result<()> {
     log::trace!("committing transaction");
     self.handle.call("Apply", None)?;
+    self.finished = true;
     Ok(())
   }
 
   /// Rolls back changes made in transaction.
-  pub fn rollback(&mut self) -> Result<()> {
+  pub fn rollback(mut self) -> Result<()> {
+    self.finished = true;
+    self.rollback_impl()
+  }
+
+  /// Returns [crate::Error::NoActiveTransaction] if operation of this transaction is no longer
+  /// active in Renga, e.g. it was finished from the host application.
+  pub fn ensure_active(&self) -> Result<()> {
+    match !self.finished && self.project.has_transaction()? {
+      true => Ok(()),
+      false => Err(Error::NoActiveTransaction)
+    }
+  }
+
+  /// Writes general information about the project. `None` fields are left untouched.
+  pub fn set_project_info(&self, info: &ProjectInfo) -> Result<()> {
+    self.ensure_active()?;
+    info.write(&self.project.handle.get("ProjectInfo")?.into_dispatch()?)
+  }
+
+  /// Writes information about the building. `None` fields are left untouched.
+  pub fn set_building_info(&self, info: &BuildingInfo) -> Result<()> {
+    self.ensure_active()?;
+    info.write(&self.project.handle.get("BuildingInfo")?.into_dispatch()?)
+  }
+
+  /// Writes information about the land plot. `None` fields are left untouched.
+  pub fn set_land_plot_info(&self, info: &LandPlotInfo) -> Result<()> {
+    self.ensure_active()?;
+    info.write(&self.project.handle.get("LandPlotInfo")?.into_dispatch()?)
+  }
+
+  /// Writes project, building and land plot information at once, 
+  /// e.g. loaded with [ProjectInformation::from_file].
+  pub fn apply_information(&self, information: &ProjectInformation) -> Result<()> {
+    self.ensure_active()?;
+    if let Some(info) = &information.project {
+      self.set_project_info(info)?;
+    }
+    if let Some(info) = &information.building {
+      self.set_building_info(info)?;
+    }
+    if let Some(info) = &information.land_plot {
+      self.set_land_plot_info(info)?;
+    }
+    Ok(())
+  }
+
+  /// Imports style template of given category from file and returns its entity.
+  pub fn import_category(&self, category: Category, path: &Path) -> Result<Entity> {
+    self.ensure_active()?;
+    let category_str = category.to_sanitized_string();
+    let path_str: String = path.to_string_lossy().to_string();
+    let entity: Entity = self
+      .project
+      .handle
+      .call("ImportCategoryS", Some(vec![category_str.into(), path_str.into()]))?
+      .into_dispatch()?
+      .try_into()?;
+    Ok(entity)
+  }
+
+  fn rollback_impl(&self) -> Result<()> {
     log::trace!("rolling back transaction");
     self.handle.call("Rollback", None)?;
     Ok(())
   }
+}
 
-  fn start(&mut self) -> Result<()> {
-    self.handle.call("Start", None)?;
-    Ok(())
+impl Deref for ProjectTransaction<'_> {
+  type Target = Project;
+  fn deref(&self) -> &Project {
+    self.project
+  }
+}
+
+/// Rolls back transaction if it was neither committed nor rolled back.
+/// 
+/// This function never fails or panics. If any error occurs, it will be logged.
+impl Drop for ProjectTransaction<'_> {
+  fn drop(&mut self) {
+    if !self.finished {
+      if let Err(error) = self.rollback_impl() {
+        log::error!("failed to roll back transaction: {error:?}");
+      }
+    }
   }
 }
 
@@ -318,9 +899,10 @@ mod tests {
   fn test_transaction(ctx: &mut ProjectContext) -> anyhow::Result<()> {
     assert!(ctx.app.project()?.is_some());
 
-    let mut transaction = ctx.project.start_transaction()?;
+    let transaction = ctx.project.start_transaction()?;
 
     //assert!(ctx.project.has_transaction()?);
+    transaction.ensure_active()?;
 
     transaction.commit()?;
 
@@ -329,13 +911,38 @@ mod tests {
     Ok(())
   }
 
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_transaction_drop(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let info = |code: &str| ProjectInfo {
+      code: Some(code.to_owned()),
+      ..Default::default()
+    };
+    ctx.project.transact(|transaction| transaction.set_project_info(&info("committed")))?;
+
+    {
+      let transaction = ctx.project.start_transaction()?;
+      transaction.set_project_info(&info("dropped"))?;
+    }
+    let result: Result<()> = ctx.project.transact(|transaction| {
+      transaction.set_project_info(&info("failed"))?;
+      Err(Error::InvalidOperation("test".to_owned()))
+    });
+
+    assert!(result.is_err());
+    assert!(!ctx.project.has_transaction()?);
+    assert_eq!(ctx.project.project_info()?.code.as_deref(), Some("committed"));
+
+    Ok(())
+  }
+
   #[test_context(ProjectContext)]
   #[test]
   fn test_import_category(ctx: &mut ProjectContext) -> anyhow::Result<()> {
     let file = external_file("style_category.rst")?;
-    let mut transaction = ctx.project.start_transaction()?;
+    let transaction = ctx.project.start_transaction()?;
 
-    let category = ctx.project.import_category(Category::Equipment, &file)?;
+    let category = transaction.import_category(Category::Equipment, &file)?;
     transaction.commit()?;
 
     assert!(ctx.project.has_unsaved_changes()?);
@@ -357,4 +964,56 @@ mod tests {
 
     Ok(())
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn test_validate_path() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir();
+
+    assert!(ProjectType::Project.validate_path(&dir.join("project.rnp")).is_ok());
+    assert!(ProjectType::Template.validate_path(&dir.join("template.RNT")).is_ok());
+    assert!(matches!(
+      ProjectType::Project.validate_path(&dir.join("template.rnt")),
+      Err(Error::InvalidOperation(_))
+    ));
+    assert!(matches!(
+      ProjectType::Project.validate_path(&dir),
+      Err(Error::InvalidOperation(_))
+    ));
+    assert!(matches!(
+      ProjectType::Project.validate_path(&dir.join("missing-directory").join("project.rnp")),
+      Err(Error::NonexistentPath(_))
+    ));
+    assert_eq!(ProjectType::from_native(ProjectType::Template.to_native())?, ProjectType::Template);
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_save_as(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("renga-api-rs-save-as.rnt");
+
+    assert_eq!(ctx.project.project_type()?, ProjectType::Project);
+    assert!(ctx.project.save_as(&path, ProjectType::Project, OverwritePolicy::Overwrite).is_err());
+    assert_eq!(
+      ctx.project.save_as(&path, ProjectType::Template, OverwritePolicy::Overwrite)?,
+      ExportStatus::Exported
+    );
+    assert_eq!(
+      ctx.project.save_as(&path, ProjectType::Template, OverwritePolicy::Skip)?,
+      ExportStatus::Skipped
+    );
+    assert!(matches!(
+      ctx.project.save_as(&path, ProjectType::Template, OverwritePolicy::Fail),
+      Err(Error::Operation(OperationError::FileAlreadyExists))
+    ));
+
+    ctx.project.close(true)?;
+    let project = ctx.app.new_project_from_template(&path)?;
+
+    assert_eq!(project.project_type()?, ProjectType::Project);
+    assert!(project.path()?.is_none());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,304 @@
+use crate::{
+  native::Dispatch,
+  Result,
+  Error
+};
+
+#[cfg(feature="serde")]
+use std::path::Path;
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Postal address of a building or a land plot.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_postal_address.html)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct PostalAddress {
+  pub address_line: Option<String>,
+  pub postal_code: Option<String>,
+  pub town: Option<String>,
+  pub region: Option<String>,
+  pub country: Option<String>
+}
+
+/// General information about the project, used in title blocks.
+///
+/// Fields read from a project are always `Some`. When writing, `None` fields are left untouched.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_project_info.html)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct ProjectInfo {
+  pub code: Option<String>,
+  pub name: Option<String>,
+  pub stage: Option<String>,
+  pub description: Option<String>
+}
+
+/// Information about the building.
+///
+/// Fields read from a project are always `Some`. When writing, `None` fields are left untouched.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_building_info.html)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct BuildingInfo {
+  pub name: Option<String>,
+  pub number: Option<String>,
+  pub description: Option<String>,
+  pub address: Option<PostalAddress>
+}
+
+/// Information about the land plot.
+///
+/// Fields read from a project are always `Some`. When writing, `None` fields are left untouched.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_land_plot_info.html)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct LandPlotInfo {
+  pub name: Option<String>,
+  pub number: Option<String>,
+  pub cadastral_number: Option<String>,
+  pub description: Option<String>,
+  pub address: Option<PostalAddress>
+}
+
+/// Project, building and land plot information applied at once with [crate::ProjectTransaction::apply_information].
+///
+/// With `serde` feature enabled, information can be loaded from JSON or TOML file.
+/// Only specified fields are written. Example of TOML file:
+/// ```toml
+/// [project]
+/// code = "2024-017"
+/// stage = "P"
+///
+/// [land_plot]
+/// cadastral_number = "77:01:0001001:1234"
+///
+/// [land_plot.address]
+/// town = "Moscow"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(default))]
+pub struct ProjectInformation {
+  pub project: Option<ProjectInfo>,
+  pub building: Option<BuildingInfo>,
+  pub land_plot: Option<LandPlotInfo>
+}
+
+fn read(handle: &Dispatch, name: &str) -> Result<Option<String>> {
+  Ok(Some(handle.get(name)?.into_string()?))
+}
+
+fn write(handle: &Dispatch, name: &str, value: &Option<String>) -> Result<()> {
+  if let Some(value) = value {
+    handle.set(name, value.as_str().into())?;
+  }
+  Ok(())
+}
+
+fn ensure_not_null(handle: &Dispatch, interface: &str) -> Result<()> {
+  match handle.is_null() {
+    true => Err(Error::Internal(format!("{interface} handle is null"))),
+    false => Ok(())
+  }
+}
+
+impl PostalAddress {
+  pub(crate) fn read(handle: &Dispatch) -> Result<Self> {
+    ensure_not_null(handle, "IPostalAddress")?;
+    Ok(Self {
+      address_line: read(handle, "AddressLine")?,
+      postal_code: read(handle, "PostalCode")?,
+      town: read(handle, "Town")?,
+      region: read(handle, "Region")?,
+      country: read(handle, "Country")?
+    })
+  }
+
+  pub(crate) fn write(&self, handle: &Dispatch) -> Result<()> {
+    ensure_not_null(handle, "IPostalAddress")?;
+    write(handle, "AddressLine", &self.address_line)?;
+    write(handle, "PostalCode", &self.postal_code)?;
+    write(handle, "Town", &self.town)?;
+    write(handle, "Region", &self.region)?;
+    write(handle, "Country", &self.country)
+  }
+}
+
+impl ProjectInfo {
+  pub(crate) fn read(handle: &Dispatch) -> Result<Self> {
+    ensure_not_null(handle, "IProjectInfo")?;
+    Ok(Self {
+      code: read(handle, "Code")?,
+      name: read(handle, "Name")?,
+      stage: read(handle, "Stage")?,
+      description: read(handle, "Description")?
+    })
+  }
+
+  pub(crate) fn write(&self, handle: &Dispatch) -> Result<()> {
+    ensure_not_null(handle, "IProjectInfo")?;
+    write(handle, "Code", &self.code)?;
+    write(handle, "Name", &self.name)?;
+    write(handle, "Stage", &self.stage)?;
+    write(handle, "Description", &self.description)
+  }
+}
+
+impl BuildingInfo {
+  pub(crate) fn read(handle: &Dispatch) -> Result<Self> {
+    ensure_not_null(handle, "IBuildingInfo")?;
+    Ok(Self {
+      name: read(handle, "Name")?,
+      number: read(handle, "Number")?,
+      description: read(handle, "Description")?,
+      address: Some(PostalAddress::read(&handle.get("Address")?.into_dispatch()?)?)
+    })
+  }
+
+  pub(crate) fn write(&self, handle: &Dispatch) -> Result<()> {
+    ensure_not_null(handle, "IBuildingInfo")?;
+    write(handle, "Name", &self.name)?;
+    write(handle, "Number", &self.number)?;
+    write(handle, "Description", &self.description)?;
+    match &self.address {
+      Some(address) => address.write(&handle.get("Address")?.into_dispatch()?),
+      None => Ok(())
+    }
+  }
+}
+
+impl LandPlotInfo {
+  pub(crate) fn read(handle: &Dispatch) -> Result<Self> {
+    ensure_not_null(handle, "ILandPlotInfo")?;
+    Ok(Self {
+      name: read(handle, "Name")?,
+      number: read(handle, "Number")?,
+      cadastral_number: read(handle, "CadastralNumber")?,
+      description: read(handle, "Description")?,
+      address: Some(PostalAddress::read(&handle.get("Address")?.into_dispatch()?)?)
+    })
+  }
+
+  pub(crate) fn write(&self, handle: &Dispatch) -> Result<()> {
+    ensure_not_null(handle, "ILandPlotInfo")?;
+    write(handle, "Name", &self.name)?;
+    write(handle, "Number", &self.number)?;
+    write(handle, "CadastralNumber", &self.cadastral_number)?;
+    write(handle, "Description", &self.description)?;
+    match &self.address {
+      Some(address) => address.write(&handle.get("Address")?.into_dispatch()?),
+      None => Ok(())
+    }
+  }
+}
+
+impl ProjectInformation {
+  /// Parses information from JSON string.
+  #[cfg(feature="serde")]
+  pub fn from_json(json: &str) -> Result<Self> {
+    serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))
+  }
+
+  /// Parses information from TOML string.
+  #[cfg(feature="serde")]
+  pub fn from_toml(toml: &str) -> Result<Self> {
+    toml::from_str(toml).map_err(|e| Error::ParseError(e.to_string()))
+  }
+
+  /// Loads information from file. Format is chosen by file extension (`.json` or `.toml`).
+  #[cfg(feature="serde")]
+  pub fn from_file(path: &Path) -> Result<Self> {
+    let contents = std::fs::read_to_string(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+      Some("json") => Self::from_json(&contents),
+      Some("toml") => Self::from_toml(&contents),
+      _ => Err(Error::ParseError(format!("Unsupported project information format: {}", path.display())))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_from_file() -> anyhow::Result<()> {
+    let toml = ProjectInformation::from_file(&external_file("project_info.toml")?)?;
+    let json = ProjectInformation::from_file(&external_file("project_info.json")?)?;
+
+    assert_eq!(toml, json);
+    assert_eq!(toml.project.as_ref().and_then(|p| p.code.as_deref()), Some("2024-017"));
+    assert_eq!(toml.project.as_ref().and_then(|p| p.name.as_deref()), None);
+    assert!(toml.building.is_none());
+
+    let land_plot = toml.land_plot.unwrap();
+    assert_eq!(land_plot.cadastral_number.as_deref(), Some("77:01:0001001:1234"));
+    assert_eq!(land_plot.address.unwrap().town.as_deref(), Some("Москва"));
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_project_info(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let info = ProjectInfo {
+      code: Some("2024-017".to_owned()),
+      ..Default::default()
+    };
+    let transaction = ctx.project.start_transaction()?;
+    transaction.set_project_info(&info)?;
+    transaction.commit()?;
+
+    let read = ctx.project.project_info()?;
+    assert_eq!(read.code.as_deref(), Some("2024-017"));
+    assert!(read.name.is_some());
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_apply_information(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let information = ProjectInformation {
+      building: Some(BuildingInfo {
+        number: Some("3".to_owned()),
+        address: Some(PostalAddress {
+          town: Some("Moscow".to_owned()),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+      land_plot: Some(LandPlotInfo {
+        cadastral_number: Some("77:01:0001001:1234".to_owned()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    ctx.project.transact(|transaction| transaction.apply_information(&information))?;
+
+    let building = ctx.project.building_info()?;
+    assert_eq!(building.number.as_deref(), Some("3"));
+    assert_eq!(building.address.unwrap().town.as_deref(), Some("Moscow"));
+    assert_eq!(
+      ctx.project.land_plot_info()?.cadastral_number.as_deref(),
+      Some("77:01:0001001:1234")
+    );
+
+    Ok(())
+  }
+}
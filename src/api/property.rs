@@ -0,0 +1,552 @@
+use std::fmt::Display;
+use crate::{
+  native::{
+    constants,
+    Dispatch,
+    Variant
+  },
+  Result,
+  Error
+};
+use super::{
+  ProjectTransaction,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Type of user-defined property.
+///
+/// Dimensioned types ([PropertyType::Length], [PropertyType::Area], [PropertyType::Volume],
+/// [PropertyType::Mass] and [PropertyType::Angle]) are exchanged in millimeters, square meters,
+/// cubic meters, kilograms and degrees respectively.
+///
+/// See [Official documentation](https://help.rengabim.com/api/group___property_types.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum PropertyType {
+  Double,
+  String,
+  Angle,
+  Area,
+  Boolean,
+  Enumeration,
+  Integer,
+  Length,
+  Logical,
+  Mass,
+  Volume
+}
+
+impl PropertyType {
+  /// Converts native `PropertyType` value to enum.
+  pub fn from_native(value: i32) -> Result<Self> {
+    match value {
+      constants::PROPERTY_TYPE_DOUBLE => Ok(Self::Double),
+      constants::PROPERTY_TYPE_STRING => Ok(Self::String),
+      constants::PROPERTY_TYPE_ANGLE => Ok(Self::Angle),
+      constants::PROPERTY_TYPE_AREA => Ok(Self::Area),
+      constants::PROPERTY_TYPE_BOOLEAN => Ok(Self::Boolean),
+      constants::PROPERTY_TYPE_ENUMERATION => Ok(Self::Enumeration),
+      constants::PROPERTY_TYPE_INTEGER => Ok(Self::Integer),
+      constants::PROPERTY_TYPE_LENGTH => Ok(Self::Length),
+      constants::PROPERTY_TYPE_LOGICAL => Ok(Self::Logical),
+      constants::PROPERTY_TYPE_MASS => Ok(Self::Mass),
+      constants::PROPERTY_TYPE_VOLUME => Ok(Self::Volume),
+      other => Err(Error::TypeMismatch(format!("unsupported property type {other}")))
+    }
+  }
+
+  /// Converts enum to native `PropertyType` value.
+  pub fn to_native(&self) -> i32 {
+    match self {
+      Self::Double => constants::PROPERTY_TYPE_DOUBLE,
+      Self::String => constants::PROPERTY_TYPE_STRING,
+      Self::Angle => constants::PROPERTY_TYPE_ANGLE,
+      Self::Area => constants::PROPERTY_TYPE_AREA,
+      Self::Boolean => constants::PROPERTY_TYPE_BOOLEAN,
+      Self::Enumeration => constants::PROPERTY_TYPE_ENUMERATION,
+      Self::Integer => constants::PROPERTY_TYPE_INTEGER,
+      Self::Length => constants::PROPERTY_TYPE_LENGTH,
+      Self::Logical => constants::PROPERTY_TYPE_LOGICAL,
+      Self::Mass => constants::PROPERTY_TYPE_MASS,
+      Self::Volume => constants::PROPERTY_TYPE_VOLUME
+    }
+  }
+}
+
+/// Typed value of a user-defined [Property].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum PropertyValue {
+  Double(f64),
+  String(String),
+  /// Angle in degrees.
+  Angle(f64),
+  /// Area in square meters.
+  Area(f64),
+  Boolean(bool),
+  /// Enumeration item.
+  Enumeration(String),
+  Integer(i32),
+  /// Length in millimeters.
+  Length(f64),
+  /// Three-state logical value, `None` meaning indeterminate.
+  Logical(Option<bool>),
+  /// Mass in kilograms.
+  Mass(f64),
+  /// Volume in cubic meters.
+  Volume(f64)
+}
+
+impl PropertyValue {
+  /// Returns type of this value.
+  pub fn property_type(&self) -> PropertyType {
+    match self {
+      Self::Double(_) => PropertyType::Double,
+      Self::String(_) => PropertyType::String,
+      Self::Angle(_) => PropertyType::Angle,
+      Self::Area(_) => PropertyType::Area,
+      Self::Boolean(_) => PropertyType::Boolean,
+      Self::Enumeration(_) => PropertyType::Enumeration,
+      Self::Integer(_) => PropertyType::Integer,
+      Self::Length(_) => PropertyType::Length,
+      Self::Logical(_) => PropertyType::Logical,
+      Self::Mass(_) => PropertyType::Mass,
+      Self::Volume(_) => PropertyType::Volume
+    }
+  }
+}
+
+impl Display for PropertyValue {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Double(value) | Self::Area(value) | Self::Mass(value) | Self::Volume(value) => write!(f, "{value}"),
+      Self::Angle(value) => write!(f, "{value}°"),
+      Self::Length(value) => write!(f, "{value} mm"),
+      Self::String(value) | Self::Enumeration(value) => write!(f, "{value}"),
+      Self::Boolean(value) => write!(f, "{value}"),
+      Self::Integer(value) => write!(f, "{value}"),
+      Self::Logical(Some(value)) => write!(f, "{value}"),
+      Self::Logical(None) => write!(f, "indeterminate")
+    }
+  }
+}
+
+/// Description of a user-defined property, used to register it in a project.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_property_description.html)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct PropertyDescription {
+  /// Unique identifier of the property.
+  pub id: UUID,
+
+  /// Name of the property.
+  pub name: String,
+
+  /// Type of the property.
+  #[cfg_attr(feature="serde", serde(rename = "type"))]
+  pub property_type: PropertyType,
+
+  /// Enumeration items. Used only for [PropertyType::Enumeration] properties.
+  #[cfg_attr(feature="serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+  pub enumeration_items: Vec<String>,
+
+  /// Expression for calculated properties.
+  #[cfg_attr(feature="serde", serde(default, skip_serializing_if = "Option::is_none"))]
+  pub expression: Option<String>
+}
+
+impl PropertyDescription {
+  /// Creates description of a plain (not calculated, non-enumeration) property.
+  pub fn new(id: UUID, name: &str, property_type: PropertyType) -> Self {
+    Self {
+      id,
+      name: name.to_owned(),
+      property_type,
+      enumeration_items: Vec::new(),
+      expression: None
+    }
+  }
+
+  /// Sets enumeration items of the property.
+  pub fn with_enumeration_items(mut self, items: &[&str]) -> Self {
+    self.enumeration_items = items.iter().map(|item| item.to_string()).collect();
+    self
+  }
+
+  /// Sets expression of the calculated property.
+  pub fn with_expression(mut self, expression: &str) -> Self {
+    self.expression = Some(expression.to_owned());
+    self
+  }
+}
+
+/// Represents project property manager, which registers user-defined properties and
+/// assigns them to object types.
+///
+/// Can be obtained using [crate::Project::property_manager] method.
+/// All mutating methods require active [ProjectTransaction].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_property_manager.html)
+#[derive(Debug, Clone)]
+pub struct PropertyManager {
+  handle: Dispatch
+}
+
+impl PropertyManager {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IPropertyManager handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns descriptions of all registered properties.
+  ///
+  /// Enumeration items and expressions are not reported by Renga and are left empty.
+  pub fn properties(&self) -> Result<Vec<PropertyDescription>> {
+    let count = self
+      .handle
+      .get("PropertyCount")?
+      .as_int()?;
+    let mut properties = Vec::with_capacity(count.max(0) as usize);
+    for index in 0..count {
+      let id: UUID = self
+        .handle
+        .call("GetPropertyIdS", Some(vec![index.into()]))?
+        .into_string()?
+        .parse()?;
+      properties.push(self.description(&id)?);
+    }
+    Ok(properties)
+  }
+
+  /// Returns description of registered property with given identifier.
+  ///
+  /// Enumeration items and expressions are not reported by Renga and are left empty.
+  pub fn description(&self, id: &UUID) -> Result<PropertyDescription> {
+    let name = self
+      .handle
+      .call("GetPropertyNameS", Some(vec![id.to_string().into()]))?
+      .into_string()?;
+    let property_type = PropertyType::from_native(self
+      .handle
+      .call("GetPropertyTypeS", Some(vec![id.to_string().into()]))?
+      .as_int()?
+    )?;
+    Ok(PropertyDescription::new(id.clone(), &name, property_type))
+  }
+
+  /// Returns `true` if property with given identifier is registered.
+  pub fn is_registered(&self, id: &UUID) -> Result<bool> {
+    self
+      .handle
+      .call("IsPropertyRegisteredS", Some(vec![id.to_string().into()]))?
+      .as_bool()
+  }
+
+  /// Registers new property.
+  pub fn register(&mut self, transaction: &ProjectTransaction, description: &PropertyDescription) -> Result<()> {
+    transaction.ensure_active()?;
+    let id = description.id.to_string();
+    if description.enumeration_items.is_empty() && description.expression.is_none() {
+      self.handle.call("RegisterPropertyS", Some(vec![
+        id.into(),
+        description.name.as_str().into(),
+        description.property_type.to_native().into()
+      ]))?;
+      return Ok(());
+    }
+    let native = self
+      .handle
+      .call("CreatePropertyDescription", Some(vec![
+        description.name.as_str().into(),
+        description.property_type.to_native().into()
+      ]))?
+      .into_dispatch()?;
+    if !description.enumeration_items.is_empty() {
+      native.call("SetEnumerationItems", Some(vec![Variant::from_strings(&description.enumeration_items)?]))?;
+    }
+    if let Some(expression) = &description.expression {
+      native.set("Expression", expression.into())?;
+    }
+    self.handle.call("RegisterPropertyS2", Some(vec![id.into(), native.into()]))?;
+    Ok(())
+  }
+
+  /// Unregisters property with given identifier.
+  pub fn unregister(&mut self, transaction: &ProjectTransaction, id: &UUID) -> Result<()> {
+    transaction.ensure_active()?;
+    self.handle.call("UnregisterPropertyS", Some(vec![id.to_string().into()]))?;
+    Ok(())
+  }
+
+  /// Returns `true` if property is assigned to given object type.
+  pub fn is_assigned(&self, property_id: &UUID, object_type: &UUID) -> Result<bool> {
+    self
+      .handle
+      .call("IsPropertyAssignedToTypeS", Some(vec![
+        property_id.to_string().into(),
+        object_type.to_string().into()
+      ]))?
+      .as_bool()
+  }
+
+  /// Assigns property to given object type.
+  pub fn assign(&mut self, transaction: &ProjectTransaction, property_id: &UUID, object_type: &UUID) -> Result<()> {
+    transaction.ensure_active()?;
+    self.handle.call("AssignPropertyToTypeS", Some(vec![
+      property_id.to_string().into(),
+      object_type.to_string().into()
+    ]))?;
+    Ok(())
+  }
+
+  /// Unassigns property from given object type.
+  pub fn unassign(&mut self, transaction: &ProjectTransaction, property_id: &UUID, object_type: &UUID) -> Result<()> {
+    transaction.ensure_active()?;
+    self.handle.call("UnassignPropertyFromTypeS", Some(vec![
+      property_id.to_string().into(),
+      object_type.to_string().into()
+    ]))?;
+    Ok(())
+  }
+}
+
+/// Represents value of user-defined property on a model object.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_property.html)
+#[derive(Debug, Clone)]
+pub struct Property {
+  /// Identifier of the property.
+  pub id: UUID,
+
+  /// Name of the property.
+  pub name: String,
+
+  /// Type of the property.
+  pub property_type: PropertyType,
+  handle: Dispatch
+}
+
+impl Property {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IProperty handle is null".to_owned()));
+    }
+    Ok(Self {
+      id: handle
+        .get("IdS")?
+        .into_string()?
+        .parse()?,
+      name: handle
+        .get("Name")?
+        .into_string()?,
+      property_type: PropertyType::from_native(handle
+        .get("Type")?
+        .as_int()?
+      )?,
+      handle
+    })
+  }
+
+  /// Returns current value of the property or `None` if value is not set.
+  pub fn value(&self) -> Result<Option<PropertyValue>> {
+    if !self.handle.call("HasValue", None)?.as_bool()? {
+      return Ok(None);
+    }
+    let value = match self.property_type {
+      PropertyType::Double => PropertyValue::Double(self.get("GetDoubleValue", None)?.as_f64()?),
+      PropertyType::String => PropertyValue::String(self.get("GetStringValue", None)?.into_string()?),
+      PropertyType::Angle => PropertyValue::Angle(self.get("GetAngleValue", Some(constants::ANGLE_UNIT_DEGREES))?.as_f64()?),
+      PropertyType::Area => PropertyValue::Area(self.get("GetAreaValue", Some(constants::AREA_UNIT_METERS2))?.as_f64()?),
+      PropertyType::Boolean => PropertyValue::Boolean(self.get("GetBooleanValue", None)?.as_bool()?),
+      PropertyType::Enumeration => PropertyValue::Enumeration(self.get("GetEnumerationValue", None)?.into_string()?),
+      PropertyType::Integer => PropertyValue::Integer(self.get("GetIntegerValue", None)?.as_int()?),
+      PropertyType::Length => PropertyValue::Length(self.get("GetLengthValue", Some(constants::LENGTH_UNIT_MILLIMETERS))?.as_f64()?),
+      PropertyType::Logical => PropertyValue::Logical(match self.get("GetLogicalValue", None)?.as_int()? {
+        constants::LOGICAL_FALSE => Some(false),
+        constants::LOGICAL_TRUE => Some(true),
+        _ => None
+      }),
+      PropertyType::Mass => PropertyValue::Mass(self.get("GetMassValue", Some(constants::MASS_UNIT_KILOGRAMS))?.as_f64()?),
+      PropertyType::Volume => PropertyValue::Volume(self.get("GetVolumeValue", Some(constants::VOLUME_UNIT_METERS3))?.as_f64()?)
+    };
+    Ok(Some(value))
+  }
+
+  /// Sets value of the property.
+  ///
+  /// Can return error in the following cases:
+  /// - [crate::Error::TypeMismatch]: Value type does not match the property type;
+  pub fn set_value(&mut self, transaction: &ProjectTransaction, value: PropertyValue) -> Result<()> {
+    transaction.ensure_active()?;
+    if value.property_type() != self.property_type {
+      return Err(Error::TypeMismatch(format!(
+        "property `{}` expects {:?}, got {:?}",
+        self.name,
+        self.property_type,
+        value.property_type()
+      )));
+    }
+    let (method, args): (&str, Vec<Variant>) = match value {
+      PropertyValue::Double(value) => ("SetDoubleValue", vec![value.into()]),
+      PropertyValue::String(value) => ("SetStringValue", vec![value.into()]),
+      PropertyValue::Angle(value) => ("SetAngleValue", vec![value.into(), constants::ANGLE_UNIT_DEGREES.into()]),
+      PropertyValue::Area(value) => ("SetAreaValue", vec![value.into(), constants::AREA_UNIT_METERS2.into()]),
+      PropertyValue::Boolean(value) => ("SetBooleanValue", vec![value.into()]),
+      PropertyValue::Enumeration(value) => ("SetEnumerationValue", vec![value.into()]),
+      PropertyValue::Integer(value) => ("SetIntegerValue", vec![value.into()]),
+      PropertyValue::Length(value) => ("SetLengthValue", vec![value.into(), constants::LENGTH_UNIT_MILLIMETERS.into()]),
+      PropertyValue::Logical(value) => ("SetLogicalValue", vec![match value {
+        Some(false) => constants::LOGICAL_FALSE,
+        Some(true) => constants::LOGICAL_TRUE,
+        None => constants::LOGICAL_UNKNOWN
+      }.into()]),
+      PropertyValue::Mass(value) => ("SetMassValue", vec![value.into(), constants::MASS_UNIT_KILOGRAMS.into()]),
+      PropertyValue::Volume(value) => ("SetVolumeValue", vec![value.into(), constants::VOLUME_UNIT_METERS3.into()])
+    };
+    self.handle.call(method, Some(args))?;
+    Ok(())
+  }
+
+  /// Resets value of the property.
+  pub fn reset(&mut self, transaction: &ProjectTransaction) -> Result<()> {
+    transaction.ensure_active()?;
+    self.handle.call("ResetValue", None)?;
+    Ok(())
+  }
+
+  fn get(&self, method: &str, unit: Option<i32>) -> Result<Variant> {
+    self.handle.call(method, unit.map(|unit| vec![unit.into()]))
+  }
+}
+
+/// Container of user-defined properties of a model object.
+///
+/// Can be obtained using [crate::ModelObject::properties] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_property_container.html)
+#[derive(Debug, Clone)]
+pub struct PropertyContainer {
+  handle: Dispatch
+}
+
+impl PropertyContainer {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IPropertyContainer handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns identifiers of all properties in the container.
+  pub fn ids(&self) -> Result<Vec<UUID>> {
+    Ok(self
+      .handle
+      .call("GetIds", None)?
+      .as_guid_vec()?
+      .into_iter()
+      .map(UUID::from)
+      .collect())
+  }
+
+  /// Returns property with given identifier or `None` if it is not assigned to the object type.
+  pub fn get(&self, id: &UUID) -> Result<Option<Property>> {
+    let contains = self
+      .handle
+      .call("ContainsS", Some(vec![id.to_string().into()]))?
+      .as_bool()?;
+    if !contains {
+      return Ok(None);
+    }
+    let handle = self
+      .handle
+      .call("GetS", Some(vec![id.to_string().into()]))?
+      .into_dispatch()?;
+    Ok(Some(Property::new(handle)?))
+  }
+
+  /// Returns all properties in the container.
+  pub fn to_vec(&self) -> Result<Vec<Property>> {
+    let ids = self.ids()?;
+    let mut vec = Vec::with_capacity(ids.len());
+    for id in &ids {
+      if let Some(property) = self.get(id)? {
+        vec.push(property);
+      }
+    }
+    Ok(vec)
+  }
+}
+
+impl TryFrom<Dispatch> for PropertyManager {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for Property {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl TryFrom<Dispatch> for PropertyContainer {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test]
+  fn test_property_type_roundtrip() -> anyhow::Result<()> {
+    for property_type in [
+      PropertyType::Double,
+      PropertyType::String,
+      PropertyType::Angle,
+      PropertyType::Area,
+      PropertyType::Boolean,
+      PropertyType::Enumeration,
+      PropertyType::Integer,
+      PropertyType::Length,
+      PropertyType::Logical,
+      PropertyType::Mass,
+      PropertyType::Volume
+    ] {
+      assert_eq!(PropertyType::from_native(property_type.to_native())?, property_type);
+    }
+    assert!(PropertyType::from_native(0).is_err());
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_register_property(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let id: UUID = "5B0DBA66-6F4C-4C41-A7B8-9F3B2F7C0D11".parse()?;
+    let description = PropertyDescription::new(id.clone(), "Fire rating", PropertyType::String);
+    let mut manager = ctx.project.property_manager()?;
+    let transaction = ctx.project.start_transaction()?;
+
+    manager.register(&transaction, &description)?;
+    transaction.commit()?;
+
+    assert!(manager.is_registered(&id)?);
+
+    Ok(())
+  }
+}
\ No newline at end of file
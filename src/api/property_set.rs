@@ -0,0 +1,249 @@
+use std::collections::{
+  HashMap,
+  HashSet
+};
+use crate::{
+  Result,
+  Error
+};
+use super::{
+  ProjectTransaction,
+  PropertyDescription,
+  PropertyManager,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use std::path::Path;
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Declarative set of user-defined properties and object types they are assigned to.
+///
+/// Property set can be applied to a project with [PropertyManager::apply]. Applying is idempotent:
+/// already registered properties and existing assignments are left untouched, but registered
+/// property with the same identifier must have the same name and type.
+///
+/// With `serde` feature enabled, property set can be loaded from JSON or TOML file.
+/// Example of TOML property set:
+/// ```toml
+/// [[properties]]
+/// id = "5B0DBA66-6F4C-4C41-A7B8-9F3B2F7C0D11"
+/// name = "Fire rating"
+/// type = "Enumeration"
+/// enumeration_items = ["EI 30", "EI 60", "EI 90"]
+/// assign_to = ["4329112A-6B65-48D9-9DA8-ABF1F8F36327"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct PropertySet {
+  #[cfg_attr(feature="serde", serde(default))]
+  pub properties: Vec<PropertySetEntry>
+}
+
+/// Single property of a [PropertySet].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct PropertySetEntry {
+  /// Description of the property.
+  #[cfg_attr(feature="serde", serde(flatten))]
+  pub description: PropertyDescription,
+
+  /// Object types the property must be assigned to.
+  #[cfg_attr(feature="serde", serde(default))]
+  pub assign_to: Vec<UUID>
+}
+
+/// Change required to bring project in line with a [PropertySet].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyAction {
+  /// Property must be registered.
+  Register(PropertyDescription),
+
+  /// Property must be assigned to object type.
+  Assign {
+    property_id: UUID,
+    object_type: UUID
+  }
+}
+
+impl PropertySet {
+  /// Parses property set from JSON string.
+  #[cfg(feature="serde")]
+  pub fn from_json(json: &str) -> Result<Self> {
+    serde_json::from_str(json).map_err(|e| crate::Error::ParseError(e.to_string()))
+  }
+
+  /// Parses property set from TOML string.
+  #[cfg(feature="serde")]
+  pub fn from_toml(toml: &str) -> Result<Self> {
+    toml::from_str(toml).map_err(|e| crate::Error::ParseError(e.to_string()))
+  }
+
+  /// Loads property set from file. Format is chosen by file extension (`.json` or `.toml`).
+  #[cfg(feature="serde")]
+  pub fn from_file(path: &Path) -> Result<Self> {
+    let contents = std::fs::read_to_string(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+      Some("json") => Self::from_json(&contents),
+      Some("toml") => Self::from_toml(&contents),
+      _ => Err(crate::Error::ParseError(format!("Unsupported property set format: {}", path.display())))
+    }
+  }
+
+  /// Computes actions required to apply this set, given descriptions of already registered
+  /// properties and existing `(property, object type)` assignments.
+  ///
+  /// Can return error in the following cases:
+  /// - [crate::Error::InvalidOperation]: Registered property has different name;
+  /// - [crate::Error::TypeMismatch]: Registered property has different type;
+  pub fn plan(&self, registered: &HashMap<UUID, PropertyDescription>, assigned: &HashSet<(UUID, UUID)>) -> Result<Vec<PropertyAction>> {
+    let mut actions = Vec::new();
+    for entry in &self.properties {
+      let id = &entry.description.id;
+      match registered.get(id) {
+        None => actions.push(PropertyAction::Register(entry.description.clone())),
+        Some(existing) if existing.name != entry.description.name => {
+          return Err(Error::InvalidOperation(format!(
+            "property {id} is registered as `{}`, not `{}`",
+            existing.name,
+            entry.description.name
+          )));
+        }
+        Some(existing) if existing.property_type != entry.description.property_type => {
+          return Err(Error::TypeMismatch(format!(
+            "property `{}` is registered with type {:?}, not {:?}",
+            existing.name,
+            existing.property_type,
+            entry.description.property_type
+          )));
+        }
+        Some(_) => ()
+      }
+      for object_type in &entry.assign_to {
+        if !assigned.contains(&(id.clone(), object_type.clone())) {
+          actions.push(PropertyAction::Assign {
+            property_id: id.clone(),
+            object_type: object_type.clone()
+          });
+        }
+      }
+    }
+    Ok(actions)
+  }
+}
+
+impl PropertyManager {
+  /// Applies property set to the project and returns actions that were performed.
+  ///
+  /// Applying the same set twice performs no actions the second time. Nothing is applied
+  /// if a registered property does not match the set, see [PropertySet::plan].
+  pub fn apply(&mut self, transaction: &ProjectTransaction, set: &PropertySet) -> Result<Vec<PropertyAction>> {
+    let mut registered = HashMap::new();
+    let mut assigned = HashSet::new();
+    for entry in &set.properties {
+      let id = &entry.description.id;
+      if !self.is_registered(id)? {
+        continue;
+      }
+      registered.insert(id.clone(), self.description(id)?);
+      for object_type in &entry.assign_to {
+        if self.is_assigned(id, object_type)? {
+          assigned.insert((id.clone(), object_type.clone()));
+        }
+      }
+    }
+    let actions = set.plan(&registered, &assigned)?;
+    for action in &actions {
+      match action {
+        PropertyAction::Register(description) => self.register(transaction, description)?,
+        PropertyAction::Assign { property_id, object_type } => self.assign(transaction, property_id, object_type)?
+      }
+    }
+    Ok(actions)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::{
+    HashMap,
+    HashSet
+  };
+  use crate::*;
+
+  fn fire_rating() -> PropertyDescription {
+    PropertyDescription::new(
+      "5B0DBA66-6F4C-4C41-A7B8-9F3B2F7C0D11".parse().unwrap(),
+      "Fire rating",
+      PropertyType::Enumeration
+    ).with_enumeration_items(&["EI 30", "EI 60", "EI 90"])
+  }
+
+  fn wall() -> UUID {
+    "4329112A-6B65-48D9-9DA8-ABF1F8F36327".parse().unwrap()
+  }
+
+  #[test]
+  fn test_plan() -> anyhow::Result<()> {
+    let set = PropertySet {
+      properties: vec![PropertySetEntry {
+        description: fire_rating(),
+        assign_to: vec![wall()]
+      }]
+    };
+
+    let actions = set.plan(&HashMap::new(), &HashSet::new())?;
+    assert_eq!(actions.len(), 2);
+    assert_eq!(actions[0], PropertyAction::Register(fire_rating()));
+
+    let registered = HashMap::from([(fire_rating().id, fire_rating())]);
+    let actions = set.plan(&registered, &HashSet::new())?;
+    assert_eq!(actions, vec![PropertyAction::Assign { property_id: fire_rating().id, object_type: wall() }]);
+
+    let assigned = HashSet::from([(fire_rating().id, wall())]);
+    assert!(set.plan(&registered, &assigned)?.is_empty());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_plan_mismatch() {
+    let set = PropertySet {
+      properties: vec![PropertySetEntry {
+        description: fire_rating(),
+        assign_to: vec![]
+      }]
+    };
+    let renamed = PropertyDescription::new(fire_rating().id, "Fire resistance", PropertyType::Enumeration);
+    let retyped = PropertyDescription::new(fire_rating().id, "Fire rating", PropertyType::String);
+
+    assert!(matches!(
+      set.plan(&HashMap::from([(fire_rating().id, renamed)]), &HashSet::new()),
+      Err(Error::InvalidOperation(_))
+    ));
+    assert!(matches!(
+      set.plan(&HashMap::from([(fire_rating().id, retyped)]), &HashSet::new()),
+      Err(Error::TypeMismatch(_))
+    ));
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_from_file() -> anyhow::Result<()> {
+    let toml = PropertySet::from_file(&external_file("property_set.toml")?)?;
+    let json = PropertySet::from_file(&external_file("property_set.json")?)?;
+
+    assert_eq!(toml, json);
+    assert_eq!(toml.properties.len(), 3);
+    assert_eq!(toml.properties[0].description, fire_rating());
+    assert_eq!(toml.properties[0].assign_to, vec![wall()]);
+    assert_eq!(toml.properties[2].description.expression.as_deref(), Some("Length * 2"));
+
+    Ok(())
+  }
+}
\ No newline at end of file
@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use crate::{
+  native::{
+    constants,
+    Dispatch
+  },
+  Result,
+  Error
+};
+use super::{
+  units::{
+    AreaUnit,
+    LengthUnit,
+    MassUnit,
+    VolumeUnit
+  },
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Type of computed quantity.
+///
+/// See [Official documentation](https://help.rengabim.com/api/group___quantity_types.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum QuantityType {
+  Length,
+  Area,
+  Volume,
+  Mass,
+  Count,
+
+  /// Quantity type unknown to this crate.
+  Unknown(i32)
+}
+
+impl QuantityType {
+  /// Converts native `QuantityType` value to enum.
+  pub fn from_native(value: i32) -> Self {
+    match value {
+      constants::QUANTITY_TYPE_LENGTH => Self::Length,
+      constants::QUANTITY_TYPE_AREA => Self::Area,
+      constants::QUANTITY_TYPE_VOLUME => Self::Volume,
+      constants::QUANTITY_TYPE_MASS => Self::Mass,
+      constants::QUANTITY_TYPE_COUNT => Self::Count,
+      other => Self::Unknown(other)
+    }
+  }
+}
+
+/// Value of computed quantity, stored in base units (see [crate::units]).
+///
+/// Use unit-aware accessors to get value in desired unit:
+/// ```no_run
+/// use renga_api_rs as renga;
+/// use renga::units::LengthUnit;
+///
+/// let value = renga::QuantityValue::Length(2750.0);
+/// assert_eq!(value.length(LengthUnit::Meters), Some(2.75));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum QuantityValue {
+  /// Length in millimeters.
+  Length(f64),
+
+  /// Area in square meters.
+  Area(f64),
+
+  /// Volume in cubic meters.
+  Volume(f64),
+
+  /// Mass in kilograms.
+  Mass(f64),
+
+  /// Number of items.
+  Count(i32)
+}
+
+impl QuantityValue {
+  /// Returns length in given unit or `None` if this is not a length.
+  pub fn length(&self, unit: LengthUnit) -> Option<f64> {
+    match self {
+      Self::Length(value) => Some(unit.from_base(*value)),
+      _ => None
+    }
+  }
+
+  /// Returns area in given unit or `None` if this is not an area.
+  pub fn area(&self, unit: AreaUnit) -> Option<f64> {
+    match self {
+      Self::Area(value) => Some(unit.from_base(*value)),
+      _ => None
+    }
+  }
+
+  /// Returns volume in given unit or `None` if this is not a volume.
+  pub fn volume(&self, unit: VolumeUnit) -> Option<f64> {
+    match self {
+      Self::Volume(value) => Some(unit.from_base(*value)),
+      _ => None
+    }
+  }
+
+  /// Returns mass in given unit or `None` if this is not a mass.
+  pub fn mass(&self, unit: MassUnit) -> Option<f64> {
+    match self {
+      Self::Mass(value) => Some(unit.from_base(*value)),
+      _ => None
+    }
+  }
+
+  /// Returns count or `None` if this is not a count.
+  pub fn count(&self) -> Option<i32> {
+    match self {
+      Self::Count(value) => Some(*value),
+      _ => None
+    }
+  }
+
+  /// Returns type of this value.
+  pub fn quantity_type(&self) -> QuantityType {
+    match self {
+      Self::Length(_) => QuantityType::Length,
+      Self::Area(_) => QuantityType::Area,
+      Self::Volume(_) => QuantityType::Volume,
+      Self::Mass(_) => QuantityType::Mass,
+      Self::Count(_) => QuantityType::Count
+    }
+  }
+}
+
+/// Represents single computed quantity of a model object.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_quantity.html)
+#[derive(Debug, Clone)]
+pub struct Quantity {
+  /// Identifier of the quantity.
+  pub id: UUID,
+
+  /// Type of the quantity.
+  pub quantity_type: QuantityType,
+  handle: Dispatch
+}
+
+impl Quantity {
+  pub fn new(id: UUID, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IQuantity handle is null".to_owned()));
+    }
+    Ok(Self {
+      id,
+      quantity_type: QuantityType::from_native(handle
+        .get("Type")?
+        .as_int()?
+      ),
+      handle
+    })
+  }
+
+  /// Returns value of the quantity or `None` if it can not be computed for the object.
+  pub fn value(&self) -> Result<Option<QuantityValue>> {
+    if !self.handle.call("HasValue", None)?.as_bool()? {
+      return Ok(None);
+    }
+    let value = match self.quantity_type {
+      QuantityType::Length => QuantityValue::Length(self
+        .handle
+        .call("AsLength", Some(vec![constants::LENGTH_UNIT_MILLIMETERS.into()]))?
+        .as_f64()?),
+      QuantityType::Area => QuantityValue::Area(self
+        .handle
+        .call("AsArea", Some(vec![constants::AREA_UNIT_METERS2.into()]))?
+        .as_f64()?),
+      QuantityType::Volume => QuantityValue::Volume(self
+        .handle
+        .call("AsVolume", Some(vec![constants::VOLUME_UNIT_METERS3.into()]))?
+        .as_f64()?),
+      QuantityType::Mass => QuantityValue::Mass(self
+        .handle
+        .call("AsMass", Some(vec![constants::MASS_UNIT_KILOGRAMS.into()]))?
+        .as_f64()?),
+      QuantityType::Count => QuantityValue::Count(self
+        .handle
+        .call("AsCount", None)?
+        .as_int()?),
+      QuantityType::Unknown(quantity_type) => {
+        return Err(Error::TypeMismatch(format!("unsupported quantity type {quantity_type}")))
+      }
+    };
+    Ok(Some(value))
+  }
+}
+
+/// Container of computed quantities of a model object.
+///
+/// Can be obtained using [crate::ModelObject::quantities] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_quantity_container.html)
+#[derive(Debug, Clone)]
+pub struct QuantityContainer {
+  handle: Dispatch
+}
+
+impl QuantityContainer {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IQuantityContainer handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns identifiers of all quantities in the container.
+  pub fn ids(&self) -> Result<Vec<UUID>> {
+    Ok(self
+      .handle
+      .call("GetIds", None)?
+      .as_guid_vec()?
+      .into_iter()
+      .map(UUID::from)
+      .collect())
+  }
+
+  /// Returns quantity with given identifier or `None` if object has no such quantity.
+  pub fn get(&self, id: &UUID) -> Result<Option<Quantity>> {
+    let contains = self
+      .handle
+      .call("ContainsS", Some(vec![id.to_string().into()]))?
+      .as_bool()?;
+    if !contains {
+      return Ok(None);
+    }
+    let handle = self
+      .handle
+      .call("GetS", Some(vec![id.to_string().into()]))?
+      .into_dispatch()?;
+    Ok(Some(Quantity::new(id.clone(), handle)?))
+  }
+
+  /// Returns values of all computable quantities, keyed by quantity identifier.
+  pub fn values(&self) -> Result<HashMap<UUID, QuantityValue>> {
+    let mut values = HashMap::new();
+    for id in self.ids()? {
+      let value = match self.get(&id)? {
+        Some(quantity) => quantity.value()?,
+        None => None
+      };
+      if let Some(value) = value {
+        values.insert(id, value);
+      }
+    }
+    Ok(values)
+  }
+}
+
+impl TryFrom<Dispatch> for QuantityContainer {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::*;
+  use crate::units::*;
+
+  #[test]
+  fn test_unit_aware_accessors() {
+    let length = QuantityValue::Length(2750.0);
+    let volume = QuantityValue::Volume(1.5);
+    let mass = QuantityValue::Mass(11775.0);
+
+    assert_eq!(length.length(LengthUnit::Meters), Some(2.75));
+    assert_eq!(length.length(LengthUnit::Centimeters), Some(275.0));
+    assert_eq!(length.area(AreaUnit::Meters2), None);
+    assert_eq!(volume.volume(VolumeUnit::Meters3), Some(1.5));
+    assert_eq!(mass.mass(MassUnit::Tonnes), Some(11.775));
+    assert_eq!(QuantityValue::Count(4).count(), Some(4));
+    assert_eq!(volume.quantity_type(), QuantityType::Volume);
+  }
+
+  #[test]
+  fn test_quantity_type_from_native() {
+    assert_eq!(QuantityType::from_native(3), QuantityType::Volume);
+    assert_eq!(QuantityType::from_native(-1), QuantityType::Unknown(-1));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,225 @@
+use crate::{
+  native::Dispatch,
+  reinforcement::Rebar,
+  Result,
+  Error
+};
+use super::Entity;
+
+/// Represents style of reinforcing bars: diameter, grade and bending parameters.
+///
+/// Can be obtained using [crate::Project::rebar_styles] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_rebar_style.html)
+#[derive(Debug, Clone)]
+pub struct RebarStyle {
+  pub entity: Entity,
+
+  /// Nominal diameter of a bar in millimeters.
+  pub diameter: f64,
+
+  /// Identifier of the reinforcement grade entity, see [crate::Project::reinforcement_grades].
+  pub grade_id: i32,
+
+  /// Minimal inner radius of bends in millimeters.
+  pub bending_radius: f64
+}
+
+impl RebarStyle {
+  /// Creates new instance of RebarStyle from style entity.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if entity is not a rebar style.
+  pub fn new(entity: Entity) -> Result<Self> {
+    let handle = entity
+      .interface("IRebarStyle")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{entity} is not a rebar style")))?;
+    Ok(Self {
+      diameter: handle
+        .get("Diameter")?
+        .as_f64()?,
+      grade_id: handle
+        .get("GradeId")?
+        .as_int()?,
+      bending_radius: handle
+        .get("BendingRadius")?
+        .as_f64()?,
+      entity
+    })
+  }
+}
+
+/// Represents usage of a [RebarStyle]: a group of identical bars in a model object or
+/// in a reinforcement unit style.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_rebar_usage.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RebarUsage {
+  /// Identifier of the [RebarStyle] of bars.
+  pub style_id: i32,
+
+  /// Number of bars.
+  pub count: u32,
+
+  /// Total length of all bars in millimeters.
+  pub total_length: f64
+}
+
+impl RebarUsage {
+  /// Creates new instance of RebarUsage from native handle.
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IRebarUsage handle is null".to_owned()));
+    }
+    Ok(Self {
+      style_id: handle
+        .get("StyleId")?
+        .as_int()?,
+      count: handle
+        .get("Count")?
+        .as_int()?
+        .max(0) as u32,
+      total_length: handle
+        .get("TotalLength")?
+        .as_f64()?
+    })
+  }
+
+  /// Converts usage to schedule item.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if its style is not one of `styles` or grade of the style
+  /// is not one of `grades`.
+  pub fn to_rebar(&self, styles: &[RebarStyle], grades: &[Entity]) -> Result<Rebar> {
+    let style = styles
+      .iter()
+      .find(|style| style.entity.id == self.style_id)
+      .ok_or_else(|| Error::InvalidOperation(format!("Unknown rebar style #{}", self.style_id)))?;
+    let grade = grades
+      .iter()
+      .find(|grade| grade.id == style.grade_id)
+      .ok_or_else(|| Error::InvalidOperation(format!("Unknown reinforcement grade #{} of rebar style '{}'", style.grade_id, style.entity.name)))?;
+    Ok(Rebar::new(&grade.name, style.diameter, self.count, self.total_length))
+  }
+}
+
+/// Reads usages from native `IRebarUsageCollection`.
+pub(crate) fn rebar_usages(handle: Dispatch) -> Result<Vec<RebarUsage>> {
+  let count = handle
+    .get("Count")?
+    .as_int()?;
+  (0..count)
+    .map(|index| handle
+      .call("Get", Some(vec![index.into()]))?
+      .into_dispatch()
+      .and_then(RebarUsage::new))
+    .collect()
+}
+
+/// Represents style of reinforcement units: a reusable group of bars (e.g. a cage or a mesh).
+///
+/// Can be obtained using [crate::Project::reinforcement_unit_styles] method. Styles are created
+/// and edited with [crate::StyleManager] of [crate::StyleKind::ReinforcementUnit].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_reinforcement_unit_style.html)
+#[derive(Debug, Clone)]
+pub struct ReinforcementUnitStyle {
+  pub entity: Entity,
+  handle: Dispatch
+}
+
+impl ReinforcementUnitStyle {
+  /// Creates new instance of ReinforcementUnitStyle from style entity.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if entity is not a reinforcement unit style.
+  pub fn new(entity: Entity) -> Result<Self> {
+    let handle = entity
+      .interface("IReinforcementUnitStyle")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{entity} is not a reinforcement unit style")))?;
+    Ok(Self { entity, handle })
+  }
+
+  /// Returns bars of a single unit of this style.
+  pub fn rebar_usages(&self) -> Result<Vec<RebarUsage>> {
+    rebar_usages(self
+      .handle
+      .call("GetRebarUsages", None)?
+      .into_dispatch()?)
+  }
+}
+
+/// Represents reinforcement of a model object: bars placed directly and reinforcement units.
+///
+/// Can be obtained using [crate::ModelObject::reinforcement] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_object_reinforcement_model.html)
+#[derive(Debug, Clone)]
+pub struct ReinforcementModel {
+  /// Identifier of the reinforced model object.
+  pub object_id: i32,
+  handle: Dispatch
+}
+
+impl ReinforcementModel {
+  /// Creates new instance of ReinforcementModel from native handle.
+  pub fn new(object_id: i32, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IObjectReinforcementModel handle is null".to_owned()));
+    }
+    Ok(Self { object_id, handle })
+  }
+
+  /// Returns bars placed directly into the object.
+  pub fn rebar_usages(&self) -> Result<Vec<RebarUsage>> {
+    rebar_usages(self
+      .handle
+      .call("GetRebarUsages", None)?
+      .into_dispatch()?)
+  }
+
+  /// Returns identifiers of [ReinforcementUnitStyle]s of units placed into the object,
+  /// one identifier per unit.
+  pub fn unit_style_ids(&self) -> Result<Vec<i32>> {
+    let handle = self
+      .handle
+      .call("GetReinforcementUnitUsages", None)?
+      .into_dispatch()?;
+    let count = handle
+      .get("Count")?
+      .as_int()?;
+    (0..count)
+      .map(|index| handle
+        .call("Get", Some(vec![index.into()]))?
+        .into_dispatch()?
+        .get("StyleId")?
+        .as_int())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_reinforcement(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let grades = ctx.project.reinforcement_grades()?;
+    let styles = ctx.project.rebar_styles()?;
+
+    for style in &styles {
+      assert!(style.diameter > 0.0);
+      assert!(grades.iter().any(|grade| grade.id == style.grade_id));
+    }
+    assert!(ctx.project.rebar_schedule()?.is_empty());
+
+    let mut manager = ctx.project.style_manager(StyleKind::ReinforcementUnit)?;
+    let transaction = ctx.project.start_transaction()?;
+    let style = manager.create(&transaction, "Cage", None)?;
+    let unit = ReinforcementUnitStyle::new(style)?;
+
+    assert!(unit.rebar_usages()?.is_empty());
+    transaction.rollback()?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,252 @@
+use std::{
+  thread,
+  time::{
+    Duration,
+    Instant
+  }
+};
+use crate::{
+  native::{
+    Dispatch,
+    Variant
+  },
+  Result,
+  Error
+};
+use super::{
+  ModelObject,
+  ModelObjectCollection,
+  UUID
+};
+
+/// Represents selection of model objects in Renga user interface.
+///
+/// Can be obtained using [crate::Application::selection] method.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_selection.html)
+#[derive(Debug, Clone)]
+pub struct Selection {
+  handle: Dispatch,
+  objects: ModelObjectCollection
+}
+
+/// Change of selection, detected by [SelectionWatcher].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionChange {
+  /// Identifiers of all currently selected objects.
+  pub selected: Vec<i32>,
+
+  /// Identifiers of objects which were selected since the previous check.
+  pub added: Vec<i32>,
+
+  /// Identifiers of objects which were deselected since the previous check.
+  pub removed: Vec<i32>
+}
+
+/// Polls [Selection] and reports its changes.
+///
+/// [SelectionWatcher::wait] blocks the calling thread and must not be used on Renga UI thread.
+///
+/// Can be obtained using [Selection::watch] method. For example, to print selected objects
+/// until the user selects nothing:
+/// ```no_run
+/// use std::time::Duration;
+/// use renga_api_rs as renga;
+///
+/// let mut app = renga::Application::new().unwrap();
+/// let mut watcher = app.selection().unwrap().watch().unwrap();
+/// while let Some(change) = watcher.wait(Duration::from_millis(200), None).unwrap() {
+///   if change.selected.is_empty() {
+///     break;
+///   }
+///   for object in watcher.selection().objects().unwrap() {
+///     println!("{object}");
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SelectionWatcher {
+  selection: Selection,
+  last: Vec<i32>
+}
+
+impl Selection {
+  /// Creates new instance of Selection from native handle.
+  ///
+  /// Selected identifiers are resolved using `objects` collection of the project model.
+  pub fn new(handle: Dispatch, objects: ModelObjectCollection) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("ISelection handle is null".to_owned()));
+    }
+    Ok(Self { handle, objects })
+  }
+
+  /// Returns identifiers of selected model objects.
+  pub fn ids(&self) -> Result<Vec<i32>> {
+    self
+      .handle
+      .call("GetSelectedObjects", None)?
+      .as_int_vec()
+  }
+
+  /// Returns selected model objects.
+  ///
+  /// Identifiers which do not resolve to a model object are skipped.
+  pub fn objects(&self) -> Result<Vec<ModelObject>> {
+    let mut objects = Vec::new();
+    for id in self.ids()? {
+      if let Some(object) = self.objects.get_by_id(id)? {
+        objects.push(object);
+      }
+    }
+    Ok(objects)
+  }
+
+  /// Returns unique identifiers of selected model objects.
+  pub fn unique_ids(&self) -> Result<Vec<UUID>> {
+    Ok(self
+      .objects()?
+      .into_iter()
+      .map(|object| object.unique_id)
+      .collect())
+  }
+
+  /// Returns `true` if nothing is selected.
+  pub fn is_empty(&self) -> Result<bool> {
+    Ok(self.ids()?.is_empty())
+  }
+
+  /// Replaces selection with given model objects.
+  pub fn select(&mut self, objects: &[ModelObject]) -> Result<()> {
+    let ids: Vec<i32> = objects
+      .iter()
+      .map(|object| object.id)
+      .collect();
+    self.select_ids(&ids)
+  }
+
+  /// Replaces selection with model objects with given identifiers.
+  pub fn select_ids(&mut self, ids: &[i32]) -> Result<()> {
+    self.handle.call("SetSelectedObjects", Some(vec![Variant::from_ints(ids)?]))?;
+    Ok(())
+  }
+
+  /// Clears selection.
+  pub fn clear(&mut self) -> Result<()> {
+    self.select_ids(&[])
+  }
+
+  /// Creates watcher, which reports changes relative to current selection.
+  pub fn watch(&self) -> Result<SelectionWatcher> {
+    Ok(SelectionWatcher {
+      selection: self.clone(),
+      last: self.ids()?
+    })
+  }
+}
+
+impl SelectionChange {
+  /// Compares two lists of selected identifiers. Returns `None` if they contain the same objects.
+  pub fn between(previous: &[i32], current: &[i32]) -> Option<Self> {
+    let added: Vec<i32> = current
+      .iter()
+      .filter(|id| !previous.contains(id))
+      .copied()
+      .collect();
+    let removed: Vec<i32> = previous
+      .iter()
+      .filter(|id| !current.contains(id))
+      .copied()
+      .collect();
+    match added.is_empty() && removed.is_empty() {
+      true => None,
+      false => Some(Self {
+        selected: current.to_vec(),
+        added,
+        removed
+      })
+    }
+  }
+}
+
+impl SelectionWatcher {
+  /// Returns watched selection.
+  pub fn selection(&self) -> &Selection {
+    &self.selection
+  }
+
+  /// Checks selection once. Returns `None` if it has not changed since the previous check.
+  pub fn poll(&mut self) -> Result<Option<SelectionChange>> {
+    let current = self.selection.ids()?;
+    let change = SelectionChange::between(&self.last, &current);
+    self.last = current;
+    Ok(change)
+  }
+
+  /// Polls selection every `interval` until it changes.
+  ///
+  /// Returns `None` if selection has not changed within `timeout`. Without timeout, waits indefinitely.
+  ///
+  /// This blocks the calling thread. Renga updates selection on its own UI thread, so this method
+  /// must never be called from it (e.g. from a plugin or an action handler) - selection would never
+  /// change and Renga would hang. Use [SelectionWatcher::poll] from such code instead.
+  pub fn wait(&mut self, interval: Duration, timeout: Option<Duration>) -> Result<Option<SelectionChange>> {
+    let started = Instant::now();
+    loop {
+      if let Some(change) = self.poll()? {
+        return Ok(Some(change));
+      }
+      if matches!(timeout, Some(timeout) if started.elapsed() >= timeout) {
+        return Ok(None);
+      }
+      thread::sleep(interval);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+  use test_context::test_context;
+  use crate::*;
+
+  #[test]
+  fn test_selection_change() {
+    assert_eq!(SelectionChange::between(&[1, 2], &[2, 1]), None);
+    assert_eq!(SelectionChange::between(&[1, 2], &[2, 3]), Some(SelectionChange {
+      selected: vec![2, 3],
+      added: vec![3],
+      removed: vec![1]
+    }));
+    assert_eq!(SelectionChange::between(&[4], &[]), Some(SelectionChange {
+      selected: vec![],
+      added: vec![],
+      removed: vec![4]
+    }));
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_select_and_watch(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let levels = ctx.project.model()?.levels()?;
+    let mut selection = ctx.app.selection()?;
+    selection.clear()?;
+    let mut watcher = selection.watch()?;
+
+    assert!(selection.is_empty()?);
+    assert_eq!(watcher.poll()?, None);
+
+    if let Some(level) = levels.first() {
+      selection.select_ids(&[level.id])?;
+
+      assert_eq!(selection.ids()?, vec![level.id]);
+      assert_eq!(selection.unique_ids()?, vec![level.unique_id.clone()]);
+      assert_eq!(
+        watcher.wait(Duration::from_millis(10), Some(Duration::from_secs(1)))?.map(|change| change.added),
+        Some(vec![level.id])
+      );
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,198 @@
+use crate::{
+  native::Dispatch,
+  Result,
+  Error
+};
+use super::{
+  Entity,
+  EntityCollection,
+  ParameterValue,
+  ProjectTransaction,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Kind of styles that can be managed by [StyleManager].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum StyleKind {
+  Equipment,
+  PlumbingFixture,
+  Beam,
+  Column,
+  System,
+  ReinforcementUnit
+}
+
+impl StyleKind {
+  /// Returns name of `IProject` property with the style manager of this kind.
+  pub fn manager_property(&self) -> String {
+    format!("{self:?}StyleManager")
+  }
+
+  /// Returns name of `IProject` property with the collection of styles of this kind.
+  pub fn collection_property(&self) -> String {
+    format!("{self:?}Styles")
+  }
+}
+
+/// Represents style manager of a single [StyleKind].
+///
+/// Can be obtained using [crate::Project::style_manager] method.
+/// All mutating methods require active [ProjectTransaction].
+///
+/// For example, to create a pump style and set its width:
+/// ```no_run
+/// use renga_api_rs as renga;
+///
+/// let mut app = renga::Application::new().unwrap();
+/// let mut project = app.new_project().unwrap();
+/// let mut manager = project.style_manager(renga::StyleKind::Equipment).unwrap();
+/// let transaction = project.start_transaction().unwrap();
+/// let style = manager.create(&transaction, "Pump 600", None).unwrap();
+/// let width = style.parameters().unwrap().get_by_name("Width").unwrap().unwrap();
+/// manager.set_parameter(&transaction, &style, &width.definition.id, renga::ParameterValue::Double(600.0)).unwrap();
+/// transaction.commit().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct StyleManager {
+  /// Kind of styles managed by this manager.
+  pub kind: StyleKind,
+  collection: EntityCollection,
+  handle: Dispatch
+}
+
+impl StyleManager {
+  pub fn new(kind: StyleKind, collection: EntityCollection, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal(format!("I{} handle is null", kind.manager_property())));
+    }
+    Ok(Self { kind, collection, handle })
+  }
+
+  /// Returns all styles of this kind.
+  pub fn styles(&self) -> Result<Vec<Entity>> {
+    self.collection.clone().into_vec()
+  }
+
+  /// Returns style with given identifier or `None` if there is no such style.
+  pub fn get(&self, id: i32) -> Result<Option<Entity>> {
+    Ok(self
+      .styles()?
+      .into_iter()
+      .find(|style| style.id == id))
+  }
+
+  /// Returns first style with given name or `None` if there is no such style.
+  pub fn find(&self, name: &str) -> Result<Option<Entity>> {
+    Ok(self
+      .styles()?
+      .into_iter()
+      .find(|style| style.name == name))
+  }
+
+  /// Creates new style with given name.
+  ///
+  /// Styles of some kinds (e.g. equipment) are based on a category, which can be passed as `category`.
+  pub fn create(&mut self, transaction: &ProjectTransaction, name: &str, category: Option<&Entity>) -> Result<Entity> {
+    transaction.ensure_active()?;
+    let category_id = category.map_or(0, |category| category.id);
+    self
+      .handle
+      .call("CreateStyle", Some(vec![name.into(), category_id.into()]))?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Sets value of style parameter with given identifier.
+  ///
+  /// Value is validated against parameter definition before being set.
+  /// Returns [Error::InvalidOperation] if `style` is not a style of this manager.
+  pub fn set_parameter(&mut self, transaction: &ProjectTransaction, style: &Entity, id: &UUID, value: ParameterValue) -> Result<()> {
+    if !matches!(self.get(style.id)?, Some(own) if own.unique_id == style.unique_id) {
+      return Err(Error::InvalidOperation(format!("Style '{}' is not a {:?} style", style.name, self.kind)));
+    }
+    let mut parameter = style
+      .parameters()?
+      .get(id)?
+      .ok_or_else(|| Error::InvalidOperation(format!("Style '{}' has no parameter {id}", style.name)))?;
+    parameter.set_value(transaction, value)
+  }
+
+  /// Renames style and returns renamed entity.
+  pub fn rename(&mut self, transaction: &ProjectTransaction, style: &Entity, name: &str) -> Result<Entity> {
+    transaction.ensure_active()?;
+    self.handle.call("RenameStyle", Some(vec![style.id.into(), name.into()]))?;
+    self
+      .get(style.id)?
+      .ok_or_else(|| Error::InvalidOperation(format!("Style #{} was removed", style.id)))
+  }
+
+  /// Creates a copy of style with all its parameters under a new name.
+  pub fn duplicate(&mut self, transaction: &ProjectTransaction, style: &Entity, name: &str) -> Result<Entity> {
+    transaction.ensure_active()?;
+    self
+      .handle
+      .call("DuplicateStyle", Some(vec![style.id.into(), name.into()]))?
+      .into_dispatch()?
+      .try_into()
+  }
+
+  /// Removes style from the project.
+  ///
+  /// Returns [Error::InvalidOperation] if style is still used by model objects.
+  pub fn delete(&mut self, transaction: &ProjectTransaction, style: &Entity) -> Result<()> {
+    transaction.ensure_active()?;
+    let removed = self
+      .handle
+      .call("RemoveStyle", Some(vec![style.id.into()]))?
+      .as_bool()?;
+    match removed {
+      true => Ok(()),
+      false => Err(Error::InvalidOperation(format!("Style '{}' can not be removed", style.name)))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test]
+  fn test_property_names() {
+    assert_eq!(StyleKind::Equipment.manager_property(), "EquipmentStyleManager");
+    assert_eq!(StyleKind::ReinforcementUnit.collection_property(), "ReinforcementUnitStyles");
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_style_lifecycle(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let mut manager = ctx.project.style_manager(StyleKind::Equipment)?;
+    let mut beams = ctx.project.style_manager(StyleKind::Beam)?;
+    let transaction = ctx.project.start_transaction()?;
+    let style = manager.create(&transaction, "Test style", None)?;
+    let renamed = manager.rename(&transaction, &style, "Renamed style")?;
+    let copy = manager.duplicate(&transaction, &renamed, "Copied style")?;
+
+    assert_eq!(renamed.name, "Renamed style");
+    assert!(manager.find("Copied style")?.is_some());
+    assert!(matches!(
+      beams.set_parameter(&transaction, &renamed, &UUID::default(), ParameterValue::Double(1.0)),
+      Err(Error::InvalidOperation(_))
+    ));
+
+    manager.delete(&transaction, &copy)?;
+    assert!(manager.find("Copied style")?.is_none());
+    transaction.rollback()?;
+
+    assert!(manager.find("Renamed style")?.is_none());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,247 @@
+use std::{
+  collections::HashSet,
+  fmt::Display
+};
+use crate::{
+  native::Dispatch,
+  Result,
+  Error
+};
+use super::{
+  Entity,
+  ModelObject,
+  ModelObjectCollection,
+  Project,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Represents topic: a named group of numbered entities, e.g. an issue or a set of drawings.
+///
+/// Can be obtained using [crate::Project::topics] method. Number of an entity inside its topic
+/// is returned by [crate::Project::entity_number_in_topic].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_topic.html)
+#[derive(Debug, Clone)]
+pub struct Topic {
+  pub entity: Entity,
+  handle: Dispatch
+}
+
+impl Topic {
+  /// Creates new instance of Topic from topic entity.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if entity is not a topic.
+  pub fn new(entity: Entity) -> Result<Self> {
+    let handle = entity
+      .interface("ITopic")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{entity} is not a topic")))?;
+    Ok(Self { entity, handle })
+  }
+
+  /// Returns unique identifiers of entities included into the topic.
+  pub fn entity_ids(&self) -> Result<Vec<UUID>> {
+    Ok(self
+      .handle
+      .call("GetEntityIds", None)?
+      .as_guid_vec()?
+      .into_iter()
+      .map(UUID::from)
+      .collect())
+  }
+}
+
+impl Display for Topic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "<topic: {}>", self.entity.name)
+  }
+}
+
+/// In-memory index of topics and their numbered entries, mapping both ways between
+/// topic entries and unique identifiers of entities (model objects, drawings etc.).
+///
+/// Renga numbers an entity once, so an entity belongs to at most one topic and
+/// [TopicIndex::from_parts] rejects entities listed in several topics. Entries, which are
+/// model objects, are resolved with [TopicEntry::object].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct TopicIndex {
+  /// Topics, ordered by name.
+  pub topics: Vec<TopicNode>
+}
+
+/// Topic node of the [TopicIndex].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct TopicNode {
+  pub id: i32,
+  pub unique_id: UUID,
+  pub name: String,
+
+  /// Entries ordered by number, entries without number go last.
+  pub entries: Vec<TopicEntry>
+}
+
+/// Entry of a [TopicNode]: entity and its number inside the topic.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct TopicEntry {
+  /// Unique identifier of the entity.
+  pub unique_id: UUID,
+
+  /// Number of the entity inside the topic, if Renga assigned one.
+  pub number: Option<i32>
+}
+
+impl TopicEntry {
+  /// Returns model object of the entry from `objects` (see [crate::Model::objects]).
+  ///
+  /// Returns `None` if the entity is not a model object, e.g. it is a drawing.
+  pub fn object(&self, objects: &ModelObjectCollection) -> Result<Option<ModelObject>> {
+    objects.get_by_unique_id(&self.unique_id)
+  }
+}
+
+impl TopicIndex {
+  /// Builds index of all topics of the project.
+  pub fn from_project(project: &Project) -> Result<Self> {
+    let mut topics = Vec::new();
+    for topic in project.topics()? {
+      let mut entries = Vec::new();
+      for unique_id in topic.entity_ids()? {
+        entries.push(TopicEntry {
+          number: project.entity_number_in_topic(&unique_id)?,
+          unique_id
+        });
+      }
+      topics.push(TopicNode {
+        id: topic.entity.id,
+        unique_id: topic.entity.unique_id,
+        name: topic.entity.name,
+        entries
+      });
+    }
+    Self::from_parts(topics)
+  }
+
+  /// Builds index from topic nodes, sorting topics by name and entries by number.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if an entity is listed more than once.
+  pub fn from_parts(mut topics: Vec<TopicNode>) -> Result<Self> {
+    let mut seen = HashSet::new();
+    for entry in topics.iter().flat_map(|topic| &topic.entries) {
+      if !seen.insert(&entry.unique_id) {
+        return Err(Error::InvalidOperation(format!("Entity {} is listed in topics more than once", entry.unique_id)));
+      }
+    }
+    topics.sort_by(|a, b| a.name.cmp(&b.name));
+    for topic in &mut topics {
+      topic.entries.sort_by_key(|entry| (entry.number.is_none(), entry.number));
+    }
+    Ok(Self { topics })
+  }
+
+  /// Returns topic with given unique identifier.
+  pub fn topic(&self, unique_id: &UUID) -> Option<&TopicNode> {
+    self.topics.iter().find(|topic| topic.unique_id == *unique_id)
+  }
+
+  /// Returns first topic with given name.
+  pub fn find(&self, name: &str) -> Option<&TopicNode> {
+    self.topics.iter().find(|topic| topic.name == name)
+  }
+
+  /// Returns topic containing given entity together with its number in it.
+  pub fn topic_of(&self, unique_id: &UUID) -> Option<(&TopicNode, Option<i32>)> {
+    self
+      .topics
+      .iter()
+      .find_map(|topic| topic
+        .entries
+        .iter()
+        .find(|entry| entry.unique_id == *unique_id)
+        .map(|entry| (topic, entry.number)))
+  }
+}
+
+impl TopicNode {
+  /// Returns unique identifier of the entity with given number.
+  pub fn entity(&self, number: i32) -> Option<&UUID> {
+    self
+      .entries
+      .iter()
+      .find(|entry| entry.number == Some(number))
+      .map(|entry| &entry.unique_id)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  fn topic(id: i32, name: &str, entries: &[(usize, Option<i32>)]) -> TopicNode {
+    TopicNode {
+      id,
+      unique_id: uuid(100 + id as usize),
+      name: name.to_owned(),
+      entries: entries
+        .iter()
+        .map(|&(entity, number)| TopicEntry {
+          unique_id: uuid(entity),
+          number
+        })
+        .collect()
+    }
+  }
+
+  #[test]
+  fn test_topic_index_from_parts() {
+    let index = TopicIndex::from_parts(vec![
+      topic(2, "Structure", &[(1, Some(2)), (3, None), (2, Some(1))]),
+      topic(1, "Architecture", &[(4, Some(5))])
+    ]).unwrap();
+
+    assert_eq!(index.topics[0].name, "Architecture");
+    let structure = index.find("Structure").unwrap();
+    assert_eq!(
+      structure.entries.iter().map(|entry| entry.number).collect::<Vec<_>>(),
+      vec![Some(1), Some(2), None]
+    );
+    assert_eq!(structure.entity(2), Some(&uuid(1)));
+    assert_eq!(structure.entity(3), None);
+    assert_eq!(index.topic(&uuid(102)).unwrap().name, "Structure");
+
+    assert_eq!(index.topic_of(&uuid(1)).map(|(topic, number)| (topic.name.as_str(), number)), Some(("Structure", Some(2))));
+    assert_eq!(index.topic_of(&uuid(4)).map(|(topic, number)| (topic.name.as_str(), number)), Some(("Architecture", Some(5))));
+    assert!(index.topic_of(&uuid(5)).is_none());
+
+    assert!(matches!(
+      TopicIndex::from_parts(vec![topic(1, "Architecture", &[(1, Some(5))]), topic(2, "Structure", &[(1, Some(2))])]),
+      Err(Error::InvalidOperation(_))
+    ));
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_topics(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let index = TopicIndex::from_project(&ctx.project)?;
+
+    assert_eq!(index.topics.len(), ctx.project.topics()?.len());
+    assert_eq!(ctx.project.entity_number_in_topic(&UUID::default())?, None);
+
+    let objects = ctx.project.model()?.objects()?;
+    for entry in index.topics.iter().flat_map(|topic| &topic.entries) {
+      if let Some(object) = entry.object(&objects)? {
+        assert_eq!(object.unique_id, entry.unique_id);
+      }
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,135 @@
+use crate::{
+  native::Dispatch,
+  Result,
+  Error
+};
+
+/// Single entry of the [UndoStack].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndoEntry {
+  /// Position of the entry in the stack, from the oldest to the newest.
+  pub index: usize,
+
+  /// Description of the operation, as shown in Renga Undo history.
+  pub description: String,
+
+  /// `true` if the operation was undone and can be redone.
+  pub undone: bool
+}
+
+/// Undo history of a model.
+///
+/// Can be obtained using [crate::Project::undo_stack] method. Changes made in transactions
+/// started with [crate::Project::start_undoable_transaction] are recorded here.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_undo_stack.html)
+#[derive(Debug, Clone)]
+pub struct UndoStack {
+  handle: Dispatch
+}
+
+impl UndoStack {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IUndoStack handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Returns total number of entries, including undone ones.
+  pub fn len(&self) -> Result<usize> {
+    Ok(self.handle.get("Count")?.as_int()?.max(0) as usize)
+  }
+
+  pub fn is_empty(&self) -> Result<bool> {
+    Ok(self.len()? == 0)
+  }
+
+  /// Returns all entries, from the oldest to the newest.
+  pub fn entries(&self) -> Result<Vec<UndoEntry>> {
+    let count = self.len()?;
+    let undo_count = self.handle.get("UndoCount")?.as_int()?.max(0) as usize;
+    (0..count)
+      .map(|index| Ok(UndoEntry {
+        index,
+        description: self
+          .handle
+          .call("GetDescription", Some(vec![(index as i32).into()]))?
+          .into_string()?,
+        undone: index >= undo_count
+      }))
+      .collect()
+  }
+
+  pub fn can_undo(&self) -> Result<bool> {
+    self.handle.get("CanUndo")?.as_bool()
+  }
+
+  pub fn can_redo(&self) -> Result<bool> {
+    self.handle.get("CanRedo")?.as_bool()
+  }
+
+  /// Undoes the newest operation.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if there is nothing to undo.
+  pub fn undo(&mut self) -> Result<()> {
+    if !self.can_undo()? {
+      return Err(Error::InvalidOperation("Nothing to undo".to_owned()));
+    }
+    self.handle.call("Undo", None)?;
+    Ok(())
+  }
+
+  /// Redoes the last undone operation.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if there is nothing to redo.
+  pub fn redo(&mut self) -> Result<()> {
+    if !self.can_redo()? {
+      return Err(Error::InvalidOperation("Nothing to redo".to_owned()));
+    }
+    self.handle.call("Redo", None)?;
+    Ok(())
+  }
+}
+
+impl TryFrom<Dispatch> for UndoStack {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_undo_redo(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let model_id = ctx.project.model()?.id()?;
+    let mut stack = ctx.project.undo_stack(&model_id)?;
+    let count = stack.len()?;
+
+    let transaction = ctx.project.start_undoable_transaction(&model_id)?;
+    transaction.set_project_info(&ProjectInfo {
+      code: Some("undo".to_owned()),
+      ..Default::default()
+    })?;
+    transaction.commit()?;
+
+    assert_eq!(stack.len()?, count + 1);
+    assert!(stack.can_undo()?);
+    assert!(!stack.entries()?.last().unwrap().undone);
+
+    stack.undo()?;
+    assert!(stack.can_redo()?);
+    assert!(stack.entries()?.last().unwrap().undone);
+
+    stack.redo()?;
+    assert!(stack.redo().is_err());
+    assert_eq!(ctx.project.project_info()?.code.as_deref(), Some("undo"));
+
+    Ok(())
+  }
+}
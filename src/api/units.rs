@@ -0,0 +1,198 @@
+//! Units of measurement used by dimensioned values.
+//!
+//! Renga reports lengths in millimeters, areas in square meters, volumes in cubic meters and
+//! masses in kilograms. These are called *base units* below; all conversions are done in Rust.
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Unit of length. Base unit is [LengthUnit::Millimeters].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum LengthUnit {
+  #[default]
+  Millimeters,
+  Centimeters,
+  Meters
+}
+
+/// Unit of area. Base unit is [AreaUnit::Meters2].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum AreaUnit {
+  Millimeters2,
+  Centimeters2,
+  #[default]
+  Meters2
+}
+
+/// Unit of volume. Base unit is [VolumeUnit::Meters3].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum VolumeUnit {
+  Millimeters3,
+  Centimeters3,
+  #[default]
+  Meters3
+}
+
+/// Unit of mass. Base unit is [MassUnit::Kilograms].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum MassUnit {
+  #[default]
+  Kilograms,
+  Tonnes
+}
+
+impl LengthUnit {
+  /// Returns how many base units (millimeters) are in one unit.
+  pub fn factor(&self) -> f64 {
+    match self {
+      Self::Millimeters => 1.0,
+      Self::Centimeters => 10.0,
+      Self::Meters => 1000.0
+    }
+  }
+
+  /// Returns unit symbol.
+  pub fn symbol(&self) -> &'static str {
+    match self {
+      Self::Millimeters => "mm",
+      Self::Centimeters => "cm",
+      Self::Meters => "m"
+    }
+  }
+}
+
+impl AreaUnit {
+  /// Returns how many base units (square meters) are in one unit.
+  pub fn factor(&self) -> f64 {
+    match self {
+      Self::Millimeters2 => 1e-6,
+      Self::Centimeters2 => 1e-4,
+      Self::Meters2 => 1.0
+    }
+  }
+
+  /// Returns unit symbol.
+  pub fn symbol(&self) -> &'static str {
+    match self {
+      Self::Millimeters2 => "mm²",
+      Self::Centimeters2 => "cm²",
+      Self::Meters2 => "m²"
+    }
+  }
+}
+
+impl VolumeUnit {
+  /// Returns how many base units (cubic meters) are in one unit.
+  pub fn factor(&self) -> f64 {
+    match self {
+      Self::Millimeters3 => 1e-9,
+      Self::Centimeters3 => 1e-6,
+      Self::Meters3 => 1.0
+    }
+  }
+
+  /// Returns unit symbol.
+  pub fn symbol(&self) -> &'static str {
+    match self {
+      Self::Millimeters3 => "mm³",
+      Self::Centimeters3 => "cm³",
+      Self::Meters3 => "m³"
+    }
+  }
+}
+
+impl MassUnit {
+  /// Returns how many base units (kilograms) are in one unit.
+  pub fn factor(&self) -> f64 {
+    match self {
+      Self::Kilograms => 1.0,
+      Self::Tonnes => 1000.0
+    }
+  }
+
+  /// Returns unit symbol.
+  pub fn symbol(&self) -> &'static str {
+    match self {
+      Self::Kilograms => "kg",
+      Self::Tonnes => "t"
+    }
+  }
+}
+
+macro_rules! impl_conversions {
+  ($($unit:ty),*) => {$(
+    impl $unit {
+      /// Converts value expressed in base units to this unit.
+      pub fn from_base(&self, value: f64) -> f64 { value / self.factor() }
+
+      /// Converts value expressed in this unit to base units.
+      pub fn to_base(&self, value: f64) -> f64 { value * self.factor() }
+
+      /// Converts value expressed in this unit to another unit.
+      pub fn convert(&self, value: f64, to: Self) -> f64 { to.from_base(self.to_base(value)) }
+    }
+  )*};
+}
+
+impl_conversions!(LengthUnit, AreaUnit, VolumeUnit, MassUnit);
+
+#[cfg(test)]
+mod tests {
+  use crate::units::*;
+
+  fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0), "{a} != {b}");
+  }
+
+  #[test]
+  fn test_length() {
+    assert_close(LengthUnit::Meters.from_base(2500.0), 2.5);
+    assert_close(LengthUnit::Centimeters.from_base(2500.0), 250.0);
+    assert_close(LengthUnit::Meters.convert(1.2, LengthUnit::Millimeters), 1200.0);
+    assert_close(LengthUnit::Centimeters.convert(15.0, LengthUnit::Meters), 0.15);
+  }
+
+  #[test]
+  fn test_area() {
+    assert_close(AreaUnit::Millimeters2.from_base(1.5), 1_500_000.0);
+    assert_close(AreaUnit::Centimeters2.from_base(1.0), 10_000.0);
+    assert_close(AreaUnit::Centimeters2.convert(250.0, AreaUnit::Meters2), 0.025);
+  }
+
+  #[test]
+  fn test_volume() {
+    assert_close(VolumeUnit::Millimeters3.from_base(0.001), 1_000_000.0);
+    assert_close(VolumeUnit::Centimeters3.convert(1_000_000.0, VolumeUnit::Meters3), 1.0);
+  }
+
+  #[test]
+  fn test_mass() {
+    assert_close(MassUnit::Tonnes.from_base(7850.0), 7.85);
+    assert_close(MassUnit::Tonnes.convert(1.5, MassUnit::Kilograms), 1500.0);
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    for unit in [LengthUnit::Millimeters, LengthUnit::Centimeters, LengthUnit::Meters] {
+      assert_close(unit.from_base(unit.to_base(123.456)), 123.456);
+    }
+    for unit in [VolumeUnit::Millimeters3, VolumeUnit::Centimeters3, VolumeUnit::Meters3] {
+      assert_close(unit.from_base(unit.to_base(0.75)), 0.75);
+    }
+  }
+
+  #[test]
+  fn test_defaults_are_base_units() {
+    assert_eq!(LengthUnit::default().factor(), 1.0);
+    assert_eq!(AreaUnit::default().factor(), 1.0);
+    assert_eq!(VolumeUnit::default().factor(), 1.0);
+    assert_eq!(MassUnit::default().factor(), 1.0);
+  }
+}
\ No newline at end of file
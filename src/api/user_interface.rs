@@ -0,0 +1,363 @@
+use std::{
+  cell::RefCell,
+  rc::Rc
+};
+use crate::{
+  native::{
+    constants,
+    Dispatch,
+    EventConnection,
+    Variant
+  },
+  ui::{
+    ActionSpec,
+    ContextMenuShowCase,
+    DropDown,
+    Icon,
+    Layout,
+    Menu,
+    MenuItem,
+    Panel,
+    PanelItem
+  },
+  Result,
+  Error
+};
+
+/// Event raised by an [Action].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionEvent {
+  /// Action was triggered by the user: button or menu item was clicked.
+  Triggered,
+
+  /// Checked state of a checkable action was changed.
+  Toggled(bool)
+}
+
+/// Renga user interface: factory of actions, panels and menus.
+///
+/// Can be obtained using [crate::Application::ui] method. See [crate::ui] for building
+/// a complete UI extension at once.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_u_i.html)
+#[derive(Debug, Clone)]
+pub struct UI {
+  handle: Dispatch
+}
+
+/// Command, which can be placed on panels and in context menus.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_action.html)
+#[derive(Debug, Clone)]
+pub struct Action {
+  /// Id of the action in [crate::ui::Layout].
+  pub id: String,
+  handle: Dispatch
+}
+
+/// Subscription of a callback to events of an [Action].
+///
+/// Callback is called while the connection is alive. Events are delivered on the thread
+/// which owns the [crate::Application], from its message loop.
+pub struct ActionConnection {
+  _connection: EventConnection
+}
+
+/// UI extension installed with [UI::install].
+///
+/// Keeps actions and their event connections alive. Renga has no API to remove added
+/// buttons and menus: after drop they stay visible, but events are not handled.
+pub struct UiExtension {
+  actions: Vec<Action>,
+  _connections: Vec<ActionConnection>
+}
+
+impl UI {
+  pub fn new(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IUI handle is null".to_owned()));
+    }
+    Ok(Self { handle })
+  }
+
+  /// Creates action with properties from `spec`.
+  pub fn create_action(&self, spec: &ActionSpec) -> Result<Action> {
+    let action = Action::new(&spec.id, self.handle.call("CreateAction", None)?.into_dispatch()?)?;
+    action.handle.set("DisplayName", spec.display_name.as_str().into())?;
+    if let Some(tooltip) = &spec.tooltip {
+      action.handle.set("ToolTip", tooltip.into())?;
+    }
+    if let Some(icon) = &spec.icon {
+      action.handle.set("Icon", self.create_image(icon)?.into())?;
+    }
+    if let Some(checked) = spec.checked {
+      action.handle.set("Checkable", true.into())?;
+      action.set_checked(checked)?;
+    }
+    action.set_enabled(spec.enabled)?;
+    Ok(action)
+  }
+
+  /// Validates `layout`, creates its actions, adds panels and context menus to the user
+  /// interface and connects all actions to `handler`.
+  ///
+  /// Handler receives id of the action and the event. Returned extension must be kept alive
+  /// for events to be handled.
+  pub fn install<F>(&self, layout: &Layout, handler: F) -> Result<UiExtension>
+  where F: FnMut(&str, ActionEvent) + 'static
+  {
+    layout.validate()?;
+    let handler = Rc::new(RefCell::new(handler));
+    let mut actions = Vec::new();
+    let mut connections = Vec::new();
+    for spec in &layout.actions {
+      let action = self.create_action(spec)?;
+      let id = spec.id.clone();
+      let handler = handler.clone();
+      connections.push(action.connect(move |event| match handler.try_borrow_mut() {
+        Ok(mut handler) => handler(&id, event),
+        Err(_) => log::warn!("ui: event {event:?} of action `{id}` raised from its own handler, ignored")
+      })?);
+      actions.push(action);
+    }
+    if !layout.primary_panel.is_empty() {
+      let extension = self.panel_extension(&layout.primary_panel, &actions)?;
+      self.handle.call("AddExtensionToPrimaryPanel", Some(vec![extension.into()]))?;
+    }
+    for (view_type, panel) in &layout.action_panels {
+      let extension = self.panel_extension(panel, &actions)?;
+      self.handle.call("AddExtensionToActionsPanel", Some(vec![extension.into(), view_type.to_native().into()]))?;
+    }
+    for context_menu in &layout.context_menus {
+      let menu = self.handle.call("CreateContextMenu", None)?.into_dispatch()?;
+      self.fill_menu(&menu, &context_menu.menu, &actions)?;
+      self.handle.call("AddContextMenuS", Some(vec![
+        context_menu.id.to_string().into(),
+        menu.into(),
+        context_menu.view_type.to_native().into(),
+        show_case_to_native(context_menu.show_case).into()
+      ]))?;
+    }
+    Ok(UiExtension {
+      actions,
+      _connections: connections
+    })
+  }
+
+  fn create_image(&self, icon: &Icon) -> Result<Dispatch> {
+    let image = self.handle.call("CreateImage", None)?.into_dispatch()?;
+    image.call("LoadFromData", Some(vec![
+      Variant::from_bytes(icon.as_bytes())?,
+      constants::IMAGE_FORMAT_PNG.into()
+    ]))?;
+    Ok(image)
+  }
+
+  fn panel_extension(&self, panel: &Panel, actions: &[Action]) -> Result<Dispatch> {
+    let extension = self.handle.call("CreateUIPanelExtension", None)?.into_dispatch()?;
+    for item in &panel.items {
+      match item {
+        PanelItem::Button(id) => {
+          extension.call("AddToolButton", Some(vec![find(actions, id)?.handle.clone().into()]))?;
+        },
+        PanelItem::DropDown(drop_down) => {
+          let button = self.drop_down_button(drop_down, actions)?;
+          extension.call("AddDropDownButton", Some(vec![button.into()]))?;
+        }
+      }
+    }
+    Ok(extension)
+  }
+
+  fn drop_down_button(&self, drop_down: &DropDown, actions: &[Action]) -> Result<Dispatch> {
+    let button = self.handle.call("CreateDropDownButton", None)?.into_dispatch()?;
+    // Drop-down buttons have no caption of their own, display name is shown as a tooltip.
+    let tooltip = drop_down.tooltip.as_ref().unwrap_or(&drop_down.display_name);
+    button.set("ToolTip", tooltip.into())?;
+    if let Some(icon) = &drop_down.icon {
+      button.set("Icon", self.create_image(icon)?.into())?;
+    }
+    for item in &drop_down.items {
+      match item {
+        Some(id) => button.call("AddAction", Some(vec![find(actions, id)?.handle.clone().into()]))?,
+        None => button.call("AddSeparator", None)?
+      };
+    }
+    Ok(button)
+  }
+
+  fn fill_menu(&self, target: &Dispatch, menu: &Menu, actions: &[Action]) -> Result<()> {
+    for item in &menu.items {
+      match item {
+        MenuItem::Action(id) => {
+          target.call("AddActionItem", Some(vec![find(actions, id)?.handle.clone().into()]))?;
+        },
+        MenuItem::Submenu { display_name, icon, menu } => {
+          let node = target.call("AddNodeItem", None)?.into_dispatch()?;
+          node.set("DisplayName", display_name.into())?;
+          if let Some(icon) = icon {
+            node.set("Icon", self.create_image(icon)?.into())?;
+          }
+          self.fill_menu(&node, menu, actions)?;
+        },
+        MenuItem::Separator => {
+          target.call("AddSeparator", None)?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+impl TryFrom<Dispatch> for UI {
+  type Error = Error;
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    Self::new(handle)
+  }
+}
+
+impl Action {
+  pub fn new(id: &str, handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IAction handle is null".to_owned()));
+    }
+    Ok(Self {
+      id: id.to_owned(),
+      handle
+    })
+  }
+
+  pub fn display_name(&self) -> Result<String> {
+    self.handle.get("DisplayName")?.into_string()
+  }
+
+  pub fn is_enabled(&self) -> Result<bool> {
+    self.handle.get("Enabled")?.as_bool()
+  }
+
+  pub fn set_enabled(&self, enabled: bool) -> Result<()> {
+    self.handle.set("Enabled", enabled.into())
+  }
+
+  pub fn is_checkable(&self) -> Result<bool> {
+    self.handle.get("Checkable")?.as_bool()
+  }
+
+  pub fn is_checked(&self) -> Result<bool> {
+    self.handle.get("Checked")?.as_bool()
+  }
+
+  pub fn set_checked(&self, checked: bool) -> Result<()> {
+    self.handle.set("Checked", checked.into())
+  }
+
+  /// Triggers the action, as if it was clicked by the user.
+  pub fn trigger(&self) -> Result<()> {
+    self.handle.call("Trigger", None)?;
+    Ok(())
+  }
+
+  /// Subscribes `callback` to `OnTriggered` and `OnToggled` events of the action.
+  ///
+  /// Renga raises action events through connection point of the action itself,
+  /// as `ActionEventHandler` of the C++ SDK does.
+  pub fn connect<F>(&self, mut callback: F) -> Result<ActionConnection>
+  where F: FnMut(ActionEvent) + 'static
+  {
+    let id = self.id.clone();
+    let connection = EventConnection::connect(&self.handle, Box::new(move |event, args| {
+      match (event, args.first().map(Variant::as_bool)) {
+        ("OnTriggered", _) => callback(ActionEvent::Triggered),
+        ("OnToggled", Some(Ok(checked))) => callback(ActionEvent::Toggled(checked)),
+        _ => log::warn!("ui: unexpected event {event} of action `{id}`, ignored")
+      }
+    }))?;
+    Ok(ActionConnection {
+      _connection: connection
+    })
+  }
+}
+
+impl UiExtension {
+  /// Returns installed action with given id.
+  pub fn action(&self, id: &str) -> Option<&Action> {
+    self
+      .actions
+      .iter()
+      .find(|action| action.id == id)
+  }
+
+  pub fn actions(&self) -> &[Action] {
+    &self.actions
+  }
+}
+
+fn find<'a>(actions: &'a [Action], id: &str) -> Result<&'a Action> {
+  actions
+    .iter()
+    .find(|action| action.id == id)
+    .ok_or_else(|| Error::InvalidOperation(format!("Unknown action `{id}`")))
+}
+
+fn show_case_to_native(show_case: ContextMenuShowCase) -> i32 {
+  match show_case {
+    ContextMenuShowCase::Scene => constants::CONTEXT_MENU_SHOW_CASE_SCENE,
+    ContextMenuShowCase::Selection => constants::CONTEXT_MENU_SHOW_CASE_SELECTION
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    cell::RefCell,
+    rc::Rc
+  };
+  use test_context::test_context;
+  use crate::*;
+  use crate::ui::*;
+
+  #[test_context(RengaContext)]
+  #[test]
+  fn test_install(ctx: &mut RengaContext) -> anyhow::Result<()> {
+    let layout = Layout::new()
+      .action(ActionSpec::new("export", "Export")
+        .with_tooltip("Export model")
+        .with_icon(Icon::from_file(&external_file("icon.png")?)?))
+      .action(ActionSpec::new("grid", "Show grid").with_checked(false))
+      .primary_panel(Panel::new()
+        .button("export")
+        .drop_down(DropDown::new("More").action("grid")))
+      .context_menu(ContextMenu::new(
+        "5C0F8A16-3A43-4E25-9C41-5E8B1A3C6F20".parse()?,
+        ViewType::View3D,
+        ContextMenuShowCase::Scene,
+        Menu::new().action("export").separator().submenu("View", None, Menu::new().action("grid"))
+      ));
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let recorded = events.clone();
+    let extension = ctx.app.ui()?.install(&layout, move |id, event| {
+      recorded.borrow_mut().push((id.to_owned(), event));
+    })?;
+
+    let export = extension.action("export").unwrap();
+    assert_eq!(export.display_name()?, "Export");
+    assert!(export.is_enabled()?);
+    assert!(extension.action("grid").unwrap().is_checkable()?);
+    assert!(extension.action("missing").is_none());
+
+    export.trigger()?;
+    assert!(events.borrow().contains(&("export".to_owned(), ActionEvent::Triggered)));
+
+    Ok(())
+  }
+
+  #[test_context(RengaContext)]
+  #[test]
+  fn test_install_invalid(ctx: &mut RengaContext) -> anyhow::Result<()> {
+    let layout = Layout::new().primary_panel(Panel::new().button("missing"));
+
+    assert!(ctx.app.ui()?.install(&layout, |_, _| {}).is_err());
+
+    Ok(())
+  }
+}
@@ -7,11 +7,38 @@ use guid::{
   guid_parts_impl
 };
 
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize, 
+  Serialize
+};
+
 #[derive(Debug, Clone)]
 pub struct UUID(GUID);
 
 impl From<GUID> for UUID { fn from(guid: GUID) -> Self { Self(guid) } }
 impl From<&GUID> for UUID { fn from(guid: &GUID) -> Self { Self(*guid) } }
+impl From<windows::core::GUID> for UUID { 
+  fn from(guid: windows::core::GUID) -> Self { 
+    Self(GUID { 
+      Data1: guid.data1, 
+      Data2: guid.data2, 
+      Data3: guid.data3, 
+      Data4: guid.data4 
+    }) 
+  } 
+}
+
+impl From<&UUID> for windows::core::GUID {
+  fn from(uuid: &UUID) -> Self {
+    Self {
+      data1: uuid.0.Data1,
+      data2: uuid.0.Data2,
+      data3: uuid.0.Data3,
+      data4: uuid.0.Data4
+    }
+  }
+}
 
 impl UUID {
   pub fn into_inner(self) -> GUID { self.0 }
@@ -27,6 +54,8 @@ impl PartialEq for UUID {
   }
 }
 
+impl Eq for UUID {}
+
 impl Default for UUID {
   fn default() -> Self { Self(guid!("00000000-0000-0000-0000-000000000000")) }
 }
@@ -63,10 +92,36 @@ impl FromStr for UUID {
   type Err = crate::Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let parsed = guid::parse(s);
+    let parsed = guid::parse(s
+      .trim()
+      .trim_start_matches('{')
+      .trim_end_matches('}')
+    );
     match parsed {
       Ok(guid) => Ok(Self(guid)),
       Err(_) => Err(crate::Error::ParseError(format!("Failed to parse UUID from string: {}", s)))
     }
   }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for UUID {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+      S: serde::Serializer {
+    serializer.serialize_str(&self.to_string())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for UUID {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+      D: serde::Deserializer<'de> {
+    let s = String::deserialize(deserializer)?;
+    s
+      .as_str()
+      .parse()
+      .map_err(serde::de::Error::custom)
+  }
 }
\ No newline at end of file
@@ -0,0 +1,299 @@
+use crate::{
+  geometry::{
+    Camera,
+    Point3D,
+    Vector3D
+  },
+  native::{
+    constants,
+    records,
+    Dispatch,
+    Variant
+  },
+  Result,
+  Error
+};
+use super::{
+  Color,
+  ModelObjectCollection
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Type of a view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum ViewType {
+  Level,
+  View3D,
+  Section,
+  Facade,
+  Drawing,
+  Assembly,
+  Table,
+  Unknown(i32)
+}
+
+impl ViewType {
+  /// Converts native `ViewType` value to enum.
+  pub fn from_native(value: i32) -> Self {
+    match value {
+      constants::VIEW_TYPE_LEVEL => Self::Level,
+      constants::VIEW_TYPE_VIEW_3D => Self::View3D,
+      constants::VIEW_TYPE_SECTION => Self::Section,
+      constants::VIEW_TYPE_FACADE => Self::Facade,
+      constants::VIEW_TYPE_DRAWING => Self::Drawing,
+      constants::VIEW_TYPE_ASSEMBLY => Self::Assembly,
+      constants::VIEW_TYPE_TABLE => Self::Table,
+      other => Self::Unknown(other)
+    }
+  }
+
+  /// Converts enum to native `ViewType` value.
+  pub fn to_native(&self) -> i32 {
+    match self {
+      Self::Level => constants::VIEW_TYPE_LEVEL,
+      Self::View3D => constants::VIEW_TYPE_VIEW_3D,
+      Self::Section => constants::VIEW_TYPE_SECTION,
+      Self::Facade => constants::VIEW_TYPE_FACADE,
+      Self::Drawing => constants::VIEW_TYPE_DRAWING,
+      Self::Assembly => constants::VIEW_TYPE_ASSEMBLY,
+      Self::Table => constants::VIEW_TYPE_TABLE,
+      Self::Unknown(value) => *value
+    }
+  }
+}
+
+/// Represents view (tab) of Renga user interface.
+///
+/// Can be obtained using [crate::Application::active_view] method.
+/// Visibility and color of objects can be changed only in model views (levels, 3D views,
+/// sections and facades), camera is available only in 3D views. Changes affect only
+/// presentation and do not require a transaction.
+///
+/// For example, to highlight objects in red and hide everything else:
+/// ```no_run
+/// use renga_api_rs as renga;
+///
+/// let mut app = renga::Application::new().unwrap();
+/// let view = app.active_view().unwrap();
+/// view.isolate(&[12, 34]).unwrap();
+/// view.set_color(&[12, 34], renga::Color::new(255, 0, 0)).unwrap();
+/// ```
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_view.html)
+#[derive(Debug, Clone)]
+pub struct View {
+  /// Type of the view.
+  pub view_type: ViewType,
+  handle: Dispatch,
+  objects: ModelObjectCollection
+}
+
+impl View {
+  /// Creates new instance of View from native handle.
+  ///
+  /// Objects of `objects` collection are used for isolation and reset.
+  pub fn new(handle: Dispatch, objects: ModelObjectCollection) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IView handle is null".to_owned()));
+    }
+    Ok(Self {
+      view_type: ViewType::from_native(handle.get("Type")?.as_int()?),
+      handle,
+      objects
+    })
+  }
+
+  /// Returns `true` if visibility and colors of objects can be changed in this view.
+  pub fn is_model_view(&self) -> Result<bool> {
+    Ok(self.interface("IModelView")?.is_some())
+  }
+
+  /// Returns `true` if object is visible in this view.
+  pub fn is_visible(&self, id: i32) -> Result<bool> {
+    self
+      .model_view()?
+      .call("IsObjectVisible", Some(vec![id.into()]))?
+      .as_bool()
+  }
+
+  /// Shows or hides objects with given identifiers.
+  pub fn set_visible(&self, ids: &[i32], visible: bool) -> Result<()> {
+    self
+      .model_view()?
+      .call("SetObjectsVisibility", Some(vec![Variant::from_ints(ids)?, visible.into()]))?;
+    Ok(())
+  }
+
+  pub fn show(&self, ids: &[i32]) -> Result<()> {
+    self.set_visible(ids, true)
+  }
+
+  pub fn hide(&self, ids: &[i32]) -> Result<()> {
+    self.set_visible(ids, false)
+  }
+
+  /// Shows objects with given identifiers and hides all other objects of the model.
+  pub fn isolate(&self, ids: &[i32]) -> Result<()> {
+    let others: Vec<i32> = self
+      .object_ids()?
+      .into_iter()
+      .filter(|id| !ids.contains(id))
+      .collect();
+    self.hide(&others)?;
+    self.show(ids)
+  }
+
+  /// Overrides color of objects with given identifiers.
+  pub fn set_color(&self, ids: &[i32], color: Color) -> Result<()> {
+    self
+      .model_view()?
+      .call("SetObjectsColor", Some(vec![Variant::from_ints(ids)?, color.to_ole().into()]))?;
+    Ok(())
+  }
+
+  /// Removes color overrides of objects with given identifiers.
+  pub fn reset_color(&self, ids: &[i32]) -> Result<()> {
+    self
+      .model_view()?
+      .call("ResetObjectsColor", Some(vec![Variant::from_ints(ids)?]))?;
+    Ok(())
+  }
+
+  /// Shows all objects of the model and removes all color overrides.
+  pub fn reset(&self) -> Result<()> {
+    let ids = self.object_ids()?;
+    self.show(&ids)?;
+    self.reset_color(&ids)
+  }
+
+  /// Returns camera of 3D view.
+  pub fn camera(&self) -> Result<Camera> {
+    let camera = self.camera_handle()?;
+    let (position, focus, up) = unsafe {(
+      records::FloatPoint3D::from_variant(&camera.get("Position")?)?,
+      records::FloatPoint3D::from_variant(&camera.get("FocusPoint")?)?,
+      records::FloatVector3D::from_variant(&camera.get("UpVector")?)?
+    )};
+    let point = |p: records::FloatPoint3D| Point3D::new(p.x as f64, p.y as f64, p.z as f64);
+    Camera::look_at(
+      point(position),
+      point(focus),
+      Vector3D::new(up.x as f64, up.y as f64, up.z as f64),
+      camera.get("FovHorizontal")?.as_f64()?
+    ).ok_or_else(|| Error::Internal("Degenerate camera of 3D view".to_owned()))
+  }
+
+  /// Moves camera of 3D view.
+  ///
+  /// Focus point is placed at unit distance along camera direction.
+  pub fn set_camera(&self, camera: &Camera) -> Result<()> {
+    let handle = self.camera_handle()?;
+    let focus = camera.target(1.0);
+    handle.call("LookAt", Some(vec![
+      camera.position.x.into(), camera.position.y.into(), camera.position.z.into(),
+      focus.x.into(), focus.y.into(), focus.z.into(),
+      camera.up.x.into(), camera.up.y.into(), camera.up.z.into()
+    ]))?;
+    handle.set("FovHorizontal", camera.field_of_view.into())?;
+    Ok(())
+  }
+
+  fn object_ids(&self) -> Result<Vec<i32>> {
+    Ok(self
+      .objects
+      .clone()
+      .into_vec()?
+      .into_iter()
+      .map(|object| object.id)
+      .collect())
+  }
+
+  fn model_view(&self) -> Result<Dispatch> {
+    self
+      .interface("IModelView")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{:?} view is not a model view", self.view_type)))
+  }
+
+  fn camera_handle(&self) -> Result<Dispatch> {
+    let params = self
+      .interface("IView3DParams")?
+      .ok_or_else(|| Error::InvalidOperation(format!("{:?} view is not a 3D view", self.view_type)))?;
+    params.get("Camera")?.into_dispatch()
+  }
+
+  fn interface(&self, name: &str) -> Result<Option<Dispatch>> {
+    self.handle.query_interface(name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+  use crate::geometry::{
+    ApproxEq,
+    Camera
+  };
+
+  #[test]
+  fn test_view_type() {
+    assert_eq!(ViewType::from_native(1), ViewType::Level);
+    assert_eq!(ViewType::from_native(2), ViewType::View3D);
+    assert_eq!(ViewType::from_native(0), ViewType::Unknown(0));
+    assert_eq!(ViewType::from_native(42), ViewType::Unknown(42));
+    assert_eq!(ViewType::from_native(ViewType::Table.to_native()), ViewType::Table);
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_visibility_and_colors(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let levels = ctx.project.model()?.levels()?;
+    let view = ctx.app.active_view()?;
+    if !view.is_model_view()? {
+      assert!(view.hide(&[]).is_err());
+      return Ok(());
+    }
+
+    if let Some(level) = levels.first() {
+      view.hide(&[level.id])?;
+      assert!(!view.is_visible(level.id)?);
+
+      view.isolate(&[level.id])?;
+      assert!(view.is_visible(level.id)?);
+
+      view.set_color(&[level.id], Color::new(255, 0, 0))?;
+      view.reset()?;
+      assert!(view.is_visible(level.id)?);
+    }
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_camera(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let view = ctx.app.active_view()?;
+    if view.view_type != ViewType::View3D {
+      assert!(view.camera().is_err());
+      return Ok(());
+    }
+
+    let camera = Camera::look_at(
+      Point3D::new(10000.0, -10000.0, 5000.0),
+      Point3D::default(),
+      Vector3D::Z,
+      view.camera()?.field_of_view
+    ).unwrap();
+    view.set_camera(&camera)?;
+
+    assert!(view.camera()?.approx_eq_eps(&camera, 1e-3));
+
+    Ok(())
+  }
+}
@@ -29,10 +29,26 @@ pub enum Error {
   #[error("No active transaction. Start new transaction first.")]
   NoActiveTransaction,
 
+  /// Opened project has unsaved changes which would be lost.
+  #[error("Opened project has unsaved changes")]
+  UnsavedChanges,
+
+  /// Value type does not match the expected one.
+  #[error("Type mismatch: {0}")]
+  TypeMismatch(String),
+
   /// Error parsing value from string.
   #[error("Error parsing value from string: {0}")]
   ParseError(String),
 
+  /// Renga reported failure of a file operation (export, save, import).
+  #[error("Operation failed: {0}")]
+  Operation(#[from] OperationError),
+
+  /// Input/output error.
+  #[error("I/O error: {0}")]
+  Io(#[from] std::io::Error),
+
   /// WinAPI error.
   /// 
   /// Can occur in cases where WinAPI functions fail. 
@@ -40,5 +56,70 @@ pub enum Error {
   WinApi(#[from] windows::core::Error),
 }
 
+/// Failure of a Renga file operation, decoded from native integer result code.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationError {
+  #[error("generic failure")]
+  Failed,
+
+  #[error("target file already exists")]
+  FileAlreadyExists,
+
+  #[error("access to target path denied")]
+  AccessDenied,
+
+  #[error("invalid target path")]
+  InvalidPath,
+
+  #[error("nothing to export")]
+  NothingToExport,
+
+  #[error("operation cancelled by user")]
+  Cancelled,
+
+  #[error("unknown result code {0}")]
+  Unknown(i32)
+}
+
+impl OperationError {
+  /// Converts native result code to error. Returns `None` for successful result.
+  pub fn from_code(code: i32) -> Option<Self> {
+    use crate::native::constants::*;
+    match code {
+      RESULT_OK => None,
+      RESULT_FAILED => Some(Self::Failed),
+      RESULT_FILE_ALREADY_EXISTS => Some(Self::FileAlreadyExists),
+      RESULT_ACCESS_DENIED => Some(Self::AccessDenied),
+      RESULT_INVALID_PATH => Some(Self::InvalidPath),
+      RESULT_NOTHING_TO_EXPORT => Some(Self::NothingToExport),
+      RESULT_CANCELLED => Some(Self::Cancelled),
+      other => Some(Self::Unknown(other))
+    }
+  }
+
+  /// Returns `Ok` for successful native result code and [Error::Operation] otherwise.
+  pub fn check(code: i32) -> Result<()> {
+    match Self::from_code(code) {
+      None => Ok(()),
+      Some(error) => Err(error.into())
+    }
+  }
+}
+
 /// Result type for Renga API.
-pub type Result<T> = std::result::Result<T, Error>;
\ No newline at end of file
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+  use crate::*;
+
+  #[test]
+  fn test_result_codes() {
+    assert!(OperationError::check(0).is_ok());
+    assert!(matches!(
+      OperationError::check(2),
+      Err(Error::Operation(OperationError::FileAlreadyExists))
+    ));
+    assert_eq!(OperationError::from_code(100), Some(OperationError::Unknown(100)));
+  }
+}
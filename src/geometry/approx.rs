@@ -0,0 +1,52 @@
+/// Default absolute tolerance used by [ApproxEq::approx_eq].
+pub const EPSILON: f64 = 1e-9;
+
+/// Approximate equality of floating point values and geometry types.
+pub trait ApproxEq {
+  /// Returns `true` if all components differ by no more than `epsilon`.
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool;
+
+  /// Returns `true` if all components differ by no more than [EPSILON].
+  fn approx_eq(&self, other: &Self) -> bool {
+    self.approx_eq_eps(other, EPSILON)
+  }
+}
+
+impl ApproxEq for f64 {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    (self - other).abs() <= epsilon
+  }
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.len() == other.len() && self
+      .iter()
+      .zip(other.iter())
+      .all(|(a, b)| a.approx_eq_eps(b, epsilon))
+  }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.as_slice().approx_eq_eps(other.as_slice(), epsilon)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::geometry::*;
+
+  #[test]
+  fn test_f64() {
+    assert!((0.1 + 0.2).approx_eq(&0.3));
+    assert!(!1.0.approx_eq(&1.001));
+    assert!(1.0.approx_eq_eps(&1.001, 1e-2));
+  }
+
+  #[test]
+  fn test_slices() {
+    assert!(vec![1.0, 2.0].approx_eq(&vec![1.0, 2.0 + 1e-12]));
+    assert!(!vec![1.0, 2.0].approx_eq(&vec![1.0]));
+  }
+}
@@ -0,0 +1,210 @@
+use super::{
+  ApproxEq,
+  Matrix3D,
+  Point2D,
+  Point3D,
+  Vector2D,
+  Vector3D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Axis-aligned bounding box in 2D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct BoundingBox2D {
+  pub min: Point2D,
+  pub max: Point2D
+}
+
+/// Axis-aligned bounding box in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct BoundingBox3D {
+  pub min: Point3D,
+  pub max: Point3D
+}
+
+impl BoundingBox2D {
+  /// Creates bounding box from two opposite corners in any order.
+  pub fn new(a: Point2D, b: Point2D) -> Self {
+    Self {
+      min: Point2D::new(a.x.min(b.x), a.y.min(b.y)),
+      max: Point2D::new(a.x.max(b.x), a.y.max(b.y))
+    }
+  }
+
+  /// Returns smallest box containing all points or `None` if there are no points.
+  pub fn from_points(points: &[Point2D]) -> Option<Self> {
+    let (first, rest) = points.split_first()?;
+    Some(rest.iter().fold(Self::new(*first, *first), |bbox, point| bbox.extended(point)))
+  }
+
+  /// Returns copy of this box extended to contain given point.
+  pub fn extended(&self, point: &Point2D) -> Self {
+    Self {
+      min: Point2D::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+      max: Point2D::new(self.max.x.max(point.x), self.max.y.max(point.y))
+    }
+  }
+
+  /// Returns smallest box containing both boxes.
+  pub fn union(&self, other: &Self) -> Self {
+    self.extended(&other.min).extended(&other.max)
+  }
+
+  pub fn size(&self) -> Vector2D {
+    self.max - self.min
+  }
+
+  pub fn center(&self) -> Point2D {
+    self.min.midpoint(&self.max)
+  }
+
+  /// Returns `true` if point is inside or on the boundary.
+  pub fn contains(&self, point: &Point2D) -> bool {
+    (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+  }
+
+  /// Returns `true` if boxes overlap or touch.
+  pub fn intersects(&self, other: &Self) -> bool {
+    self.min.x <= other.max.x && other.min.x <= self.max.x
+      && self.min.y <= other.max.y && other.min.y <= self.max.y
+  }
+}
+
+impl BoundingBox3D {
+  /// Creates bounding box from two opposite corners in any order.
+  pub fn new(a: Point3D, b: Point3D) -> Self {
+    Self {
+      min: Point3D::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+      max: Point3D::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+    }
+  }
+
+  /// Returns smallest box containing all points or `None` if there are no points.
+  pub fn from_points(points: &[Point3D]) -> Option<Self> {
+    let (first, rest) = points.split_first()?;
+    Some(rest.iter().fold(Self::new(*first, *first), |bbox, point| bbox.extended(point)))
+  }
+
+  /// Returns copy of this box extended to contain given point.
+  pub fn extended(&self, point: &Point3D) -> Self {
+    Self {
+      min: Point3D::new(self.min.x.min(point.x), self.min.y.min(point.y), self.min.z.min(point.z)),
+      max: Point3D::new(self.max.x.max(point.x), self.max.y.max(point.y), self.max.z.max(point.z))
+    }
+  }
+
+  /// Returns smallest box containing both boxes.
+  pub fn union(&self, other: &Self) -> Self {
+    self.extended(&other.min).extended(&other.max)
+  }
+
+  pub fn size(&self) -> Vector3D {
+    self.max - self.min
+  }
+
+  pub fn center(&self) -> Point3D {
+    self.min.midpoint(&self.max)
+  }
+
+  /// Returns all eight corners of the box.
+  pub fn corners(&self) -> [Point3D; 8] {
+    let (a, b) = (self.min, self.max);
+    [
+      Point3D::new(a.x, a.y, a.z), Point3D::new(b.x, a.y, a.z),
+      Point3D::new(a.x, b.y, a.z), Point3D::new(b.x, b.y, a.z),
+      Point3D::new(a.x, a.y, b.z), Point3D::new(b.x, a.y, b.z),
+      Point3D::new(a.x, b.y, b.z), Point3D::new(b.x, b.y, b.z)
+    ]
+  }
+
+  /// Returns `true` if point is inside or on the boundary.
+  pub fn contains(&self, point: &Point3D) -> bool {
+    (self.min.x..=self.max.x).contains(&point.x)
+      && (self.min.y..=self.max.y).contains(&point.y)
+      && (self.min.z..=self.max.z).contains(&point.z)
+  }
+
+  /// Returns `true` if boxes overlap or touch.
+  pub fn intersects(&self, other: &Self) -> bool {
+    self.min.x <= other.max.x && other.min.x <= self.max.x
+      && self.min.y <= other.max.y && other.min.y <= self.max.y
+      && self.min.z <= other.max.z && other.min.z <= self.max.z
+  }
+
+  /// Returns bounding box of this box after transformation.
+  pub fn transform(&self, matrix: &Matrix3D) -> Self {
+    let corners = self.corners().map(|corner| matrix.transform_point(&corner));
+    Self::from_points(&corners).unwrap_or(*self)
+  }
+}
+
+impl ApproxEq for BoundingBox2D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.min.approx_eq_eps(&other.min, epsilon) && self.max.approx_eq_eps(&other.max, epsilon)
+  }
+}
+
+impl ApproxEq for BoundingBox3D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.min.approx_eq_eps(&other.min, epsilon) && self.max.approx_eq_eps(&other.max, epsilon)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::FRAC_PI_2;
+  use crate::geometry::*;
+
+  #[test]
+  fn test_from_points() {
+    let bbox = BoundingBox3D::from_points(&[
+      Point3D::new(1.0, 5.0, -2.0),
+      Point3D::new(-3.0, 2.0, 4.0),
+      Point3D::new(0.0, 7.0, 0.0)
+    ]).unwrap();
+
+    assert_eq!(bbox.min, Point3D::new(-3.0, 2.0, -2.0));
+    assert_eq!(bbox.max, Point3D::new(1.0, 7.0, 4.0));
+    assert_eq!(bbox.size(), Vector3D::new(4.0, 5.0, 6.0));
+    assert_eq!(bbox.center(), Point3D::new(-1.0, 4.5, 1.0));
+    assert!(BoundingBox3D::from_points(&[]).is_none());
+    assert!(BoundingBox2D::from_points(&[]).is_none());
+  }
+
+  #[test]
+  fn test_contains_and_intersects() {
+    let a = BoundingBox2D::new(Point2D::new(10.0, 10.0), Point2D::new(0.0, 0.0));
+    let b = BoundingBox2D::new(Point2D::new(10.0, 5.0), Point2D::new(20.0, 20.0));
+    let c = BoundingBox2D::new(Point2D::new(11.0, 0.0), Point2D::new(20.0, 4.0));
+
+    assert!(a.contains(&Point2D::new(10.0, 0.0)));
+    assert!(!a.contains(&Point2D::new(10.1, 0.0)));
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&c));
+    assert_eq!(a.union(&c), BoundingBox2D::new(Point2D::new(0.0, 0.0), Point2D::new(20.0, 10.0)));
+  }
+
+  #[test]
+  fn test_3d_intersects() {
+    let a = BoundingBox3D::new(Point3D::default(), Point3D::new(1.0, 1.0, 1.0));
+    let b = BoundingBox3D::new(Point3D::new(0.5, 0.5, 2.0), Point3D::new(2.0, 2.0, 3.0));
+
+    assert!(!a.intersects(&b));
+    assert!(a.union(&b).contains(&Point3D::new(1.5, 0.2, 1.5)));
+  }
+
+  #[test]
+  fn test_transform() {
+    let bbox = BoundingBox3D::new(Point3D::default(), Point3D::new(2.0, 1.0, 1.0));
+    let rotated = bbox.transform(&Matrix3D::rotation(Vector3D::Z, FRAC_PI_2));
+
+    assert!(rotated.approx_eq(&BoundingBox3D::new(Point3D::new(-1.0, 0.0, 0.0), Point3D::new(0.0, 2.0, 1.0))));
+  }
+}
@@ -0,0 +1,112 @@
+use super::{
+  ApproxEq,
+  BoundingBox3D,
+  Point3D,
+  Vector3D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Perspective camera of a 3D view.
+///
+/// `direction` and `up` are unit vectors, orthogonal to each other.
+/// `field_of_view` is the horizontal view angle in radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Camera {
+  pub position: Point3D,
+  pub direction: Vector3D,
+  pub up: Vector3D,
+  pub field_of_view: f64
+}
+
+impl Camera {
+  /// Creates camera at `position`, looking at `target`.
+  ///
+  /// `up` is made orthogonal to view direction. Returns `None` if `target` coincides with
+  /// `position` or `up` is parallel to view direction.
+  pub fn look_at(position: Point3D, target: Point3D, up: Vector3D, field_of_view: f64) -> Option<Self> {
+    let direction = (target - position).normalized()?;
+    let up = (up - direction * up.dot(&direction)).normalized()?;
+    Some(Self {
+      position,
+      direction,
+      up,
+      field_of_view
+    })
+  }
+
+  /// Returns unit vector pointing to the right of the view.
+  pub fn right(&self) -> Vector3D {
+    self.direction.cross(&self.up)
+  }
+
+  /// Returns point at `distance` along view direction.
+  pub fn target(&self, distance: f64) -> Point3D {
+    self.position + self.direction * distance
+  }
+
+  /// Returns camera with the same orientation, moved so that whole `bbox` is in view.
+  pub fn fit(&self, bbox: &BoundingBox3D) -> Self {
+    let radius = (bbox.max - bbox.min).length() / 2.0;
+    let half_angle = (self.field_of_view / 2.0).clamp(f64::EPSILON, std::f64::consts::FRAC_PI_2);
+    let distance = radius / half_angle.sin();
+    Self {
+      position: bbox.center() - self.direction * distance,
+      ..*self
+    }
+  }
+}
+
+impl ApproxEq for Camera {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.position.approx_eq_eps(&other.position, epsilon)
+      && self.direction.approx_eq_eps(&other.direction, epsilon)
+      && self.up.approx_eq_eps(&other.up, epsilon)
+      && self.field_of_view.approx_eq_eps(&other.field_of_view, epsilon)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::FRAC_PI_2;
+  use crate::geometry::*;
+
+  #[test]
+  fn test_look_at() {
+    let camera = Camera::look_at(
+      Point3D::new(0.0, -10.0, 0.0),
+      Point3D::new(0.0, 0.0, 0.0),
+      Vector3D::new(0.0, 1.0, 1.0),
+      FRAC_PI_2
+    ).unwrap();
+
+    assert!(camera.direction.approx_eq(&Vector3D::Y));
+    assert!(camera.up.approx_eq(&Vector3D::Z));
+    assert!(camera.right().approx_eq(&Vector3D::X));
+    assert!(camera.target(10.0).approx_eq(&Point3D::new(0.0, 0.0, 0.0)));
+    assert!(Camera::look_at(Point3D::default(), Point3D::default(), Vector3D::Z, FRAC_PI_2).is_none());
+    assert!(Camera::look_at(Point3D::default(), Point3D::new(0.0, 0.0, 1.0), Vector3D::Z, FRAC_PI_2).is_none());
+  }
+
+  #[test]
+  fn test_fit() {
+    let camera = Camera::look_at(
+      Point3D::new(0.0, 0.0, 0.0),
+      Point3D::new(1.0, 0.0, 0.0),
+      Vector3D::Z,
+      FRAC_PI_2
+    ).unwrap();
+    let bbox = BoundingBox3D::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0));
+    let fitted = camera.fit(&bbox);
+    let distance = 3f64.sqrt() / (FRAC_PI_2 / 2.0).sin();
+
+    assert!(fitted.position.approx_eq(&Point3D::new(-distance, 0.0, 0.0)));
+    assert!(fitted.direction.approx_eq(&camera.direction));
+    assert_eq!(fitted.field_of_view, camera.field_of_view);
+  }
+}
@@ -0,0 +1,414 @@
+use std::f64::consts::{
+  FRAC_PI_2,
+  TAU
+};
+use super::{
+  ApproxEq,
+  BoundingBox2D,
+  Matrix2D,
+  Point2D,
+  Point3D,
+  Vector2D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Straight line segment in 2D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct LineSegment2D {
+  pub start: Point2D,
+  pub end: Point2D
+}
+
+/// Straight line segment in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct LineSegment3D {
+  pub start: Point3D,
+  pub end: Point3D
+}
+
+/// Circular arc in 2D space, going from `start_angle` to `end_angle`.
+///
+/// Angles are in radians, measured from X axis. Arc goes counter-clockwise if `end_angle` is
+/// greater than `start_angle` and clockwise otherwise; full circle has `|end_angle - start_angle| == 2π`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Arc2D {
+  pub center: Point2D,
+  pub radius: f64,
+  pub start_angle: f64,
+  pub end_angle: f64
+}
+
+/// Chain of straight segments in 2D space.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Polyline2D {
+  pub points: Vec<Point2D>,
+
+  /// If `true`, last point is connected to the first one.
+  pub closed: bool
+}
+
+/// Any supported curve in 2D space.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum Curve2D {
+  Line(LineSegment2D),
+  Arc(Arc2D),
+  Polyline(Polyline2D)
+}
+
+impl LineSegment2D {
+  pub fn new(start: Point2D, end: Point2D) -> Self {
+    Self { start, end }
+  }
+
+  pub fn length(&self) -> f64 {
+    self.start.distance(&self.end)
+  }
+
+  pub fn direction(&self) -> Vector2D {
+    self.end - self.start
+  }
+
+  /// Returns point at parameter `t` in range `[0, 1]`.
+  pub fn point_at(&self, t: f64) -> Point2D {
+    self.start + self.direction() * t
+  }
+
+  /// Returns distance from point to this segment.
+  pub fn distance_to(&self, point: &Point2D) -> f64 {
+    let direction = self.direction();
+    let length2 = direction.dot(&direction);
+    if length2 == 0.0 {
+      return self.start.distance(point);
+    }
+    let t = ((*point - self.start).dot(&direction) / length2).clamp(0.0, 1.0);
+    self.point_at(t).distance(point)
+  }
+}
+
+impl LineSegment3D {
+  pub fn new(start: Point3D, end: Point3D) -> Self {
+    Self { start, end }
+  }
+
+  pub fn length(&self) -> f64 {
+    self.start.distance(&self.end)
+  }
+
+  /// Returns point at parameter `t` in range `[0, 1]`.
+  pub fn point_at(&self, t: f64) -> Point3D {
+    self.start + (self.end - self.start) * t
+  }
+}
+
+impl Arc2D {
+  pub fn new(center: Point2D, radius: f64, start_angle: f64, end_angle: f64) -> Self {
+    Self { center, radius, start_angle, end_angle }
+  }
+
+  /// Returns full circle.
+  pub fn circle(center: Point2D, radius: f64) -> Self {
+    Self::new(center, radius, 0.0, TAU)
+  }
+
+  /// Returns arc with given center, going from `start` to `end` through `through` point.
+  ///
+  /// Radius is the distance from center to `start`. Coinciding `start` and `end` give
+  /// a full counter-clockwise circle.
+  pub fn from_points(center: Point2D, start: Point2D, through: Point2D, end: Point2D) -> Self {
+    let start_angle = (start - center).angle();
+    let ccw_offset = |point: Point2D| ((point - center).angle() - start_angle).rem_euclid(TAU);
+    let mut sweep = ccw_offset(end);
+    if sweep < 1e-12 {
+      sweep = TAU;
+    }
+    if ccw_offset(through) > sweep {
+      sweep -= TAU;
+    }
+    Self::new(center, start.distance(&center), start_angle, start_angle + sweep)
+  }
+
+  /// Returns swept angle in radians, negative for clockwise arcs.
+  pub fn sweep(&self) -> f64 {
+    self.end_angle - self.start_angle
+  }
+
+  pub fn length(&self) -> f64 {
+    self.radius * self.sweep().abs()
+  }
+
+  pub fn point_at_angle(&self, angle: f64) -> Point2D {
+    self.center + Vector2D::from_angle(angle) * self.radius
+  }
+
+  pub fn start(&self) -> Point2D {
+    self.point_at_angle(self.start_angle)
+  }
+
+  pub fn end(&self) -> Point2D {
+    self.point_at_angle(self.end_angle)
+  }
+
+  /// Returns `true` if arc is a full circle.
+  pub fn is_closed(&self) -> bool {
+    self.sweep().abs() >= TAU - 1e-12
+  }
+
+  /// Returns bounding box of the arc, including extreme points on the circle.
+  pub fn bounding_box(&self) -> BoundingBox2D {
+    let mut points = vec![self.start(), self.end()];
+    let (from, to) = (self.start_angle.min(self.end_angle), self.start_angle.max(self.end_angle));
+    let mut angle = (from / FRAC_PI_2).ceil() * FRAC_PI_2;
+    while angle <= to {
+      points.push(self.point_at_angle(angle));
+      angle += FRAC_PI_2;
+    }
+    BoundingBox2D::from_points(&points).unwrap_or_default()
+  }
+
+  /// Approximates arc with `segments` straight segments.
+  pub fn tessellate(&self, segments: usize) -> Vec<Point2D> {
+    let segments = segments.max(1);
+    (0..=segments)
+      .map(|i| self.point_at_angle(self.start_angle + self.sweep() * i as f64 / segments as f64))
+      .collect()
+  }
+}
+
+impl Polyline2D {
+  pub fn new(points: Vec<Point2D>, closed: bool) -> Self {
+    Self { points, closed }
+  }
+
+  /// Returns segments of the polyline, including closing segment for closed polylines.
+  pub fn segments(&self) -> Vec<LineSegment2D> {
+    let mut segments: Vec<_> = self
+      .points
+      .windows(2)
+      .map(|pair| LineSegment2D::new(pair[0], pair[1]))
+      .collect();
+    if let (true, Some(first), Some(last)) = (self.closed, self.points.first(), self.points.last()) {
+      if self.points.len() > 2 && !first.approx_eq(last) {
+        segments.push(LineSegment2D::new(*last, *first));
+      }
+    }
+    segments
+  }
+
+  pub fn length(&self) -> f64 {
+    self.segments().iter().map(|segment| segment.length()).sum()
+  }
+
+  /// Returns signed area enclosed by the polyline: positive for counter-clockwise order.
+  pub fn signed_area(&self) -> f64 {
+    let n = self.points.len();
+    if n < 3 {
+      return 0.0;
+    }
+    (0..n)
+      .map(|i| {
+        let (a, b) = (self.points[i], self.points[(i + 1) % n]);
+        a.x * b.y - b.x * a.y
+      })
+      .sum::<f64>() / 2.0
+  }
+
+  pub fn bounding_box(&self) -> Option<BoundingBox2D> {
+    BoundingBox2D::from_points(&self.points)
+  }
+
+  /// Returns transformed copy of the polyline.
+  pub fn transform(&self, matrix: &Matrix2D) -> Self {
+    Self::new(self.points.iter().map(|point| matrix.transform_point(point)).collect(), self.closed)
+  }
+}
+
+impl Curve2D {
+  pub fn start(&self) -> Option<Point2D> {
+    match self {
+      Self::Line(line) => Some(line.start),
+      Self::Arc(arc) => Some(arc.start()),
+      Self::Polyline(polyline) => polyline.points.first().copied()
+    }
+  }
+
+  pub fn end(&self) -> Option<Point2D> {
+    match self {
+      Self::Line(line) => Some(line.end),
+      Self::Arc(arc) => Some(arc.end()),
+      Self::Polyline(polyline) if polyline.closed => polyline.points.first().copied(),
+      Self::Polyline(polyline) => polyline.points.last().copied()
+    }
+  }
+
+  pub fn length(&self) -> f64 {
+    match self {
+      Self::Line(line) => line.length(),
+      Self::Arc(arc) => arc.length(),
+      Self::Polyline(polyline) => polyline.length()
+    }
+  }
+
+  pub fn bounding_box(&self) -> Option<BoundingBox2D> {
+    match self {
+      Self::Line(line) => BoundingBox2D::from_points(&[line.start, line.end]),
+      Self::Arc(arc) => Some(arc.bounding_box()),
+      Self::Polyline(polyline) => polyline.bounding_box()
+    }
+  }
+
+  /// Approximates curve with points. Arcs are split into `arc_segments` segments.
+  pub fn tessellate(&self, arc_segments: usize) -> Vec<Point2D> {
+    match self {
+      Self::Line(line) => vec![line.start, line.end],
+      Self::Arc(arc) => arc.tessellate(arc_segments),
+      Self::Polyline(polyline) => {
+        let mut points = polyline.points.clone();
+        if let (true, Some(first)) = (polyline.closed, polyline.points.first()) {
+          points.push(*first);
+        }
+        points
+      }
+    }
+  }
+}
+
+impl From<LineSegment2D> for Curve2D {
+  fn from(line: LineSegment2D) -> Self { Self::Line(line) }
+}
+
+impl From<Arc2D> for Curve2D {
+  fn from(arc: Arc2D) -> Self { Self::Arc(arc) }
+}
+
+impl From<Polyline2D> for Curve2D {
+  fn from(polyline: Polyline2D) -> Self { Self::Polyline(polyline) }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::{
+    FRAC_PI_2,
+    PI,
+    TAU
+  };
+  use crate::geometry::*;
+
+  fn square() -> Polyline2D {
+    Polyline2D::new(vec![
+      Point2D::new(0.0, 0.0),
+      Point2D::new(10.0, 0.0),
+      Point2D::new(10.0, 10.0),
+      Point2D::new(0.0, 10.0)
+    ], true)
+  }
+
+  #[test]
+  fn test_line() {
+    let line = LineSegment2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0));
+
+    assert_eq!(line.length(), 10.0);
+    assert_eq!(line.point_at(0.25), Point2D::new(2.5, 0.0));
+    assert_eq!(line.distance_to(&Point2D::new(5.0, 3.0)), 3.0);
+    assert_eq!(line.distance_to(&Point2D::new(13.0, 4.0)), 5.0);
+
+    let line = LineSegment3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(0.0, 3.0, 4.0));
+    assert_eq!(line.length(), 5.0);
+    assert_eq!(line.point_at(1.0), line.end);
+  }
+
+  #[test]
+  fn test_arc() {
+    let arc = Arc2D::new(Point2D::new(0.0, 0.0), 10.0, 0.0, FRAC_PI_2);
+
+    assert!(arc.length().approx_eq(&(5.0 * PI)));
+    assert!(arc.start().approx_eq(&Point2D::new(10.0, 0.0)));
+    assert!(arc.end().approx_eq(&Point2D::new(0.0, 10.0)));
+    assert!(!arc.is_closed());
+    assert!(Arc2D::circle(Point2D::default(), 1.0).is_closed());
+
+    let points = arc.tessellate(4);
+    assert_eq!(points.len(), 5);
+    assert!(points.iter().all(|point| point.distance(&arc.center).approx_eq(&10.0)));
+  }
+
+  #[test]
+  fn test_arc_from_points() {
+    let (center, start, end) = (Point2D::default(), Point2D::new(10.0, 0.0), Point2D::new(-10.0, 0.0));
+    let counter_clockwise = Arc2D::from_points(center, start, Point2D::new(0.0, 10.0), end);
+    let clockwise = Arc2D::from_points(center, start, Point2D::new(0.0, -10.0), end);
+
+    assert!(counter_clockwise.sweep().approx_eq(&PI));
+    assert!(clockwise.sweep().approx_eq(&-PI));
+    assert!(clockwise.end().approx_eq(&end));
+    assert!(clockwise.length().approx_eq(&(10.0 * PI)));
+    assert!(clockwise.tessellate(2)[1].approx_eq(&Point2D::new(0.0, -10.0)));
+    assert!(clockwise.bounding_box().min.approx_eq(&Point2D::new(-10.0, -10.0)));
+    assert!(clockwise.bounding_box().max.approx_eq(&Point2D::new(10.0, 0.0)));
+
+    let quarter = Arc2D::from_points(center, start, Point2D::new(10.0 / 2.0f64.sqrt(), -10.0 / 2.0f64.sqrt()), Point2D::new(0.0, -10.0));
+    assert!(quarter.sweep().approx_eq(&-FRAC_PI_2));
+    assert!(Arc2D::from_points(center, start, Point2D::new(-10.0, 0.0), start).is_closed());
+  }
+
+  #[test]
+  fn test_arc_bounding_box() {
+    let arc = Arc2D::new(Point2D::new(0.0, 0.0), 10.0, -FRAC_PI_2 / 2.0, PI);
+    let bbox = arc.bounding_box();
+
+    assert!(bbox.min.approx_eq(&Point2D::new(-10.0, -10.0 * (FRAC_PI_2 / 2.0).sin())));
+    assert!(bbox.max.approx_eq(&Point2D::new(10.0, 10.0)));
+
+    let circle = Arc2D::circle(Point2D::new(5.0, 5.0), 1.0).bounding_box();
+    assert!(circle.min.approx_eq(&Point2D::new(4.0, 4.0)));
+    assert!(circle.max.approx_eq(&Point2D::new(6.0, 6.0)));
+  }
+
+  #[test]
+  fn test_polyline() {
+    let square = square();
+
+    assert_eq!(square.segments().len(), 4);
+    assert_eq!(square.length(), 40.0);
+    assert_eq!(square.signed_area(), 100.0);
+    assert_eq!(Polyline2D::new(square.points.iter().rev().copied().collect(), true).signed_area(), -100.0);
+
+    let open = Polyline2D::new(square.points.clone(), false);
+    assert_eq!(open.segments().len(), 3);
+    assert_eq!(open.length(), 30.0);
+  }
+
+  #[test]
+  fn test_polyline_transform() {
+    let moved = square().transform(&Matrix2D::translation(Vector2D::new(5.0, -5.0)));
+    let bbox = moved.bounding_box().unwrap();
+
+    assert_eq!(bbox.min, Point2D::new(5.0, -5.0));
+    assert_eq!(bbox.max, Point2D::new(15.0, 5.0));
+  }
+
+  #[test]
+  fn test_curve() {
+    let curves: Vec<Curve2D> = vec![
+      LineSegment2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0)).into(),
+      Arc2D::circle(Point2D::default(), 1.0).into(),
+      square().into()
+    ];
+
+    assert_eq!(curves[0].length(), 5.0);
+    assert!(curves[1].length().approx_eq(&TAU));
+    assert_eq!(curves[2].length(), 40.0);
+    assert_eq!(curves[2].start(), curves[2].end());
+    assert_eq!(curves[2].tessellate(8).len(), 5);
+    assert_eq!(curves[1].tessellate(8).len(), 9);
+  }
+}
@@ -0,0 +1,313 @@
+use std::ops::Mul;
+use super::{
+  ApproxEq,
+  Point2D,
+  Point3D,
+  Vector2D,
+  Vector3D,
+  EPSILON
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Affine transformation of 2D space, stored as row-major 3x3 matrix.
+///
+/// Points are treated as column vectors, so `a * b` applies `b` first and then `a`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Matrix2D {
+  pub m: [[f64; 3]; 3]
+}
+
+/// Affine transformation of 3D space, stored as row-major 4x4 matrix.
+///
+/// Points are treated as column vectors, so `a * b` applies `b` first and then `a`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Matrix3D {
+  pub m: [[f64; 4]; 4]
+}
+
+impl Default for Matrix2D {
+  fn default() -> Self {
+    Self::identity()
+  }
+}
+
+impl Default for Matrix3D {
+  fn default() -> Self {
+    Self::identity()
+  }
+}
+
+impl Matrix2D {
+  pub fn identity() -> Self {
+    Self { m: [
+      [1.0, 0.0, 0.0],
+      [0.0, 1.0, 0.0],
+      [0.0, 0.0, 1.0]
+    ]}
+  }
+
+  pub fn translation(offset: Vector2D) -> Self {
+    Self { m: [
+      [1.0, 0.0, offset.x],
+      [0.0, 1.0, offset.y],
+      [0.0, 0.0, 1.0]
+    ]}
+  }
+
+  /// Counter-clockwise rotation around origin.
+  pub fn rotation(angle: f64) -> Self {
+    let (sin, cos) = angle.sin_cos();
+    Self { m: [
+      [cos, -sin, 0.0],
+      [sin, cos, 0.0],
+      [0.0, 0.0, 1.0]
+    ]}
+  }
+
+  pub fn scale(x: f64, y: f64) -> Self {
+    Self { m: [
+      [x, 0.0, 0.0],
+      [0.0, y, 0.0],
+      [0.0, 0.0, 1.0]
+    ]}
+  }
+
+  /// Builds matrix from columns: images of X and Y axes and translation.
+  pub fn from_axes(axis_x: Vector2D, axis_y: Vector2D, origin: Point2D) -> Self {
+    Self { m: [
+      [axis_x.x, axis_y.x, origin.x],
+      [axis_x.y, axis_y.y, origin.y],
+      [0.0, 0.0, 1.0]
+    ]}
+  }
+
+  pub fn transform_point(&self, point: &Point2D) -> Point2D {
+    let m = &self.m;
+    Point2D::new(
+      m[0][0] * point.x + m[0][1] * point.y + m[0][2],
+      m[1][0] * point.x + m[1][1] * point.y + m[1][2]
+    )
+  }
+
+  /// Transforms vector, ignoring translation.
+  pub fn transform_vector(&self, vector: &Vector2D) -> Vector2D {
+    let m = &self.m;
+    Vector2D::new(
+      m[0][0] * vector.x + m[0][1] * vector.y,
+      m[1][0] * vector.x + m[1][1] * vector.y
+    )
+  }
+
+  pub fn determinant(&self) -> f64 {
+    self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+  }
+
+  /// Returns inverse transformation or `None` if matrix is degenerate.
+  pub fn inverse(&self) -> Option<Self> {
+    let det = self.determinant();
+    if det.abs() <= EPSILON {
+      return None;
+    }
+    let m = &self.m;
+    let (a, b, c, d) = (m[1][1] / det, -m[0][1] / det, -m[1][0] / det, m[0][0] / det);
+    Some(Self { m: [
+      [a, b, -(a * m[0][2] + b * m[1][2])],
+      [c, d, -(c * m[0][2] + d * m[1][2])],
+      [0.0, 0.0, 1.0]
+    ]})
+  }
+}
+
+impl Matrix3D {
+  pub fn identity() -> Self {
+    Self { m: [
+      [1.0, 0.0, 0.0, 0.0],
+      [0.0, 1.0, 0.0, 0.0],
+      [0.0, 0.0, 1.0, 0.0],
+      [0.0, 0.0, 0.0, 1.0]
+    ]}
+  }
+
+  pub fn translation(offset: Vector3D) -> Self {
+    Self::from_axes(Vector3D::X, Vector3D::Y, Vector3D::Z, Point3D::new(offset.x, offset.y, offset.z))
+  }
+
+  /// Counter-clockwise rotation around given axis (right-hand rule).
+  ///
+  /// Returns identity matrix if axis is a zero vector.
+  pub fn rotation(axis: Vector3D, angle: f64) -> Self {
+    let Some(Vector3D { x, y, z }) = axis.normalized() else {
+      return Self::identity();
+    };
+    let (sin, cos) = angle.sin_cos();
+    let t = 1.0 - cos;
+    Self { m: [
+      [t * x * x + cos, t * x * y - sin * z, t * x * z + sin * y, 0.0],
+      [t * x * y + sin * z, t * y * y + cos, t * y * z - sin * x, 0.0],
+      [t * x * z - sin * y, t * y * z + sin * x, t * z * z + cos, 0.0],
+      [0.0, 0.0, 0.0, 1.0]
+    ]}
+  }
+
+  pub fn scale(x: f64, y: f64, z: f64) -> Self {
+    Self::from_axes(Vector3D::X * x, Vector3D::Y * y, Vector3D::Z * z, Point3D::default())
+  }
+
+  /// Builds matrix from columns: images of X, Y and Z axes and translation.
+  pub fn from_axes(axis_x: Vector3D, axis_y: Vector3D, axis_z: Vector3D, origin: Point3D) -> Self {
+    Self { m: [
+      [axis_x.x, axis_y.x, axis_z.x, origin.x],
+      [axis_x.y, axis_y.y, axis_z.y, origin.y],
+      [axis_x.z, axis_y.z, axis_z.z, origin.z],
+      [0.0, 0.0, 0.0, 1.0]
+    ]}
+  }
+
+  pub fn transform_point(&self, point: &Point3D) -> Point3D {
+    let m = &self.m;
+    Point3D::new(
+      m[0][0] * point.x + m[0][1] * point.y + m[0][2] * point.z + m[0][3],
+      m[1][0] * point.x + m[1][1] * point.y + m[1][2] * point.z + m[1][3],
+      m[2][0] * point.x + m[2][1] * point.y + m[2][2] * point.z + m[2][3]
+    )
+  }
+
+  /// Transforms vector, ignoring translation.
+  pub fn transform_vector(&self, vector: &Vector3D) -> Vector3D {
+    let m = &self.m;
+    Vector3D::new(
+      m[0][0] * vector.x + m[0][1] * vector.y + m[0][2] * vector.z,
+      m[1][0] * vector.x + m[1][1] * vector.y + m[1][2] * vector.z,
+      m[2][0] * vector.x + m[2][1] * vector.y + m[2][2] * vector.z
+    )
+  }
+
+  /// Returns determinant of the linear part.
+  pub fn determinant(&self) -> f64 {
+    let m = &self.m;
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+      - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+      + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+  }
+
+  /// Returns inverse transformation or `None` if matrix is degenerate.
+  pub fn inverse(&self) -> Option<Self> {
+    let det = self.determinant();
+    if det.abs() <= EPSILON {
+      return None;
+    }
+    let m = &self.m;
+    let mut inv = [[0.0; 3]; 3];
+    for (row, inv_row) in inv.iter_mut().enumerate() {
+      for (col, value) in inv_row.iter_mut().enumerate() {
+        // cofactor of (col, row) gives the adjugate entry (row, col)
+        let (r1, r2) = ((col + 1) % 3, (col + 2) % 3);
+        let (c1, c2) = ((row + 1) % 3, (row + 2) % 3);
+        *value = (m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]) / det;
+      }
+    }
+    let t = [m[0][3], m[1][3], m[2][3]];
+    let mut result = Self::identity();
+    for (row, inv_row) in result.m.iter_mut().zip(inv.iter()) {
+      row[..3].copy_from_slice(inv_row);
+      row[3] = -(inv_row[0] * t[0] + inv_row[1] * t[1] + inv_row[2] * t[2]);
+    }
+    Some(result)
+  }
+}
+
+impl Mul for Matrix2D {
+  type Output = Self;
+  fn mul(self, rhs: Self) -> Self {
+    let mut m = [[0.0; 3]; 3];
+    for (row, values) in m.iter_mut().enumerate() {
+      for (col, value) in values.iter_mut().enumerate() {
+        *value = (0..3).map(|k| self.m[row][k] * rhs.m[k][col]).sum();
+      }
+    }
+    Self { m }
+  }
+}
+
+impl Mul for Matrix3D {
+  type Output = Self;
+  fn mul(self, rhs: Self) -> Self {
+    let mut m = [[0.0; 4]; 4];
+    for (row, values) in m.iter_mut().enumerate() {
+      for (col, value) in values.iter_mut().enumerate() {
+        *value = (0..4).map(|k| self.m[row][k] * rhs.m[k][col]).sum();
+      }
+    }
+    Self { m }
+  }
+}
+
+impl ApproxEq for Matrix2D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.m.iter().flatten().zip(other.m.iter().flatten()).all(|(a, b)| a.approx_eq_eps(b, epsilon))
+  }
+}
+
+impl ApproxEq for Matrix3D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.m.iter().flatten().zip(other.m.iter().flatten()).all(|(a, b)| a.approx_eq_eps(b, epsilon))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::FRAC_PI_2;
+  use crate::geometry::*;
+
+  #[test]
+  fn test_matrix_2d() {
+    let m = Matrix2D::translation(Vector2D::new(10.0, 0.0)) * Matrix2D::rotation(FRAC_PI_2);
+
+    assert!(m.transform_point(&Point2D::new(1.0, 0.0)).approx_eq(&Point2D::new(10.0, 1.0)));
+    assert!(m.transform_vector(&Vector2D::X).approx_eq(&Vector2D::Y));
+    assert!((m * m.inverse().unwrap()).approx_eq(&Matrix2D::identity()));
+    assert!(Matrix2D::scale(0.0, 1.0).inverse().is_none());
+  }
+
+  #[test]
+  fn test_rotation_3d() {
+    let m = Matrix3D::rotation(Vector3D::Z, FRAC_PI_2);
+
+    assert!(m.transform_vector(&Vector3D::X).approx_eq(&Vector3D::Y));
+    assert!(m.transform_vector(&Vector3D::Z).approx_eq(&Vector3D::Z));
+    assert!(Matrix3D::rotation(Vector3D::X, FRAC_PI_2).transform_vector(&Vector3D::Y).approx_eq(&Vector3D::Z));
+    assert!(m.determinant().approx_eq(&1.0));
+    assert_eq!(Matrix3D::rotation(Vector3D::default(), 1.0), Matrix3D::identity());
+  }
+
+  #[test]
+  fn test_composition_order() {
+    let translate = Matrix3D::translation(Vector3D::new(100.0, 0.0, 0.0));
+    let rotate = Matrix3D::rotation(Vector3D::Z, FRAC_PI_2);
+    let point = Point3D::new(1.0, 0.0, 0.0);
+
+    assert!((translate * rotate).transform_point(&point).approx_eq(&Point3D::new(100.0, 1.0, 0.0)));
+    assert!((rotate * translate).transform_point(&point).approx_eq(&Point3D::new(0.0, 101.0, 0.0)));
+  }
+
+  #[test]
+  fn test_inverse_3d() {
+    let m = Matrix3D::translation(Vector3D::new(5.0, -3.0, 2.0))
+      * Matrix3D::rotation(Vector3D::new(1.0, 1.0, 1.0), 0.7)
+      * Matrix3D::scale(2.0, 3.0, 4.0);
+    let inverse = m.inverse().unwrap();
+    let point = Point3D::new(1.5, -2.0, 7.0);
+
+    assert!((m * inverse).approx_eq(&Matrix3D::identity()));
+    assert!((inverse * m).approx_eq(&Matrix3D::identity()));
+    assert!(inverse.transform_point(&m.transform_point(&point)).approx_eq(&point));
+    assert!(Matrix3D::scale(1.0, 0.0, 1.0).inverse().is_none());
+  }
+}
@@ -0,0 +1,61 @@
+//! Pure-Rust geometry primitives.
+//!
+//! Types in this module mirror geometric concepts of Renga API (points, vectors, placements,
+//! curves and regions) and can be converted from corresponding Renga records and interfaces.
+//! All lengths are in millimeters and all angles are in radians, as in Renga.
+//!
+//! Floating point values should be compared with [ApproxEq]:
+//! ```no_run
+//! use renga_api_rs::geometry::*;
+//!
+//! let a = Vector3D::new(0.1 + 0.2, 0.0, 0.0);
+//! let b = Vector3D::new(0.3, 0.0, 0.0);
+//! assert!(a.approx_eq(&b));
+//! ```
+
+mod approx;
+mod point;
+mod vector;
+mod matrix;
+mod placement;
+mod curve;
+mod region;
+mod section;
+mod bounding_box;
+mod camera;
+mod renga;
+
+pub use approx::{
+  ApproxEq,
+  EPSILON
+};
+pub use point::{
+  Point2D,
+  Point3D
+};
+pub use vector::{
+  Vector2D,
+  Vector3D
+};
+pub use matrix::{
+  Matrix2D,
+  Matrix3D
+};
+pub use placement::{
+  Placement2D,
+  Placement3D
+};
+pub use curve::{
+  Arc2D,
+  Curve2D,
+  LineSegment2D,
+  LineSegment3D,
+  Polyline2D
+};
+pub use region::Region2D;
+pub use section::SectionProperties;
+pub use bounding_box::{
+  BoundingBox2D,
+  BoundingBox3D
+};
+pub use camera::Camera;
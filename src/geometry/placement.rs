@@ -0,0 +1,212 @@
+use super::{
+  ApproxEq,
+  Matrix2D,
+  Matrix3D,
+  Point2D,
+  Point3D,
+  Vector2D,
+  Vector3D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Local coordinate system in 2D space.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_placement2_d.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Placement2D {
+  /// Origin of the coordinate system.
+  pub origin: Point2D,
+
+  /// Direction of X axis.
+  pub axis_x: Vector2D
+}
+
+/// Local coordinate system in 3D space.
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_placement3_d.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Placement3D {
+  /// Origin of the coordinate system.
+  pub origin: Point3D,
+
+  /// Direction of X axis.
+  pub axis_x: Vector3D,
+
+  /// Direction of Z axis.
+  pub axis_z: Vector3D
+}
+
+impl Default for Placement2D {
+  fn default() -> Self {
+    Self {
+      origin: Point2D::default(),
+      axis_x: Vector2D::X
+    }
+  }
+}
+
+impl Default for Placement3D {
+  fn default() -> Self {
+    Self {
+      origin: Point3D::default(),
+      axis_x: Vector3D::X,
+      axis_z: Vector3D::Z
+    }
+  }
+}
+
+impl Placement2D {
+  pub fn new(origin: Point2D, axis_x: Vector2D) -> Self {
+    Self { origin, axis_x }
+  }
+
+  /// Returns direction of Y axis, perpendicular to X axis.
+  pub fn axis_y(&self) -> Vector2D {
+    self.axis_x.perpendicular()
+  }
+
+  /// Returns matrix transforming local coordinates to global coordinates.
+  pub fn to_matrix(&self) -> Matrix2D {
+    let axis_x = self.axis_x.normalized().unwrap_or(Vector2D::X);
+    Matrix2D::from_axes(axis_x, axis_x.perpendicular(), self.origin)
+  }
+
+  /// Converts point from local to global coordinates.
+  pub fn to_global(&self, point: &Point2D) -> Point2D {
+    self.to_matrix().transform_point(point)
+  }
+
+  /// Converts point from global to local coordinates.
+  pub fn to_local(&self, point: &Point2D) -> Point2D {
+    self
+      .to_matrix()
+      .inverse()
+      .map_or(*point, |inverse| inverse.transform_point(point))
+  }
+
+  /// Returns 3D placement in XY plane with given elevation.
+  pub fn to_3d(&self, z: f64) -> Placement3D {
+    Placement3D::new(self.origin.to_3d(z), self.axis_x.to_3d(0.0), Vector3D::Z)
+  }
+}
+
+impl Placement3D {
+  pub fn new(origin: Point3D, axis_x: Vector3D, axis_z: Vector3D) -> Self {
+    Self { origin, axis_x, axis_z }
+  }
+
+  /// Returns direction of Y axis, forming right-handed coordinate system.
+  pub fn axis_y(&self) -> Vector3D {
+    self.axis_z.cross(&self.axis_x)
+  }
+
+  /// Returns matrix transforming local coordinates to global coordinates.
+  pub fn to_matrix(&self) -> Matrix3D {
+    let axis_z = self.axis_z.normalized().unwrap_or(Vector3D::Z);
+    let axis_x = self.axis_x.normalized().unwrap_or(Vector3D::X);
+    Matrix3D::from_axes(axis_x, axis_z.cross(&axis_x), axis_z, self.origin)
+  }
+
+  /// Builds placement from rigid transformation matrix.
+  pub fn from_matrix(matrix: &Matrix3D) -> Self {
+    Self {
+      origin: matrix.transform_point(&Point3D::default()),
+      axis_x: matrix.transform_vector(&Vector3D::X),
+      axis_z: matrix.transform_vector(&Vector3D::Z)
+    }
+  }
+
+  /// Converts point from local to global coordinates.
+  pub fn to_global(&self, point: &Point3D) -> Point3D {
+    self.to_matrix().transform_point(point)
+  }
+
+  /// Converts point from global to local coordinates.
+  pub fn to_local(&self, point: &Point3D) -> Point3D {
+    self
+      .to_matrix()
+      .inverse()
+      .map_or(*point, |inverse| inverse.transform_point(point))
+  }
+
+  /// Returns placement given in this coordinate system, expressed in global coordinates.
+  pub fn compose(&self, child: &Placement3D) -> Placement3D {
+    Self::from_matrix(&(self.to_matrix() * child.to_matrix()))
+  }
+}
+
+impl ApproxEq for Placement2D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.origin.approx_eq_eps(&other.origin, epsilon) && self.axis_x.approx_eq_eps(&other.axis_x, epsilon)
+  }
+}
+
+impl ApproxEq for Placement3D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.origin.approx_eq_eps(&other.origin, epsilon)
+      && self.axis_x.approx_eq_eps(&other.axis_x, epsilon)
+      && self.axis_z.approx_eq_eps(&other.axis_z, epsilon)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::geometry::*;
+
+  #[test]
+  fn test_default_is_identity() {
+    assert_eq!(Placement3D::default().to_matrix(), Matrix3D::identity());
+    assert_eq!(Placement2D::default().to_matrix(), Matrix2D::identity());
+    assert_eq!(Placement3D::default().axis_y(), Vector3D::Y);
+  }
+
+  #[test]
+  fn test_placement_2d() {
+    let placement = Placement2D::new(Point2D::new(100.0, 50.0), Vector2D::new(0.0, 2.0));
+    let local = Point2D::new(10.0, 5.0);
+    let global = placement.to_global(&local);
+
+    assert!(placement.axis_y().approx_eq(&Vector2D::new(-2.0, 0.0)));
+    assert!(global.approx_eq(&Point2D::new(95.0, 60.0)));
+    assert!(placement.to_local(&global).approx_eq(&local));
+  }
+
+  #[test]
+  fn test_placement_3d() {
+    let placement = Placement3D::new(Point3D::new(1000.0, 0.0, 3000.0), Vector3D::Y, Vector3D::Z);
+    let local = Point3D::new(10.0, 20.0, 30.0);
+    let global = placement.to_global(&local);
+
+    assert!(global.approx_eq(&Point3D::new(980.0, 10.0, 3030.0)));
+    assert!(placement.to_local(&global).approx_eq(&local));
+    assert!(Placement3D::from_matrix(&placement.to_matrix()).approx_eq(&placement));
+  }
+
+  #[test]
+  fn test_compose() {
+    let parent = Placement3D::new(Point3D::new(1000.0, 0.0, 0.0), Vector3D::Y, Vector3D::Z);
+    let child = Placement3D::new(Point3D::new(100.0, 0.0, 0.0), Vector3D::Y, Vector3D::Z);
+    let composed = parent.compose(&child);
+
+    assert!(composed.origin.approx_eq(&Point3D::new(1000.0, 100.0, 0.0)));
+    assert!(composed.axis_x.approx_eq(&-Vector3D::X));
+    assert!(composed.axis_z.approx_eq(&Vector3D::Z));
+    assert!(Placement3D::default().compose(&child).approx_eq(&child));
+  }
+
+  #[test]
+  fn test_to_3d() {
+    let placement = Placement2D::new(Point2D::new(1.0, 2.0), Vector2D::Y).to_3d(300.0);
+
+    assert_eq!(placement.origin, Point3D::new(1.0, 2.0, 300.0));
+    assert_eq!(placement.axis_x, Vector3D::Y);
+    assert_eq!(placement.axis_z, Vector3D::Z);
+  }
+}
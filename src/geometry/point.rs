@@ -0,0 +1,149 @@
+use std::ops::{
+  Add,
+  Sub
+};
+use super::{
+  ApproxEq,
+  Vector2D,
+  Vector3D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Point in 2D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Point2D {
+  pub x: f64,
+  pub y: f64
+}
+
+/// Point in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Point3D {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64
+}
+
+impl Point2D {
+  pub fn new(x: f64, y: f64) -> Self {
+    Self { x, y }
+  }
+
+  /// Returns distance to another point.
+  pub fn distance(&self, other: &Self) -> f64 {
+    (*other - *self).length()
+  }
+
+  /// Returns point in the middle between this and another point.
+  pub fn midpoint(&self, other: &Self) -> Self {
+    Self::new((self.x + other.x) / 2.0, (self.y + other.y) / 2.0)
+  }
+
+  /// Returns 3D point with the same coordinates and given elevation.
+  pub fn to_3d(&self, z: f64) -> Point3D {
+    Point3D::new(self.x, self.y, z)
+  }
+}
+
+impl Point3D {
+  pub fn new(x: f64, y: f64, z: f64) -> Self {
+    Self { x, y, z }
+  }
+
+  /// Returns distance to another point.
+  pub fn distance(&self, other: &Self) -> f64 {
+    (*other - *self).length()
+  }
+
+  /// Returns point in the middle between this and another point.
+  pub fn midpoint(&self, other: &Self) -> Self {
+    Self::new((self.x + other.x) / 2.0, (self.y + other.y) / 2.0, (self.z + other.z) / 2.0)
+  }
+
+  /// Returns projection of this point onto XY plane.
+  pub fn to_2d(&self) -> Point2D {
+    Point2D::new(self.x, self.y)
+  }
+}
+
+impl Add<Vector2D> for Point2D {
+  type Output = Self;
+  fn add(self, rhs: Vector2D) -> Self { Self::new(self.x + rhs.x, self.y + rhs.y) }
+}
+
+impl Sub<Vector2D> for Point2D {
+  type Output = Self;
+  fn sub(self, rhs: Vector2D) -> Self { Self::new(self.x - rhs.x, self.y - rhs.y) }
+}
+
+impl Sub for Point2D {
+  type Output = Vector2D;
+  fn sub(self, rhs: Self) -> Vector2D { Vector2D::new(self.x - rhs.x, self.y - rhs.y) }
+}
+
+impl Add<Vector3D> for Point3D {
+  type Output = Self;
+  fn add(self, rhs: Vector3D) -> Self { Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z) }
+}
+
+impl Sub<Vector3D> for Point3D {
+  type Output = Self;
+  fn sub(self, rhs: Vector3D) -> Self { Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z) }
+}
+
+impl Sub for Point3D {
+  type Output = Vector3D;
+  fn sub(self, rhs: Self) -> Vector3D { Vector3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z) }
+}
+
+impl ApproxEq for Point2D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.x.approx_eq_eps(&other.x, epsilon) && self.y.approx_eq_eps(&other.y, epsilon)
+  }
+}
+
+impl ApproxEq for Point3D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.x.approx_eq_eps(&other.x, epsilon)
+      && self.y.approx_eq_eps(&other.y, epsilon)
+      && self.z.approx_eq_eps(&other.z, epsilon)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::geometry::*;
+
+  #[test]
+  fn test_distance() {
+    assert!(Point2D::new(0.0, 0.0).distance(&Point2D::new(3.0, 4.0)).approx_eq(&5.0));
+    assert!(Point3D::new(1.0, 2.0, 3.0).distance(&Point3D::new(3.0, 5.0, 9.0)).approx_eq(&7.0));
+  }
+
+  #[test]
+  fn test_arithmetic() {
+    let a = Point3D::new(1.0, 2.0, 3.0);
+    let b = Point3D::new(4.0, 6.0, 8.0);
+
+    assert_eq!(b - a, Vector3D::new(3.0, 4.0, 5.0));
+    assert_eq!(a + (b - a), b);
+    assert_eq!(b - (b - a), a);
+    assert_eq!(a.midpoint(&b), Point3D::new(2.5, 4.0, 5.5));
+    assert_eq!(Point2D::new(1.0, 1.0) + Vector2D::new(2.0, -1.0), Point2D::new(3.0, 0.0));
+  }
+
+  #[test]
+  fn test_dimension_conversion() {
+    let point = Point3D::new(1.0, 2.0, 3.0);
+
+    assert_eq!(point.to_2d(), Point2D::new(1.0, 2.0));
+    assert_eq!(point.to_2d().to_3d(3.0), point);
+  }
+}
@@ -0,0 +1,149 @@
+use super::{
+  ApproxEq,
+  BoundingBox2D,
+  Curve2D,
+  Point2D,
+  Polyline2D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Planar region bounded by an outer contour, possibly with holes.
+///
+/// Contours are stored as closed polylines; curved boundaries are tessellated with
+/// [Region2D::from_curves].
+///
+/// See [Official documentation](https://help.rengabim.com/api/interface_i_region2_d.html)
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Region2D {
+  /// Outer boundary of the region.
+  pub outer: Polyline2D,
+
+  /// Boundaries of holes inside the region.
+  pub holes: Vec<Polyline2D>
+}
+
+impl Region2D {
+  pub fn new(outer: Polyline2D, holes: Vec<Polyline2D>) -> Self {
+    Self {
+      outer: Polyline2D::new(outer.points, true),
+      holes: holes
+        .into_iter()
+        .map(|hole| Polyline2D::new(hole.points, true))
+        .collect()
+    }
+  }
+
+  /// Builds closed contour from a chain of curves, splitting arcs into `arc_segments` segments.
+  ///
+  /// Consecutive duplicate points where curves meet are merged.
+  pub fn contour_from_curves(curves: &[Curve2D], arc_segments: usize) -> Polyline2D {
+    let mut points: Vec<Point2D> = Vec::new();
+    for point in curves.iter().flat_map(|curve| curve.tessellate(arc_segments)) {
+      if !matches!(points.last(), Some(last) if last.approx_eq_eps(&point, 1e-6)) {
+        points.push(point);
+      }
+    }
+    if points.len() > 1 && points[0].approx_eq_eps(&points[points.len() - 1], 1e-6) {
+      points.pop();
+    }
+    Polyline2D::new(points, true)
+  }
+
+  /// Builds region from curve chains of the outer boundary and holes.
+  pub fn from_curves(outer: &[Curve2D], holes: &[Vec<Curve2D>], arc_segments: usize) -> Self {
+    Self::new(
+      Self::contour_from_curves(outer, arc_segments),
+      holes
+        .iter()
+        .map(|hole| Self::contour_from_curves(hole, arc_segments))
+        .collect()
+    )
+  }
+
+  /// Returns area of the region, excluding holes.
+  pub fn area(&self) -> f64 {
+    self.outer.signed_area().abs() - self
+      .holes
+      .iter()
+      .map(|hole| hole.signed_area().abs())
+      .sum::<f64>()
+  }
+
+  pub fn perimeter(&self) -> f64 {
+    self.outer.length() + self.holes.iter().map(|hole| hole.length()).sum::<f64>()
+  }
+
+  pub fn bounding_box(&self) -> Option<BoundingBox2D> {
+    self.outer.bounding_box()
+  }
+
+  /// Returns `true` if point lies inside the region and not inside any hole.
+  pub fn contains(&self, point: &Point2D) -> bool {
+    Self::contour_contains(&self.outer, point) && !self
+      .holes
+      .iter()
+      .any(|hole| Self::contour_contains(hole, point))
+  }
+
+  fn contour_contains(contour: &Polyline2D, point: &Point2D) -> bool {
+    let points = &contour.points;
+    let mut inside = false;
+    let mut j = points.len().wrapping_sub(1);
+    for i in 0..points.len() {
+      let (a, b) = (points[i], points[j]);
+      if (a.y > point.y) != (b.y > point.y) && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x {
+        inside = !inside;
+      }
+      j = i;
+    }
+    inside
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::PI;
+  use crate::geometry::*;
+
+  fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Polyline2D {
+    Polyline2D::new(vec![
+      Point2D::new(x0, y0),
+      Point2D::new(x1, y0),
+      Point2D::new(x1, y1),
+      Point2D::new(x0, y1)
+    ], true)
+  }
+
+  #[test]
+  fn test_area_with_holes() {
+    let region = Region2D::new(rectangle(0.0, 0.0, 100.0, 50.0), vec![rectangle(10.0, 10.0, 20.0, 20.0)]);
+
+    assert_eq!(region.area(), 4900.0);
+    assert_eq!(region.perimeter(), 340.0);
+    assert!(region.contains(&Point2D::new(50.0, 25.0)));
+    assert!(!region.contains(&Point2D::new(15.0, 15.0)));
+    assert!(!region.contains(&Point2D::new(150.0, 25.0)));
+  }
+
+  #[test]
+  fn test_from_curves() {
+    // D-shaped region: straight edge closed by a half circle
+    let outer: Vec<Curve2D> = vec![
+      LineSegment2D::new(Point2D::new(-10.0, 0.0), Point2D::new(10.0, 0.0)).into(),
+      Arc2D::new(Point2D::default(), 10.0, 0.0, PI).into()
+    ];
+    let region = Region2D::from_curves(&outer, &[], 256);
+
+    assert_eq!(region.outer.points.len(), 257);
+    assert!(region.area().approx_eq_eps(&(PI * 50.0), 0.1));
+    assert!(region.contains(&Point2D::new(0.0, 5.0)));
+    assert!(!region.contains(&Point2D::new(0.0, -5.0)));
+    assert!(region.bounding_box().unwrap().approx_eq(&BoundingBox2D::new(Point2D::new(-10.0, 0.0), Point2D::new(10.0, 10.0))));
+  }
+}
@@ -0,0 +1,158 @@
+//! Conversions between geometry types and Renga records and interfaces.
+
+use crate::{
+  native::{
+    constants,
+    Dispatch,
+    records
+  },
+  Result,
+  Error
+};
+use super::{
+  Arc2D,
+  Curve2D,
+  LineSegment2D,
+  Placement2D,
+  Placement3D,
+  Point2D,
+  Point3D,
+  Region2D,
+  Vector2D,
+  Vector3D
+};
+
+impl From<records::Point2D> for Point2D {
+  fn from(record: records::Point2D) -> Self { Self { x: record.x, y: record.y } }
+}
+
+impl From<records::Point3D> for Point3D {
+  fn from(record: records::Point3D) -> Self { Self { x: record.x, y: record.y, z: record.z } }
+}
+
+impl From<records::Vector2D> for Vector2D {
+  fn from(record: records::Vector2D) -> Self { Self { x: record.x, y: record.y } }
+}
+
+impl From<records::Vector3D> for Vector3D {
+  fn from(record: records::Vector3D) -> Self { Self { x: record.x, y: record.y, z: record.z } }
+}
+
+impl From<Point2D> for records::Point2D {
+  fn from(point: Point2D) -> Self { Self { x: point.x, y: point.y } }
+}
+
+impl From<Point3D> for records::Point3D {
+  fn from(point: Point3D) -> Self { Self { x: point.x, y: point.y, z: point.z } }
+}
+
+impl From<Vector2D> for records::Vector2D {
+  fn from(vector: Vector2D) -> Self { Self { x: vector.x, y: vector.y } }
+}
+
+impl From<Vector3D> for records::Vector3D {
+  fn from(vector: Vector3D) -> Self { Self { x: vector.x, y: vector.y, z: vector.z } }
+}
+
+fn point2d(handle: &Dispatch, name: &str) -> Result<Point2D> {
+  let variant = handle.call(name, None)?;
+  Ok(unsafe { records::Point2D::from_variant(&variant)? }.into())
+}
+
+impl TryFrom<Dispatch> for Placement2D {
+  type Error = Error;
+  /// Reads placement from native `IPlacement2D` handle.
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IPlacement2D handle is null".to_owned()));
+    }
+    let (origin, axis_x) = unsafe {(
+      records::Point2D::from_variant(&handle.get("Origin")?)?,
+      records::Vector2D::from_variant(&handle.get("xAxis")?)?
+    )};
+    Ok(Self::new(origin.into(), axis_x.into()))
+  }
+}
+
+impl TryFrom<Dispatch> for Placement3D {
+  type Error = Error;
+  /// Reads placement from native `IPlacement3D` handle.
+  fn try_from(handle: Dispatch) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IPlacement3D handle is null".to_owned()));
+    }
+    let (origin, axis_x, axis_z) = unsafe {(
+      records::Point3D::from_variant(&handle.get("Origin")?)?,
+      records::Vector3D::from_variant(&handle.get("AxisX")?)?,
+      records::Vector3D::from_variant(&handle.get("AxisZ")?)?
+    )};
+    Ok(Self::new(origin.into(), axis_x.into(), axis_z.into()))
+  }
+}
+
+impl Curve2D {
+  /// Reads curve from native `ICurve2D` handle.
+  ///
+  /// Poly-curves are flattened into their segments, so this returns a chain of curves.
+  /// Orientation of arcs is determined by their middle point.
+  pub fn from_native(handle: &Dispatch) -> Result<Vec<Self>> {
+    if handle.is_null() {
+      return Err(Error::Internal("ICurve2D handle is null".to_owned()));
+    }
+    let curve_type = handle.get("Curve2DType")?.as_int()?;
+    match curve_type {
+      constants::CURVE2D_TYPE_LINE_SEGMENT => Ok(vec![LineSegment2D::new(
+        point2d(handle, "GetBeginPoint")?,
+        point2d(handle, "GetEndPoint")?
+      ).into()]),
+      constants::CURVE2D_TYPE_ARC => {
+        let arc = handle
+          .call("GetInterfaceByName", Some(vec!["IArc2D".into()]))?
+          .into_dispatch()?;
+        let center = unsafe { records::Point2D::from_variant(&arc.get("Center")?)? };
+        let middle = (handle.call("GetMinParameter", None)?.as_f64()? + handle.call("GetMaxParameter", None)?.as_f64()?) / 2.0;
+        let through = unsafe { records::Point2D::from_variant(&handle.call("GetPointOn", Some(vec![middle.into()]))?)? };
+        Ok(vec![Arc2D::from_points(
+          center.into(),
+          point2d(handle, "GetBeginPoint")?,
+          through.into(),
+          point2d(handle, "GetEndPoint")?
+        ).into()])
+      },
+      constants::CURVE2D_TYPE_POLY_CURVE => {
+        let poly = handle
+          .call("GetInterfaceByName", Some(vec!["IPolyCurve2D".into()]))?
+          .into_dispatch()?;
+        let count = poly.call("GetSegmentCount", None)?.as_int()?;
+        let mut curves = Vec::new();
+        for index in 0..count {
+          let segment = poly
+            .call("GetSegment", Some(vec![index.into()]))?
+            .into_dispatch()?;
+          curves.extend(Self::from_native(&segment)?);
+        }
+        Ok(curves)
+      },
+      other => Err(Error::TypeMismatch(format!("unsupported curve type {other}")))
+    }
+  }
+}
+
+impl Region2D {
+  /// Reads region from native `IRegion2D` handle, splitting arcs into `arc_segments` segments.
+  pub fn from_native(handle: &Dispatch, arc_segments: usize) -> Result<Self> {
+    if handle.is_null() {
+      return Err(Error::Internal("IRegion2D handle is null".to_owned()));
+    }
+    let outer = Curve2D::from_native(&handle.call("GetContour", None)?.into_dispatch()?)?;
+    let count = handle.call("GetHoleCount", None)?.as_int()?;
+    let mut holes = Vec::with_capacity(count.max(0) as usize);
+    for index in 0..count {
+      let hole = handle
+        .call("GetHole", Some(vec![index.into()]))?
+        .into_dispatch()?;
+      holes.push(Curve2D::from_native(&hole)?);
+    }
+    Ok(Self::from_curves(&outer, &holes, arc_segments))
+  }
+}
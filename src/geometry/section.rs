@@ -0,0 +1,250 @@
+use super::{
+  Point2D,
+  Polyline2D,
+  Region2D
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Geometric properties of a cross-section, e.g. of a steel profile.
+///
+/// Moments of inertia are taken about axes parallel to X and Y through the centroid.
+/// Lengths are in millimeters, so area is in mm² and moments of inertia are in mm⁴.
+///
+/// ```
+/// use renga_api_rs::geometry::*;
+///
+/// let rectangle = Polyline2D::new(vec![
+///   Point2D::new(0.0, 0.0),
+///   Point2D::new(100.0, 0.0),
+///   Point2D::new(100.0, 200.0),
+///   Point2D::new(0.0, 200.0)
+/// ], true);
+/// let section = SectionProperties::from_region(&Region2D::new(rectangle, vec![]));
+///
+/// assert!(section.area.approx_eq(&20_000.0));
+/// assert!(section.ix.approx_eq_eps(&(100.0 * 200.0_f64.powi(3) / 12.0), 1e-3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct SectionProperties {
+  pub area: f64,
+  pub centroid: Point2D,
+
+  /// Moment of inertia about the centroidal axis parallel to X.
+  pub ix: f64,
+
+  /// Moment of inertia about the centroidal axis parallel to Y.
+  pub iy: f64,
+
+  /// Product of inertia about the centroidal axes.
+  pub ixy: f64
+}
+
+/// Area integrals of a contour about the origin: area, first and second moments.
+#[derive(Default)]
+struct Integrals {
+  area: f64,
+  sx: f64,
+  sy: f64,
+  ixx: f64,
+  iyy: f64,
+  ixy: f64
+}
+
+impl Integrals {
+  /// Computes integrals of a closed contour, normalized to counter-clockwise orientation.
+  fn of(contour: &Polyline2D) -> Self {
+    let points = &contour.points;
+    let n = points.len();
+    let mut result = Self::default();
+    if n < 3 {
+      return result;
+    }
+    for i in 0..n {
+      let (a, b) = (points[i], points[(i + 1) % n]);
+      let cross = a.x * b.y - b.x * a.y;
+      result.area += cross / 2.0;
+      result.sx += (a.y + b.y) * cross / 6.0;
+      result.sy += (a.x + b.x) * cross / 6.0;
+      result.ixx += (a.y * a.y + a.y * b.y + b.y * b.y) * cross / 12.0;
+      result.iyy += (a.x * a.x + a.x * b.x + b.x * b.x) * cross / 12.0;
+      result.ixy += (a.x * b.y + 2.0 * a.x * a.y + 2.0 * b.x * b.y + b.x * a.y) * cross / 24.0;
+    }
+    if result.area < 0.0 {
+      result.scale(-1.0);
+    }
+    result
+  }
+
+  fn scale(&mut self, factor: f64) {
+    self.area *= factor;
+    self.sx *= factor;
+    self.sy *= factor;
+    self.ixx *= factor;
+    self.iyy *= factor;
+    self.ixy *= factor;
+  }
+
+  fn add(&mut self, other: &Self, factor: f64) {
+    self.area += other.area * factor;
+    self.sx += other.sx * factor;
+    self.sy += other.sy * factor;
+    self.ixx += other.ixx * factor;
+    self.iyy += other.iyy * factor;
+    self.ixy += other.ixy * factor;
+  }
+}
+
+impl SectionProperties {
+  /// Computes properties of a region. Holes are subtracted regardless of contour orientation.
+  ///
+  /// Curved boundaries are approximated by the polylines of the region, so precision
+  /// depends on the number of arc segments used to build it.
+  pub fn from_region(region: &Region2D) -> Self {
+    let mut total = Integrals::of(&region.outer);
+    for hole in &region.holes {
+      total.add(&Integrals::of(hole), -1.0);
+    }
+    if total.area <= 0.0 {
+      return Self::default();
+    }
+    let centroid = Point2D::new(total.sy / total.area, total.sx / total.area);
+    Self {
+      area: total.area,
+      centroid,
+      ix: total.ixx - total.area * centroid.y * centroid.y,
+      iy: total.iyy - total.area * centroid.x * centroid.x,
+      ixy: total.ixy - total.area * centroid.x * centroid.y
+    }
+  }
+
+  /// Returns radius of gyration about the centroidal axis parallel to X.
+  pub fn radius_of_gyration_x(&self) -> f64 {
+    self.radius_of_gyration(self.ix)
+  }
+
+  /// Returns radius of gyration about the centroidal axis parallel to Y.
+  pub fn radius_of_gyration_y(&self) -> f64 {
+    self.radius_of_gyration(self.iy)
+  }
+
+  /// Returns principal moments of inertia (maximal first) and angle of the major
+  /// principal axis to X axis in radians.
+  pub fn principal_moments(&self) -> (f64, f64, f64) {
+    let average = (self.ix + self.iy) / 2.0;
+    let radius = ((self.ix - self.iy) / 2.0).hypot(self.ixy);
+    let angle = 0.5 * (-2.0 * self.ixy).atan2(self.ix - self.iy);
+    (average + radius, average - radius, angle)
+  }
+
+  fn radius_of_gyration(&self, moment: f64) -> f64 {
+    match self.area > 0.0 {
+      true => (moment / self.area).sqrt(),
+      false => 0.0
+    }
+  }
+}
+
+impl Region2D {
+  /// Returns geometric properties of the region as a cross-section.
+  pub fn section_properties(&self) -> SectionProperties {
+    SectionProperties::from_region(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::PI;
+  use crate::geometry::*;
+
+  fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Polyline2D {
+    Polyline2D::new(vec![
+      Point2D::new(x0, y0),
+      Point2D::new(x1, y0),
+      Point2D::new(x1, y1),
+      Point2D::new(x0, y1)
+    ], true)
+  }
+
+  #[test]
+  fn test_rectangle() {
+    let clockwise = Polyline2D::new(rectangle(10.0, 20.0, 110.0, 220.0).points.into_iter().rev().collect(), true);
+    let section = Region2D::new(clockwise, vec![]).section_properties();
+
+    assert!(section.area.approx_eq(&20_000.0));
+    assert!(section.centroid.approx_eq(&Point2D::new(60.0, 120.0)));
+    assert!(section.ix.approx_eq_eps(&(100.0 * 200.0f64.powi(3) / 12.0), 1e-3));
+    assert!(section.iy.approx_eq_eps(&(200.0 * 100.0f64.powi(3) / 12.0), 1e-3));
+    assert!(section.ixy.approx_eq_eps(&0.0, 1e-3));
+    assert!(section.radius_of_gyration_x().approx_eq(&(200.0 / 12.0f64.sqrt())));
+  }
+
+  #[test]
+  fn test_i_beam() {
+    // Welded I-beam 200x100, flanges 10 mm, web 6 mm, as outer contour with two notches
+    let outer = Polyline2D::new(vec![
+      Point2D::new(-50.0, -100.0),
+      Point2D::new(50.0, -100.0),
+      Point2D::new(50.0, -90.0),
+      Point2D::new(3.0, -90.0),
+      Point2D::new(3.0, 90.0),
+      Point2D::new(50.0, 90.0),
+      Point2D::new(50.0, 100.0),
+      Point2D::new(-50.0, 100.0),
+      Point2D::new(-50.0, 90.0),
+      Point2D::new(-3.0, 90.0),
+      Point2D::new(-3.0, -90.0),
+      Point2D::new(-50.0, -90.0)
+    ], true);
+    let section = Region2D::new(outer, vec![]).section_properties();
+    let ix = 100.0 * 200.0f64.powi(3) / 12.0 - 94.0 * 180.0f64.powi(3) / 12.0;
+    let iy = 2.0 * 10.0 * 100.0f64.powi(3) / 12.0 + 180.0 * 6.0f64.powi(3) / 12.0;
+
+    assert!(section.area.approx_eq(&3080.0));
+    assert!(section.centroid.approx_eq(&Point2D::default()));
+    assert!(section.ix.approx_eq_eps(&ix, 1e-3));
+    assert!(section.iy.approx_eq_eps(&iy, 1e-3));
+    let (major, minor, angle) = section.principal_moments();
+    assert!(major.approx_eq_eps(&ix, 1e-3));
+    assert!(minor.approx_eq_eps(&iy, 1e-3));
+    assert!(angle.approx_eq(&0.0));
+  }
+
+  #[test]
+  fn test_tube_and_angle() {
+    let tube = Region2D::from_curves(
+      &[Arc2D::circle(Point2D::default(), 50.0).into()],
+      &[vec![Arc2D::circle(Point2D::default(), 40.0).into()]],
+      720
+    ).section_properties();
+    let ix = PI / 4.0 * (50.0f64.powi(4) - 40.0f64.powi(4));
+
+    assert!((tube.area - PI * (2500.0 - 1600.0)).abs() / tube.area < 1e-3);
+    assert!((tube.ix - ix).abs() / ix < 1e-3);
+    assert!((tube.iy - ix).abs() / ix < 1e-3);
+
+    // Equal angle 100x100x10: principal axes are rotated by 45 degrees
+    let angle = Polyline2D::new(vec![
+      Point2D::new(0.0, 0.0),
+      Point2D::new(100.0, 0.0),
+      Point2D::new(100.0, 10.0),
+      Point2D::new(10.0, 10.0),
+      Point2D::new(10.0, 100.0),
+      Point2D::new(0.0, 100.0)
+    ], true);
+    let section = Region2D::new(angle, vec![]).section_properties();
+    let (major, minor, rotation) = section.principal_moments();
+
+    assert!(section.area.approx_eq(&1900.0));
+    assert!(section.ix.approx_eq_eps(&section.iy, 1e-3));
+    assert!(section.ixy < 0.0);
+    assert!(major > minor);
+    assert!(rotation.abs().approx_eq_eps(&(PI / 4.0), 1e-9));
+    assert_eq!(Region2D::default().section_properties(), SectionProperties::default());
+  }
+}
@@ -0,0 +1,229 @@
+use std::ops::{
+  Add,
+  Mul,
+  Neg,
+  Sub
+};
+use super::{
+  ApproxEq,
+  EPSILON
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Vector in 2D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Vector2D {
+  pub x: f64,
+  pub y: f64
+}
+
+/// Vector in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Vector3D {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64
+}
+
+impl Vector2D {
+  pub const X: Self = Self { x: 1.0, y: 0.0 };
+  pub const Y: Self = Self { x: 0.0, y: 1.0 };
+
+  pub fn new(x: f64, y: f64) -> Self {
+    Self { x, y }
+  }
+
+  /// Returns unit vector with given angle to X axis.
+  pub fn from_angle(angle: f64) -> Self {
+    Self::new(angle.cos(), angle.sin())
+  }
+
+  pub fn length(&self) -> f64 {
+    self.x.hypot(self.y)
+  }
+
+  /// Returns unit vector with the same direction or `None` for zero vector.
+  pub fn normalized(&self) -> Option<Self> {
+    let length = self.length();
+    match length > EPSILON {
+      true => Some(*self * (1.0 / length)),
+      false => None
+    }
+  }
+
+  pub fn dot(&self, other: &Self) -> f64 {
+    self.x * other.x + self.y * other.y
+  }
+
+  /// Returns Z component of the cross product.
+  pub fn cross(&self, other: &Self) -> f64 {
+    self.x * other.y - self.y * other.x
+  }
+
+  /// Returns vector rotated by 90 degrees counter-clockwise.
+  pub fn perpendicular(&self) -> Self {
+    Self::new(-self.y, self.x)
+  }
+
+  /// Returns angle to X axis in range `(-π, π]`.
+  pub fn angle(&self) -> f64 {
+    self.y.atan2(self.x)
+  }
+
+  /// Returns 3D vector with the same components and given Z component.
+  pub fn to_3d(&self, z: f64) -> Vector3D {
+    Vector3D::new(self.x, self.y, z)
+  }
+}
+
+impl Vector3D {
+  pub const X: Self = Self { x: 1.0, y: 0.0, z: 0.0 };
+  pub const Y: Self = Self { x: 0.0, y: 1.0, z: 0.0 };
+  pub const Z: Self = Self { x: 0.0, y: 0.0, z: 1.0 };
+
+  pub fn new(x: f64, y: f64, z: f64) -> Self {
+    Self { x, y, z }
+  }
+
+  pub fn length(&self) -> f64 {
+    self.dot(self).sqrt()
+  }
+
+  /// Returns unit vector with the same direction or `None` for zero vector.
+  pub fn normalized(&self) -> Option<Self> {
+    let length = self.length();
+    match length > EPSILON {
+      true => Some(*self * (1.0 / length)),
+      false => None
+    }
+  }
+
+  pub fn dot(&self, other: &Self) -> f64 {
+    self.x * other.x + self.y * other.y + self.z * other.z
+  }
+
+  pub fn cross(&self, other: &Self) -> Self {
+    Self::new(
+      self.y * other.z - self.z * other.y,
+      self.z * other.x - self.x * other.z,
+      self.x * other.y - self.y * other.x
+    )
+  }
+
+  /// Returns angle between this and another vector in range `[0, π]`.
+  pub fn angle_to(&self, other: &Self) -> f64 {
+    self.cross(other).length().atan2(self.dot(other))
+  }
+
+  /// Returns projection of this vector onto XY plane.
+  pub fn to_2d(&self) -> Vector2D {
+    Vector2D::new(self.x, self.y)
+  }
+}
+
+impl Add for Vector2D {
+  type Output = Self;
+  fn add(self, rhs: Self) -> Self { Self::new(self.x + rhs.x, self.y + rhs.y) }
+}
+
+impl Sub for Vector2D {
+  type Output = Self;
+  fn sub(self, rhs: Self) -> Self { Self::new(self.x - rhs.x, self.y - rhs.y) }
+}
+
+impl Mul<f64> for Vector2D {
+  type Output = Self;
+  fn mul(self, rhs: f64) -> Self { Self::new(self.x * rhs, self.y * rhs) }
+}
+
+impl Neg for Vector2D {
+  type Output = Self;
+  fn neg(self) -> Self { Self::new(-self.x, -self.y) }
+}
+
+impl Add for Vector3D {
+  type Output = Self;
+  fn add(self, rhs: Self) -> Self { Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z) }
+}
+
+impl Sub for Vector3D {
+  type Output = Self;
+  fn sub(self, rhs: Self) -> Self { Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z) }
+}
+
+impl Mul<f64> for Vector3D {
+  type Output = Self;
+  fn mul(self, rhs: f64) -> Self { Self::new(self.x * rhs, self.y * rhs, self.z * rhs) }
+}
+
+impl Neg for Vector3D {
+  type Output = Self;
+  fn neg(self) -> Self { Self::new(-self.x, -self.y, -self.z) }
+}
+
+impl ApproxEq for Vector2D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.x.approx_eq_eps(&other.x, epsilon) && self.y.approx_eq_eps(&other.y, epsilon)
+  }
+}
+
+impl ApproxEq for Vector3D {
+  fn approx_eq_eps(&self, other: &Self, epsilon: f64) -> bool {
+    self.x.approx_eq_eps(&other.x, epsilon)
+      && self.y.approx_eq_eps(&other.y, epsilon)
+      && self.z.approx_eq_eps(&other.z, epsilon)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::{
+    FRAC_PI_2,
+    PI
+  };
+  use crate::geometry::*;
+
+  #[test]
+  fn test_products() {
+    assert_eq!(Vector3D::X.cross(&Vector3D::Y), Vector3D::Z);
+    assert_eq!(Vector3D::Y.cross(&Vector3D::X), -Vector3D::Z);
+    assert_eq!(Vector3D::new(1.0, 2.0, 3.0).dot(&Vector3D::new(4.0, -5.0, 6.0)), 12.0);
+    assert_eq!(Vector2D::X.cross(&Vector2D::Y), 1.0);
+    assert_eq!(Vector2D::new(1.0, 2.0).dot(&Vector2D::new(3.0, 4.0)), 11.0);
+  }
+
+  #[test]
+  fn test_normalized() {
+    let v = Vector3D::new(0.0, 3.0, 4.0).normalized().unwrap();
+
+    assert!(v.approx_eq(&Vector3D::new(0.0, 0.6, 0.8)));
+    assert!(v.length().approx_eq(&1.0));
+    assert!(Vector3D::default().normalized().is_none());
+    assert!(Vector2D::default().normalized().is_none());
+  }
+
+  #[test]
+  fn test_angles() {
+    assert!(Vector2D::from_angle(FRAC_PI_2).approx_eq(&Vector2D::Y));
+    assert!(Vector2D::new(-1.0, 0.0).angle().approx_eq(&PI));
+    assert!(Vector3D::X.angle_to(&Vector3D::Z).approx_eq(&FRAC_PI_2));
+    assert!(Vector3D::X.angle_to(&-Vector3D::X).approx_eq(&PI));
+    assert_eq!(Vector2D::X.perpendicular(), Vector2D::Y);
+  }
+
+  #[test]
+  fn test_arithmetic() {
+    let a = Vector3D::new(1.0, 2.0, 3.0);
+
+    assert_eq!(a + a, a * 2.0);
+    assert_eq!(a - a, Vector3D::default());
+    assert_eq!(a.to_2d().to_3d(3.0), a);
+  }
+}
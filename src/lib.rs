@@ -29,6 +29,14 @@ mod checks;
 //#[cfg(windows)]
 mod api;
 
+pub mod geometry;
+pub mod mesh;
+pub mod table;
+pub mod ui;
+pub mod plugin;
+pub mod network;
+pub mod reinforcement;
+
 #[cfg(test)]
 mod tests;
 
@@ -37,7 +45,14 @@ pub use tests::*;
 
 pub use error::{
   Result,
-  Error
+  Error,
+  OperationError
+};
+
+pub use geometry::{
+  Point3D,
+  Vector3D,
+  Placement3D
 };
 
 pub mod win {
@@ -48,11 +63,97 @@ pub use api::{
   Application,
   Project,
   ProjectTransaction,
+  ProjectType,
+  BuildingInfo,
+  LandPlotInfo,
+  PostalAddress,
+  ProjectInfo,
+  ProjectInformation,
   Version,
   Category,
   EntityTypes,
   Entity,
-  EntityCollection
+  EntityCollection,
+  UUID,
+  Model,
+  ModelObject,
+  ModelObjectCollection,
+  Level,
+  Building,
+  LevelNode,
+  ObjectNode,
+  Parameter,
+  ParameterContainer,
+  ParameterDefinition,
+  ParameterValue,
+  ParameterValueType,
+  Property,
+  PropertyContainer,
+  PropertyDescription,
+  PropertyManager,
+  PropertyType,
+  PropertyValue,
+  PropertyAction,
+  PropertySet,
+  PropertySetEntry,
+  Quantity,
+  QuantityContainer,
+  QuantityType,
+  QuantityValue,
+  Color,
+  LayeredMaterial,
+  LayeredMaterialManager,
+  Material,
+  MaterialLayer,
+  MaterialManager,
+  NewMaterial,
+  ObjectMaterial,
+  StyleKind,
+  StyleManager,
+  DataExporter,
+  ExportedGrid,
+  ExportedObject,
+  ExportStatus,
+  OverwritePolicy,
+  Drawing,
+  DrawingFormat,
+  IfcExportSettings,
+  IfcImportOptions,
+  IfcVersion,
+  UndoEntry,
+  UndoStack,
+  Selection,
+  SelectionChange,
+  SelectionWatcher,
+  View,
+  ViewType,
+  Action,
+  ActionConnection,
+  ActionEvent,
+  UI,
+  UiExtension,
+  EngineeringSystem,
+  Port,
+  PortContainer,
+  SystemCategory,
+  SystemStyle,
+  RebarStyle,
+  RebarUsage,
+  ReinforcementModel,
+  ReinforcementUnitStyle,
+  Profile,
+  ProfileDescriptionManager,
+  Topic,
+  TopicEntry,
+  TopicIndex,
+  TopicNode,
+  Assembly,
+  AssemblyInstance,
+  AssemblyLibrary,
+  AssemblyNode,
+  AssemblyPart,
+  FlatObject,
+  units
 };
 
 /// Meta information about this crate.
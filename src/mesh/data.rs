@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+use crate::geometry::{
+  BoundingBox3D,
+  Matrix3D,
+  Point3D,
+  Vector3D
+};
+
+/// Indexed triangle mesh.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+  /// Vertex positions in millimeters.
+  pub vertices: Vec<[f32; 3]>,
+
+  /// Per-vertex unit normals. Either empty or of the same length as `vertices`.
+  pub normals: Vec<[f32; 3]>,
+
+  /// Triangles as triples of vertex indices, counter-clockwise when seen from outside.
+  pub triangles: Vec<[u32; 3]>
+}
+
+/// How meshes of model objects are grouped into [MeshNode]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MeshGrouping {
+  /// One node per model object, named by object unique id.
+  #[default]
+  PerObject,
+
+  /// One node per material, named by material name.
+  PerMaterial
+}
+
+/// Named mesh with optional material, e.g. a single model object.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MeshNode {
+  /// Node name, e.g. unique id of the model object.
+  pub name: String,
+
+  /// Name of the material, if known.
+  pub material: Option<String>,
+
+  /// Mesh of the node.
+  pub mesh: Mesh
+}
+
+impl Mesh {
+  pub fn new(vertices: Vec<[f32; 3]>, triangles: Vec<[u32; 3]>) -> Self {
+    Self { vertices, normals: Vec::new(), triangles }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.triangles.is_empty()
+  }
+
+  /// Returns `true` if mesh has one normal per vertex.
+  pub fn has_normals(&self) -> bool {
+    !self.normals.is_empty() && self.normals.len() == self.vertices.len()
+  }
+
+  /// Returns normal of the triangle with given index, or zero vector for degenerate triangles.
+  pub fn face_normal(&self, index: usize) -> [f32; 3] {
+    let [a, b, c] = self.triangles[index].map(|i| self.point(i as usize));
+    let normal = (b - a).cross(&(c - a)).normalized().unwrap_or_default();
+    [normal.x as f32, normal.y as f32, normal.z as f32]
+  }
+
+  /// Computes smooth per-vertex normals by averaging area-weighted face normals.
+  pub fn compute_normals(&mut self) {
+    let mut normals = vec![Vector3D::default(); self.vertices.len()];
+    for triangle in &self.triangles {
+      let [a, b, c] = triangle.map(|i| self.point(i as usize));
+      let normal = (b - a).cross(&(c - a));
+      for index in triangle {
+        normals[*index as usize] = normals[*index as usize] + normal;
+      }
+    }
+    self.normals = normals
+      .into_iter()
+      .map(|normal| normal.normalized().unwrap_or_default())
+      .map(|normal| [normal.x as f32, normal.y as f32, normal.z as f32])
+      .collect();
+  }
+
+  /// Returns bounding box of all vertices or `None` for mesh without vertices.
+  pub fn bounding_box(&self) -> Option<BoundingBox3D> {
+    let points: Vec<_> = (0..self.vertices.len()).map(|i| self.point(i)).collect();
+    BoundingBox3D::from_points(&points)
+  }
+
+  /// Appends another mesh to this one.
+  ///
+  /// Normals are kept only if both meshes have them.
+  pub fn append(&mut self, other: &Mesh) {
+    let offset = self.vertices.len() as u32;
+    let keep_normals = (self.has_normals() || self.vertices.is_empty()) && other.has_normals();
+    self.vertices.extend_from_slice(&other.vertices);
+    match keep_normals {
+      true => self.normals.extend_from_slice(&other.normals),
+      false => self.normals.clear()
+    }
+    self
+      .triangles
+      .extend(other.triangles.iter().map(|triangle| triangle.map(|i| i + offset)));
+  }
+
+  /// Transforms vertices and normals by given matrix.
+  pub fn transform(&mut self, matrix: &Matrix3D) {
+    for vertex in self.vertices.iter_mut() {
+      let point = matrix.transform_point(&Point3D::new(vertex[0] as f64, vertex[1] as f64, vertex[2] as f64));
+      *vertex = [point.x as f32, point.y as f32, point.z as f32];
+    }
+    let normal_matrix = matrix.inverse().map(|inverse| Self::transposed(&inverse)).unwrap_or(*matrix);
+    for normal in self.normals.iter_mut() {
+      let vector = normal_matrix
+        .transform_vector(&Vector3D::new(normal[0] as f64, normal[1] as f64, normal[2] as f64))
+        .normalized()
+        .unwrap_or_default();
+      *normal = [vector.x as f32, vector.y as f32, vector.z as f32];
+    }
+  }
+
+  fn point(&self, index: usize) -> Point3D {
+    let [x, y, z] = self.vertices[index];
+    Point3D::new(x as f64, y as f64, z as f64)
+  }
+
+  fn transposed(matrix: &Matrix3D) -> Matrix3D {
+    let mut result = Matrix3D::identity();
+    for row in 0..3 {
+      for col in 0..3 {
+        result.m[row][col] = matrix.m[col][row];
+      }
+    }
+    result
+  }
+}
+
+impl MeshNode {
+  pub fn new(name: &str, material: Option<&str>, mesh: Mesh) -> Self {
+    Self {
+      name: name.to_owned(),
+      material: material.map(str::to_owned),
+      mesh
+    }
+  }
+
+  /// Merges nodes with the same material into a single node named by the material.
+  ///
+  /// Nodes without material are merged into a node named `default`. Result is sorted by name.
+  pub fn group_by_material(nodes: &[MeshNode]) -> Vec<MeshNode> {
+    let mut groups: BTreeMap<Option<&str>, Mesh> = BTreeMap::new();
+    for node in nodes {
+      groups
+        .entry(node.material.as_deref())
+        .or_default()
+        .append(&node.mesh);
+    }
+    let mut result: Vec<_> = groups
+      .into_iter()
+      .map(|(material, mesh)| MeshNode::new(material.unwrap_or("default"), material, mesh))
+      .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::f64::consts::FRAC_PI_2;
+  use crate::geometry::*;
+  use crate::mesh::*;
+  use crate::mesh::tests::cube;
+
+  #[test]
+  fn test_normals() {
+    let mut mesh = cube(2.0);
+    mesh.compute_normals();
+
+    assert!(mesh.has_normals());
+    assert_eq!(mesh.face_normal(0), [0.0, 0.0, -1.0]);
+    assert_eq!(mesh.face_normal(2), [0.0, 0.0, 1.0]);
+    for (vertex, normal) in mesh.vertices.iter().zip(&mesh.normals) {
+      let outward: f32 = (0..3).map(|i| (vertex[i] - 1.0) * normal[i]).sum();
+      let length: f32 = normal.iter().map(|value| value * value).sum();
+      assert!(outward > 0.0);
+      assert!((length - 1.0).abs() < 1e-6);
+    }
+  }
+
+  #[test]
+  fn test_append() {
+    let mut mesh = cube(1.0);
+    mesh.append(&cube(1.0));
+
+    assert_eq!(mesh.vertices.len(), 16);
+    assert_eq!(mesh.triangles.len(), 24);
+    assert_eq!(mesh.triangles[12], [8, 10, 9]);
+    assert!(!mesh.has_normals());
+  }
+
+  #[test]
+  fn test_transform() {
+    let mut mesh = cube(1.0);
+    mesh.compute_normals();
+    let [x, y, z] = mesh.normals[6];
+    mesh.transform(&(Matrix3D::translation(Vector3D::new(10.0, 0.0, 0.0)) * Matrix3D::rotation(Vector3D::Z, FRAC_PI_2)));
+    let bbox = mesh.bounding_box().unwrap();
+
+    assert!(bbox.approx_eq_eps(&BoundingBox3D::new(Point3D::new(9.0, 0.0, 0.0), Point3D::new(10.0, 1.0, 1.0)), 1e-6));
+    let normal = mesh.normals[6];
+    assert!((normal[0] + y).abs() < 1e-6);
+    assert!((normal[1] - x).abs() < 1e-6);
+    assert!((normal[2] - z).abs() < 1e-6);
+  }
+
+  #[test]
+  fn test_group_by_material() {
+    let nodes = vec![
+      MeshNode::new("a", Some("Concrete"), cube(1.0)),
+      MeshNode::new("b", None, cube(1.0)),
+      MeshNode::new("c", Some("Concrete"), cube(1.0))
+    ];
+    let groups = MeshNode::group_by_material(&nodes);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name, "Concrete");
+    assert_eq!(groups[0].mesh.triangles.len(), 24);
+    assert_eq!(groups[1].name, "default");
+    assert_eq!(groups[1].material, None);
+  }
+}
@@ -0,0 +1,297 @@
+use std::{
+  fmt::Write as _,
+  io::Write
+};
+use super::MeshNode;
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const TRIANGLES: u32 = 4;
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+/// Writes mesh nodes as binary glTF 2.0 (`.glb`).
+///
+/// Each node becomes a glTF node with its own mesh; nodes share materials by name.
+/// All nodes are children of a root node that converts Renga millimeters with Z axis up to
+/// glTF meters with Y axis up.
+pub fn write_glb<W: Write>(nodes: &[MeshNode], writer: &mut W) -> std::io::Result<()> {
+  let (json, mut bin) = document(nodes, None);
+  let mut json = json.into_bytes();
+  pad(&mut json, b' ');
+  pad(&mut bin, 0);
+  let has_bin = !bin.is_empty();
+  let length = 12 + 8 + json.len() + if has_bin { 8 + bin.len() } else { 0 };
+
+  for value in [GLB_MAGIC, GLB_VERSION, length as u32, json.len() as u32, CHUNK_JSON] {
+    writer.write_all(&value.to_le_bytes())?;
+  }
+  writer.write_all(&json)?;
+  if has_bin {
+    writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+    writer.write_all(&CHUNK_BIN.to_le_bytes())?;
+    writer.write_all(&bin)?;
+  }
+  Ok(())
+}
+
+/// Writes mesh nodes as glTF 2.0 JSON (`.gltf`) with buffer embedded as base64 data URI.
+///
+/// See [write_glb] for the document layout.
+pub fn write_gltf<W: Write>(nodes: &[MeshNode], writer: &mut W) -> std::io::Result<()> {
+  let (_, bin) = document(nodes, None);
+  let uri = format!("data:application/octet-stream;base64,{}", base64(&bin));
+  let (json, _) = document(nodes, Some(&uri));
+  writer.write_all(json.as_bytes())
+}
+
+/// Builds glTF JSON and binary buffer. If `uri` is given, it is used as buffer URI.
+fn document(nodes: &[MeshNode], uri: Option<&str>) -> (String, Vec<u8>) {
+  let mut bin: Vec<u8> = Vec::new();
+  let mut buffer_views = Vec::new();
+  let mut accessors = Vec::new();
+  let mut meshes = Vec::new();
+  let mut gltf_nodes = Vec::new();
+  let mut materials: Vec<&str> = Vec::new();
+
+  let mut push_view = |bin: &mut Vec<u8>, data: &[u8], target: u32| -> usize {
+    buffer_views.push(format!(
+      r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{target}}}"#,
+      bin.len(),
+      data.len()
+    ));
+    bin.extend_from_slice(data);
+    buffer_views.len() - 1
+  };
+
+  for node in nodes {
+    let mesh = &node.mesh;
+    if mesh.is_empty() || mesh.vertices.is_empty() {
+      gltf_nodes.push(format!(r#"{{"name":{}}}"#, string(&node.name)));
+      continue;
+    }
+
+    let positions: Vec<u8> = mesh.vertices.iter().flatten().flat_map(|v| v.to_le_bytes()).collect();
+    let view = push_view(&mut bin, &positions, ARRAY_BUFFER);
+    let (min, max) = bounds(&mesh.vertices);
+    accessors.push(format!(
+      r#"{{"bufferView":{view},"componentType":{FLOAT},"count":{},"type":"VEC3","min":{},"max":{}}}"#,
+      mesh.vertices.len(),
+      array(&min),
+      array(&max)
+    ));
+    let mut attributes = format!(r#""POSITION":{}"#, accessors.len() - 1);
+
+    if mesh.has_normals() {
+      let normals: Vec<u8> = mesh.normals.iter().flatten().flat_map(|v| v.to_le_bytes()).collect();
+      let view = push_view(&mut bin, &normals, ARRAY_BUFFER);
+      accessors.push(format!(
+        r#"{{"bufferView":{view},"componentType":{FLOAT},"count":{},"type":"VEC3"}}"#,
+        mesh.normals.len()
+      ));
+      let _ = write!(attributes, r#","NORMAL":{}"#, accessors.len() - 1);
+    }
+
+    let indices: Vec<u8> = mesh.triangles.iter().flatten().flat_map(|i| i.to_le_bytes()).collect();
+    let view = push_view(&mut bin, &indices, ELEMENT_ARRAY_BUFFER);
+    accessors.push(format!(
+      r#"{{"bufferView":{view},"componentType":{UNSIGNED_INT},"count":{},"type":"SCALAR"}}"#,
+      mesh.triangles.len() * 3
+    ));
+
+    let mut primitive = format!(r#"{{"attributes":{{{attributes}}},"indices":{},"mode":{TRIANGLES}"#, accessors.len() - 1);
+    if let Some(material) = &node.material {
+      let index = match materials.iter().position(|name| name == material) {
+        Some(index) => index,
+        None => {
+          materials.push(material);
+          materials.len() - 1
+        }
+      };
+      let _ = write!(primitive, r#","material":{index}"#);
+    }
+    primitive.push('}');
+
+    meshes.push(format!(r#"{{"name":{},"primitives":[{primitive}]}}"#, string(&node.name)));
+    gltf_nodes.push(format!(r#"{{"name":{},"mesh":{}}}"#, string(&node.name), meshes.len() - 1));
+  }
+
+  let children: Vec<String> = (1..=gltf_nodes.len()).map(|i| i.to_string()).collect();
+  let root = format!(
+    r#"{{"name":"root","rotation":[-0.70710677,0,0,0.70710677],"scale":[0.001,0.001,0.001]{}}}"#,
+    match children.is_empty() {
+      true => String::new(),
+      false => format!(r#","children":[{}]"#, children.join(","))
+    }
+  );
+  gltf_nodes.insert(0, root);
+
+  let materials: Vec<String> = materials
+    .iter()
+    .map(|name| format!(
+      r#"{{"name":{},"pbrMetallicRoughness":{{"metallicFactor":0,"roughnessFactor":1}}}}"#,
+      string(name)
+    ))
+    .collect();
+
+  let mut json = format!(
+    r#"{{"asset":{{"version":"2.0","generator":"{} {}"}},"scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{}]"#,
+    env!("CARGO_PKG_NAME"),
+    env!("CARGO_PKG_VERSION"),
+    gltf_nodes.join(",")
+  );
+  if !meshes.is_empty() {
+    let _ = write!(json, r#","meshes":[{}]"#, meshes.join(","));
+    let _ = write!(json, r#","accessors":[{}]"#, accessors.join(","));
+    let _ = write!(json, r#","bufferViews":[{}]"#, buffer_views.join(","));
+    let _ = match uri {
+      Some(uri) => write!(json, r#","buffers":[{{"byteLength":{},"uri":{}}}]"#, bin.len(), string(uri)),
+      None => write!(json, r#","buffers":[{{"byteLength":{}}}]"#, bin.len())
+    };
+  }
+  if !materials.is_empty() {
+    let _ = write!(json, r#","materials":[{}]"#, materials.join(","));
+  }
+  json.push('}');
+  (json, bin)
+}
+
+fn bounds(vertices: &[[f32; 3]]) -> ([f32; 3], [f32; 3]) {
+  vertices.iter().fold(
+    ([f32::MAX; 3], [f32::MIN; 3]),
+    |(min, max), v| (
+      [min[0].min(v[0]), min[1].min(v[1]), min[2].min(v[2])],
+      [max[0].max(v[0]), max[1].max(v[1]), max[2].max(v[2])]
+    )
+  )
+}
+
+fn array(values: &[f32; 3]) -> String {
+  format!("[{},{},{}]", values[0], values[1], values[2])
+}
+
+/// Encodes string as JSON string literal.
+fn string(value: &str) -> String {
+  let mut result = String::with_capacity(value.len() + 2);
+  result.push('"');
+  for c in value.chars() {
+    match c {
+      '"' => result.push_str("\\\""),
+      '\\' => result.push_str("\\\\"),
+      '\n' => result.push_str("\\n"),
+      '\r' => result.push_str("\\r"),
+      '\t' => result.push_str("\\t"),
+      c if (c as u32) < 0x20 => { let _ = write!(result, "\\u{:04x}", c as u32); },
+      c => result.push(c)
+    }
+  }
+  result.push('"');
+  result
+}
+
+fn base64(data: &[u8]) -> String {
+  const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+  let mut result = String::with_capacity((data.len() + 2) / 3 * 4);
+  for chunk in data.chunks(3) {
+    let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+    let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+    for i in 0..4 {
+      match i <= chunk.len() {
+        true => result.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char),
+        false => result.push('=')
+      }
+    }
+  }
+  result
+}
+
+fn pad(data: &mut Vec<u8>, value: u8) {
+  data.resize((data.len() + 3) & !3, value);
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::mesh::*;
+  use crate::mesh::tests::nodes;
+
+  fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+  }
+
+  #[test]
+  fn test_base64() {
+    assert_eq!(super::base64(b""), "");
+    assert_eq!(super::base64(b"f"), "Zg==");
+    assert_eq!(super::base64(b"fo"), "Zm8=");
+    assert_eq!(super::base64(b"foo"), "Zm9v");
+    assert_eq!(super::base64(b"foobar"), "Zm9vYmFy");
+  }
+
+  #[test]
+  fn test_json_string() {
+    assert_eq!(super::string("a \"b\"\\\n"), r#""a \"b\"\\\n""#);
+  }
+
+  #[test]
+  fn test_write_glb() -> std::io::Result<()> {
+    let mut data = Vec::new();
+    write_glb(&nodes(), &mut data)?;
+
+    assert_eq!(read_u32(&data, 0), 0x46546C67);
+    assert_eq!(read_u32(&data, 4), 2);
+    assert_eq!(read_u32(&data, 8) as usize, data.len());
+    let json_length = read_u32(&data, 12) as usize;
+    assert_eq!(json_length % 4, 0);
+    assert_eq!(read_u32(&data, 16), 0x4E4F534A);
+
+    let json = std::str::from_utf8(&data[20..20 + json_length]).unwrap();
+    assert!(json.contains(r#""asset":{"version":"2.0""#));
+    assert!(json.contains(r#""materials":[{"name":"Concrete""#));
+    assert!(json.contains(r#""min":[0,0,0],"max":[1000,1000,1000]"#));
+
+    // cube: 8 * 12 + 36 * 4, triangle: 3 * 12 + 3 * 4
+    let bin = 20 + json_length;
+    assert_eq!(read_u32(&data, bin), 96 + 144 + 36 + 12);
+    assert_eq!(read_u32(&data, bin + 4), 0x004E4942);
+    assert_eq!(data.len(), bin + 8 + 288);
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_gltf() -> std::io::Result<()> {
+    let mut data = Vec::new();
+    write_gltf(&nodes(), &mut data)?;
+    let json = String::from_utf8(data).unwrap();
+
+    assert!(json.starts_with('{') && json.ends_with('}'));
+    assert!(json.contains(r#""uri":"data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAAB6RAAAAAAAAAAA"#));
+    assert!(json.contains(r#""children":[1,2]"#));
+    Ok(())
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_gltf_structure() -> std::io::Result<()> {
+    let mut nodes = nodes();
+    nodes[1].mesh.compute_normals();
+    nodes.push(MeshNode::new("empty", None, Mesh::default()));
+    let mut data = Vec::new();
+    write_gltf(&nodes, &mut data)?;
+    let json: serde_json::Value = serde_json::from_slice(&data).unwrap();
+
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 4);
+    assert_eq!(json["meshes"].as_array().unwrap().len(), 2);
+    assert_eq!(json["accessors"].as_array().unwrap().len(), 5);
+    assert_eq!(json["accessors"][3]["count"], 3);
+    assert_eq!(json["meshes"][1]["primitives"][0]["attributes"]["NORMAL"], 3);
+    assert_eq!(json["meshes"][0]["primitives"][0]["material"], 0);
+    assert_eq!(json["nodes"][3]["name"], "empty");
+    assert!(json["nodes"][3].get("mesh").is_none());
+    Ok(())
+  }
+}
@@ -0,0 +1,128 @@
+//! Triangle meshes and writers for common 3D formats.
+//!
+//! Meshes of model objects can be obtained with [crate::DataExporter] and written to
+//! glTF 2.0 (binary or JSON), Wavefront OBJ or binary STL:
+//! ```no_run
+//! use renga_api_rs as renga;
+//! use renga::mesh::{MeshFormat, MeshGrouping};
+//!
+//! let mut app = renga::Application::new().unwrap();
+//! let project = app.project().unwrap();
+//! let nodes = project.data_exporter().unwrap().mesh_nodes(&project, MeshGrouping::PerObject).unwrap();
+//! renga::mesh::write_file(&nodes, std::path::Path::new("model.glb"), MeshFormat::Glb).unwrap();
+//! ```
+//!
+//! Coordinates are in millimeters, as in Renga, with Z axis pointing up. glTF writer converts
+//! them to meters with Y axis pointing up, as required by the specification.
+
+mod data;
+mod gltf;
+mod obj;
+mod stl;
+
+use std::{
+  fs::File,
+  io::{
+    BufWriter,
+    Write
+  },
+  path::Path
+};
+
+pub use data::{
+  Mesh,
+  MeshGrouping,
+  MeshNode
+};
+pub use gltf::{
+  write_glb,
+  write_gltf
+};
+pub use obj::write_obj;
+pub use stl::write_stl;
+
+/// Supported mesh file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeshFormat {
+  /// Binary glTF 2.0 (`.glb`).
+  Glb,
+
+  /// glTF 2.0 JSON with embedded buffer (`.gltf`).
+  Gltf,
+
+  /// Wavefront OBJ (`.obj`).
+  Obj,
+
+  /// Binary STL (`.stl`).
+  Stl
+}
+
+impl MeshFormat {
+  /// Guesses format from file extension.
+  pub fn from_path(path: &Path) -> Option<Self> {
+    match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+      "glb" => Some(Self::Glb),
+      "gltf" => Some(Self::Gltf),
+      "obj" => Some(Self::Obj),
+      "stl" => Some(Self::Stl),
+      _ => None
+    }
+  }
+}
+
+/// Writes mesh nodes to writer in given format.
+pub fn write<W: Write>(nodes: &[MeshNode], writer: &mut W, format: MeshFormat) -> std::io::Result<()> {
+  match format {
+    MeshFormat::Glb => write_glb(nodes, writer),
+    MeshFormat::Gltf => write_gltf(nodes, writer),
+    MeshFormat::Obj => write_obj(nodes, writer),
+    MeshFormat::Stl => write_stl(nodes, writer)
+  }
+}
+
+/// Writes mesh nodes to file in given format, overwriting existing file.
+pub fn write_file(nodes: &[MeshNode], path: &Path, format: MeshFormat) -> std::io::Result<()> {
+  let mut writer = BufWriter::new(File::create(path)?);
+  write(nodes, &mut writer, format)?;
+  writer.flush()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use crate::mesh::*;
+
+  /// Axis-aligned cube with given size, 8 vertices and 12 triangles.
+  pub fn cube(size: f32) -> Mesh {
+    let s = size;
+    let vertices = vec![
+      [0.0, 0.0, 0.0], [s, 0.0, 0.0], [s, s, 0.0], [0.0, s, 0.0],
+      [0.0, 0.0, s], [s, 0.0, s], [s, s, s], [0.0, s, s]
+    ];
+    let triangles = vec![
+      [0, 2, 1], [0, 3, 2],
+      [4, 5, 6], [4, 6, 7],
+      [0, 1, 5], [0, 5, 4],
+      [1, 2, 6], [1, 6, 5],
+      [2, 3, 7], [2, 7, 6],
+      [3, 0, 4], [3, 4, 7]
+    ];
+    Mesh::new(vertices, triangles)
+  }
+
+  pub fn nodes() -> Vec<MeshNode> {
+    vec![
+      MeshNode::new("cube", Some("Concrete"), cube(1000.0)),
+      MeshNode::new("triangle", None, Mesh::new(
+        vec![[0.0, 0.0, 0.0], [1000.0, 0.0, 0.0], [0.0, 1000.0, 0.0]],
+        vec![[0, 1, 2]]
+      ))
+    ]
+  }
+
+  #[test]
+  fn test_format_from_path() {
+    assert_eq!(MeshFormat::from_path(std::path::Path::new("model.GLB")), Some(MeshFormat::Glb));
+    assert_eq!(MeshFormat::from_path(std::path::Path::new("model.stl")), Some(MeshFormat::Stl));
+    assert_eq!(MeshFormat::from_path(std::path::Path::new("model.fbx")), None);
+  }
+}
@@ -0,0 +1,83 @@
+use std::io::Write;
+use super::MeshNode;
+
+/// Writes mesh nodes as Wavefront OBJ.
+///
+/// Each node becomes an object (`o`) with material reference (`usemtl`), if node has material.
+/// Normals are written only for meshes that have them.
+pub fn write_obj<W: Write>(nodes: &[MeshNode], writer: &mut W) -> std::io::Result<()> {
+  writeln!(writer, "# {} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))?;
+  let mut offset = 1;
+  let mut normal_offset = 1;
+  for node in nodes {
+    let mesh = &node.mesh;
+    writeln!(writer, "o {}", sanitize(&node.name))?;
+    if let Some(material) = &node.material {
+      writeln!(writer, "usemtl {}", sanitize(material))?;
+    }
+    for [x, y, z] in &mesh.vertices {
+      writeln!(writer, "v {x} {y} {z}")?;
+    }
+    if mesh.has_normals() {
+      for [x, y, z] in &mesh.normals {
+        writeln!(writer, "vn {x} {y} {z}")?;
+      }
+    }
+    for triangle in &mesh.triangles {
+      let [a, b, c] = triangle.map(|index| index + offset);
+      match mesh.has_normals() {
+        true => {
+          let [na, nb, nc] = triangle.map(|index| index + normal_offset);
+          writeln!(writer, "f {a}//{na} {b}//{nb} {c}//{nc}")?
+        },
+        false => writeln!(writer, "f {a} {b} {c}")?
+      }
+    }
+    offset += mesh.vertices.len() as u32;
+    if mesh.has_normals() {
+      normal_offset += mesh.normals.len() as u32;
+    }
+  }
+  Ok(())
+}
+
+/// OBJ names can not contain whitespace.
+fn sanitize(name: &str) -> String {
+  name.replace(char::is_whitespace, "_")
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::mesh::*;
+  use crate::mesh::tests::nodes;
+
+  #[test]
+  fn test_write_obj() -> std::io::Result<()> {
+    let mut nodes = nodes();
+    nodes[1].mesh.compute_normals();
+    nodes[1].name = "second node".to_owned();
+    let mut buffer = Vec::new();
+    write_obj(&nodes, &mut buffer)?;
+    let text = String::from_utf8(buffer).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    let count = |prefix: &str| lines.iter().filter(|line| line.starts_with(prefix)).count();
+
+    assert_eq!(count("o "), 2);
+    assert_eq!(count("v "), 11);
+    assert_eq!(count("vn "), 3);
+    assert_eq!(count("f "), 13);
+    assert!(lines.contains(&"usemtl Concrete"));
+    assert!(lines.contains(&"o second_node"));
+    assert!(lines.contains(&"v 1000 1000 1000"));
+    assert!(lines.contains(&"f 1 3 2"));
+    assert_eq!(lines.last(), Some(&"f 9//1 10//2 11//3"));
+
+    nodes[0].mesh.compute_normals();
+    let mut buffer = Vec::new();
+    write_obj(&nodes, &mut buffer)?;
+    let text = String::from_utf8(buffer).unwrap();
+    assert_eq!(text.lines().filter(|line| line.starts_with("vn ")).count(), 11);
+    assert_eq!(text.lines().last(), Some("f 9//9 10//10 11//11"));
+    Ok(())
+  }
+}
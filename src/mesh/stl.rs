@@ -0,0 +1,67 @@
+use std::io::Write;
+use super::MeshNode;
+
+/// Writes all mesh nodes as a single binary STL solid.
+///
+/// STL has no notion of objects or materials, so all triangles are merged. Facet normals are
+/// computed from triangle vertices.
+pub fn write_stl<W: Write>(nodes: &[MeshNode], writer: &mut W) -> std::io::Result<()> {
+  let mut header = [0u8; 80];
+  let title = format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+  header[..title.len()].copy_from_slice(title.as_bytes());
+  writer.write_all(&header)?;
+
+  let count: usize = nodes.iter().map(|node| node.mesh.triangles.len()).sum();
+  writer.write_all(&(count as u32).to_le_bytes())?;
+  for node in nodes {
+    let mesh = &node.mesh;
+    for (index, triangle) in mesh.triangles.iter().enumerate() {
+      let vertices = triangle.map(|i| mesh.vertices[i as usize]);
+      for value in mesh.face_normal(index).iter().chain(vertices.iter().flatten()) {
+        writer.write_all(&value.to_le_bytes())?;
+      }
+      writer.write_all(&0u16.to_le_bytes())?;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::mesh::*;
+  use crate::mesh::tests::nodes;
+
+  fn read_f32(data: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+  }
+
+  #[test]
+  fn test_write_stl() -> std::io::Result<()> {
+    let mut data = Vec::new();
+    write_stl(&nodes(), &mut data)?;
+
+    assert_eq!(data.len(), 84 + 50 * 13);
+    assert!(data.starts_with(env!("CARGO_PKG_NAME").as_bytes()));
+    assert_eq!(u32::from_le_bytes(data[80..84].try_into().unwrap()), 13);
+
+    // first facet: bottom face of the cube, pointing down
+    assert_eq!(read_f32(&data, 84 + 8), -1.0);
+    // second vertex of the first facet is (1000, 1000, 0)
+    assert_eq!(read_f32(&data, 84 + 24), 1000.0);
+    assert_eq!(read_f32(&data, 84 + 28), 1000.0);
+    // last facet: triangle in XY plane, pointing up
+    let last = 84 + 50 * 12;
+    assert_eq!(read_f32(&data, last + 8), 1.0);
+    assert_eq!(read_f32(&data, last + 24), 1000.0);
+    Ok(())
+  }
+
+  #[test]
+  fn test_empty() -> std::io::Result<()> {
+    let mut data = Vec::new();
+    write_stl(&[], &mut data)?;
+
+    assert_eq!(data.len(), 84);
+    Ok(())
+  }
+}
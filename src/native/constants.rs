@@ -1,2 +1,101 @@
 pub const LOCALE_USER_DEFAULT: u32 = 0x0400;
-pub const LOCALE_SYSTEM_DEFAULT: u32 = 0x0800;
\ No newline at end of file
+pub const LOCALE_SYSTEM_DEFAULT: u32 = 0x0800;
+
+// Renga unit enumerations used to read and write dimensioned values.
+// Values are always exchanged in these base units and converted on Rust side.
+// See https://help.rengabim.com/api/group___length_units.html, group___area_units.html,
+// group___volume_units.html, group___mass_units.html and group___angle_units.html.
+pub const LENGTH_UNIT_MILLIMETERS: i32 = 2;
+pub const AREA_UNIT_METERS2: i32 = 0;
+pub const VOLUME_UNIT_METERS3: i32 = 0;
+pub const MASS_UNIT_KILOGRAMS: i32 = 0;
+pub const ANGLE_UNIT_DEGREES: i32 = 0;
+
+// Renga `ParameterValueType` enumeration.
+pub const PARAMETER_TYPE_BOOL: i32 = 1;
+pub const PARAMETER_TYPE_INT: i32 = 2;
+pub const PARAMETER_TYPE_DOUBLE: i32 = 3;
+pub const PARAMETER_TYPE_STRING: i32 = 4;
+pub const PARAMETER_TYPE_ID: i32 = 5;
+pub const PARAMETER_TYPE_ENUMERATION: i32 = 6;
+
+// Renga `PropertyType` enumeration.
+pub const PROPERTY_TYPE_DOUBLE: i32 = 1;
+pub const PROPERTY_TYPE_STRING: i32 = 2;
+pub const PROPERTY_TYPE_ANGLE: i32 = 3;
+pub const PROPERTY_TYPE_AREA: i32 = 4;
+pub const PROPERTY_TYPE_BOOLEAN: i32 = 5;
+pub const PROPERTY_TYPE_ENUMERATION: i32 = 6;
+pub const PROPERTY_TYPE_INTEGER: i32 = 7;
+pub const PROPERTY_TYPE_LENGTH: i32 = 8;
+pub const PROPERTY_TYPE_LOGICAL: i32 = 9;
+pub const PROPERTY_TYPE_MASS: i32 = 10;
+pub const PROPERTY_TYPE_VOLUME: i32 = 11;
+
+// Renga `Logical` enumeration.
+pub const LOGICAL_FALSE: i32 = 0;
+pub const LOGICAL_TRUE: i32 = 1;
+pub const LOGICAL_UNKNOWN: i32 = 2;
+
+// Renga `QuantityType` enumeration.
+pub const QUANTITY_TYPE_LENGTH: i32 = 1;
+pub const QUANTITY_TYPE_AREA: i32 = 2;
+pub const QUANTITY_TYPE_VOLUME: i32 = 3;
+pub const QUANTITY_TYPE_MASS: i32 = 4;
+pub const QUANTITY_TYPE_COUNT: i32 = 5;
+
+// Renga `Curve2DType` enumeration.
+pub const CURVE2D_TYPE_LINE_SEGMENT: i32 = 1;
+pub const CURVE2D_TYPE_ARC: i32 = 2;
+pub const CURVE2D_TYPE_POLY_CURVE: i32 = 3;
+
+// Renga result codes returned by file operations (export, save, import), e.g. by
+// `IProject::ExportDrawingsToPdfS` and `IProject::ExportToCsv`.
+// See https://help.rengabim.com/api/interface_i_project.html
+pub const RESULT_OK: i32 = 0;
+pub const RESULT_FAILED: i32 = 1;
+pub const RESULT_FILE_ALREADY_EXISTS: i32 = 2;
+pub const RESULT_ACCESS_DENIED: i32 = 3;
+pub const RESULT_INVALID_PATH: i32 = 4;
+pub const RESULT_NOTHING_TO_EXPORT: i32 = 5;
+pub const RESULT_CANCELLED: i32 = 6;
+
+// Renga `IfcVersion` enumeration.
+pub const IFC_VERSION_2X3: i32 = 0;
+pub const IFC_VERSION_4: i32 = 1;
+pub const IFC_VERSION_4X3: i32 = 2;
+
+// Renga `ProjectType` enumeration.
+pub const PROJECT_TYPE_PROJECT: i32 = 0;
+pub const PROJECT_TYPE_TEMPLATE: i32 = 1;
+
+// Renga `ViewType` enumeration.
+pub const VIEW_TYPE_LEVEL: i32 = 1;
+pub const VIEW_TYPE_VIEW_3D: i32 = 2;
+pub const VIEW_TYPE_SECTION: i32 = 3;
+pub const VIEW_TYPE_FACADE: i32 = 4;
+pub const VIEW_TYPE_DRAWING: i32 = 5;
+pub const VIEW_TYPE_ASSEMBLY: i32 = 6;
+pub const VIEW_TYPE_TABLE: i32 = 7;
+
+// Renga `ImageFormat` enumeration.
+pub const IMAGE_FORMAT_PNG: i32 = 0;
+
+// Renga `ContextMenuShowCase` enumeration.
+pub const CONTEXT_MENU_SHOW_CASE_SCENE: i32 = 0;
+pub const CONTEXT_MENU_SHOW_CASE_SELECTION: i32 = 1;
+
+// Renga `FlowDirection` enumeration.
+pub const FLOW_DIRECTION_INLET: i32 = 1;
+pub const FLOW_DIRECTION_OUTLET: i32 = 2;
+pub const FLOW_DIRECTION_INLET_AND_OUTLET: i32 = 3;
+
+// Renga `SystemCategory` enumeration.
+pub const SYSTEM_CATEGORY_DOMESTIC_COLD_WATER: i32 = 1;
+pub const SYSTEM_CATEGORY_DOMESTIC_HOT_WATER: i32 = 2;
+pub const SYSTEM_CATEGORY_HEATING: i32 = 3;
+pub const SYSTEM_CATEGORY_DOMESTIC_SEWAGE: i32 = 4;
+pub const SYSTEM_CATEGORY_FIRE_WATER: i32 = 5;
+pub const SYSTEM_CATEGORY_SUPPLY_AIR: i32 = 6;
+pub const SYSTEM_CATEGORY_EXHAUST_AIR: i32 = 7;
+pub const SYSTEM_CATEGORY_ELECTRICAL: i32 = 8;
@@ -1,27 +1,43 @@
 use windows::{
   core::{
     Interface, 
+    BSTR,
     GUID, 
     HSTRING, 
     PCWSTR, 
     VARIANT
   }, 
-  Win32::System::{
-    Com::{
-      CoCreateInstance, 
-      IDispatch, 
-      CLSCTX_INPROC_SERVER, 
-      CLSCTX_LOCAL_SERVER, 
-      DISPATCH_FLAGS,
-      DISPATCH_PROPERTYGET, 
-      DISPATCH_PROPERTYPUT, 
-      DISPATCH_METHOD,
-      DISPPARAMS
-    }, 
-    Ole::DISPID_PROPERTYPUT
+  Win32::{
+    Foundation::E_NOINTERFACE,
+    System::{
+      Com::{
+        CoCreateInstance, 
+        IDispatch, 
+        CLSCTX_INPROC_SERVER, 
+        CLSCTX_LOCAL_SERVER, 
+        DISPATCH_FLAGS,
+        DISPATCH_PROPERTYGET, 
+        DISPATCH_PROPERTYPUT, 
+        DISPATCH_METHOD,
+        DISPPARAMS,
+        TYPEDESC
+      }, 
+      Ole::{
+        GetRecordInfoFromTypeInfo,
+        IRecordInfo,
+        DISPID_PROPERTYPUT
+      },
+      Variant::{
+        VT_PTR,
+        VT_USERDEFINED
+      }
+    }
   }
 };
-use crate::Result;
+use crate::{
+  Result,
+  Error
+};
 use super::{
   constants, 
   ClassID, 
@@ -84,6 +100,58 @@ impl Dispatch {
     self.invoke(DISPATCH_METHOD, name, args)
   }
 
+  /// Queries interface of Renga object by its name using `GetInterfaceByName` method.
+  ///
+  /// Returns `None` only if object does not support requested interface. Other failures are propagated.
+  pub fn query_interface(&self, name: &str) -> Result<Option<Self>> {
+    match self.call("GetInterfaceByName", Some(vec![name.into()])) {
+      Ok(variant) => variant.as_optional_dispatch(),
+      Err(Error::WinApi(e)) if e.code() == E_NOINTERFACE => Ok(None),
+      Err(e) => Err(e)
+    }
+  }
+
+  /// Wraps `guid` into a variant for `GUID` parameter number `index` of `method`.
+  ///
+  /// Such parameters are passed as `VT_RECORD`, record description is taken from type library
+  /// of the object.
+  pub fn guid_argument(&self, method: &str, index: usize, guid: &GUID) -> Result<Variant> {
+    unsafe {
+      let record_info = self.parameter_record_info(method, index)?;
+      Variant::from_record(guid, record_info)
+    }
+  }
+
+  unsafe fn parameter_record_info(&self, method: &str, index: usize) -> Result<IRecordInfo> {
+    let info = self.0.GetTypeInfo(0, constants::LOCALE_USER_DEFAULT)?;
+    let attributes = info.GetTypeAttr()?;
+    let count = (*attributes).cFuncs;
+    info.ReleaseTypeAttr(attributes);
+    for function in 0..count {
+      let description = info.GetFuncDesc(function as u32)?;
+      let mut names = [BSTR::default()];
+      let mut fetched = 0;
+      info.GetNames((*description).memid, &mut names, &mut fetched)?;
+      let parameter: Option<TYPEDESC> = match method == names[0] && index < (*description).cParams.max(0) as usize {
+        true => Some((*(*description).lprgelemdescParam.add(index)).tdesc),
+        false => None
+      };
+      info.ReleaseFuncDesc(description);
+      let Some(mut parameter) = parameter else {
+        continue;
+      };
+      if parameter.vt == VT_PTR {
+        parameter = *parameter.Anonymous.lptdesc;
+      }
+      if parameter.vt != VT_USERDEFINED {
+        return Err(Error::TypeMismatch(format!("Parameter {index} of {method} is not a record")));
+      }
+      let record = info.GetRefTypeInfo(parameter.Anonymous.hreftype)?;
+      return Ok(GetRecordInfoFromTypeInfo(&record)?);
+    }
+    Err(Error::Internal(format!("Method {method} is not described in type information")))
+  }
+
   fn invoke(&self, flags: DISPATCH_FLAGS, name: &str, mut args: Vec<Variant>) -> Result<Variant> {
     unsafe {
       let mut disp_id = 0;
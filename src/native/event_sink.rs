@@ -0,0 +1,246 @@
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  ffi::c_void,
+  mem::MaybeUninit,
+  panic::{
+    catch_unwind,
+    AssertUnwindSafe
+  },
+  sync::atomic::{
+    AtomicU32,
+    Ordering
+  }
+};
+use windows::{
+  core::{
+    BSTR,
+    IUnknown,
+    IUnknown_Vtbl,
+    Interface,
+    GUID,
+    HRESULT,
+    PCWSTR,
+    VARIANT
+  },
+  Win32::{
+    Foundation::{
+      DISP_E_UNKNOWNNAME,
+      E_FAIL,
+      E_NOINTERFACE,
+      E_NOTIMPL,
+      E_POINTER,
+      S_OK
+    },
+    System::Com::{
+      IConnectionPoint,
+      IConnectionPointContainer,
+      IDispatch,
+      IDispatch_Vtbl,
+      ITypeInfo,
+      ITypeLib,
+      DISPATCH_FLAGS,
+      DISPPARAMS,
+      EXCEPINFO
+    }
+  }
+};
+use crate::{
+  Result,
+  Error
+};
+use super::{
+  constants,
+  Dispatch,
+  Variant
+};
+
+/// Callback of [EventSink]: receives name of the event (e.g. `OnTriggered`) and its arguments
+/// in declaration order.
+pub type EventCallback = Box<dyn FnMut(&str, &[Variant])>;
+
+/// Minimal `IDispatch` implementation, which forwards every `Invoke` to a callback.
+///
+/// Renga raises events through connection points with dispinterfaces, so the sink
+/// answers `QueryInterface` for the outgoing interface of the connection point as well.
+/// Callback is kept in a [RefCell]: events raised from inside the callback are dropped
+/// instead of aliasing it.
+#[repr(C)]
+struct EventSink {
+  vtable: *const IDispatch_Vtbl,
+  refs: AtomicU32,
+  iid: GUID,
+  events: HashMap<i32, String>,
+  callback: RefCell<EventCallback>
+}
+
+static VTABLE: IDispatch_Vtbl = IDispatch_Vtbl {
+  base__: IUnknown_Vtbl {
+    QueryInterface: query_interface,
+    AddRef: add_ref,
+    Release: release
+  },
+  GetTypeInfoCount: get_type_info_count,
+  GetTypeInfo: get_type_info,
+  GetIDsOfNames: get_ids_of_names,
+  Invoke: invoke
+};
+
+unsafe extern "system" fn query_interface(this: *mut c_void, iid: *const GUID, out: *mut *mut c_void) -> HRESULT {
+  if iid.is_null() || out.is_null() {
+    return E_POINTER;
+  }
+  let sink = &*(this as *const EventSink);
+  if *iid == IUnknown::IID || *iid == IDispatch::IID || *iid == sink.iid {
+    add_ref(this);
+    *out = this;
+    return S_OK;
+  }
+  *out = std::ptr::null_mut();
+  E_NOINTERFACE
+}
+
+unsafe extern "system" fn add_ref(this: *mut c_void) -> u32 {
+  (*(this as *const EventSink)).refs.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+unsafe extern "system" fn release(this: *mut c_void) -> u32 {
+  let refs = (*(this as *const EventSink)).refs.fetch_sub(1, Ordering::Release) - 1;
+  if refs == 0 {
+    drop(Box::from_raw(this as *mut EventSink));
+  }
+  refs
+}
+
+unsafe extern "system" fn get_type_info_count(_this: *mut c_void, count: *mut u32) -> HRESULT {
+  if count.is_null() {
+    return E_POINTER;
+  }
+  *count = 0;
+  S_OK
+}
+
+unsafe extern "system" fn get_type_info(_this: *mut c_void, _index: u32, _lcid: u32, _info: *mut *mut c_void) -> HRESULT {
+  E_NOTIMPL
+}
+
+unsafe extern "system" fn get_ids_of_names(
+  _this: *mut c_void,
+  _iid: *const GUID,
+  _names: *const PCWSTR,
+  _count: u32,
+  _lcid: u32,
+  _ids: *mut i32
+) -> HRESULT {
+  DISP_E_UNKNOWNNAME
+}
+
+unsafe extern "system" fn invoke(
+  this: *mut c_void,
+  disp_id: i32,
+  _iid: *const GUID,
+  _lcid: u32,
+  _flags: DISPATCH_FLAGS,
+  params: *const DISPPARAMS,
+  _result: *mut MaybeUninit<VARIANT>,
+  _exception: *mut EXCEPINFO,
+  _arg_err: *mut u32
+) -> HRESULT {
+  let sink = &*(this as *const EventSink);
+  let Some(event) = sink.events.get(&disp_id) else {
+    log::warn!("event sink: unknown DISPID {disp_id}, ignored");
+    return S_OK;
+  };
+  let Ok(mut callback) = sink.callback.try_borrow_mut() else {
+    log::warn!("event sink: event {event} raised from its own callback, ignored");
+    return S_OK;
+  };
+  let mut args: Vec<Variant> = match params.as_ref() {
+    Some(params) if params.cArgs > 0 && !params.rgvarg.is_null() => {
+      std::slice::from_raw_parts(params.rgvarg, params.cArgs as usize)
+        .iter()
+        .map(Variant::from)
+        .collect()
+    },
+    _ => Vec::new()
+  };
+  args.reverse();
+  match catch_unwind(AssertUnwindSafe(|| (callback)(event, &args))) {
+    Ok(()) => S_OK,
+    Err(_) => {
+      log::error!("event sink: callback for {event} panicked");
+      E_FAIL
+    }
+  }
+}
+
+/// Reads DISPIDs and names of the events of outgoing interface `iid` from type library of `source`.
+unsafe fn event_names(source: &Dispatch, iid: &GUID) -> Result<HashMap<i32, String>> {
+  let mut library: Option<ITypeLib> = None;
+  let mut index = 0;
+  source
+    .as_raw()
+    .GetTypeInfo(0, constants::LOCALE_USER_DEFAULT)?
+    .GetContainingTypeLib(&mut library, &mut index)?;
+  let info: ITypeInfo = library
+    .ok_or_else(|| Error::Internal("Event source has no type library".to_owned()))?
+    .GetTypeInfoOfGuid(iid)?;
+  let attributes = info.GetTypeAttr()?;
+  let count = (*attributes).cFuncs;
+  info.ReleaseTypeAttr(attributes);
+  let mut events = HashMap::new();
+  for index in 0..count {
+    let description = info.GetFuncDesc(index as u32)?;
+    let id = (*description).memid;
+    info.ReleaseFuncDesc(description);
+    let mut names = [BSTR::default()];
+    let mut fetched = 0;
+    info.GetNames(id, &mut names, &mut fetched)?;
+    events.insert(id, names[0].to_string());
+  }
+  Ok(events)
+}
+
+/// Subscription of a callback to events of a COM object. Unsubscribes on drop.
+pub struct EventConnection {
+  point: IConnectionPoint,
+  cookie: u32
+}
+
+impl EventConnection {
+  /// Subscribes `callback` to events of `source`.
+  ///
+  /// Source must implement `IConnectionPointContainer`. The first connection point of
+  /// the source is used, Renga objects expose only one. DISPIDs of the events are
+  /// resolved to names using type library of the source.
+  pub fn connect(source: &Dispatch, callback: EventCallback) -> Result<Self> {
+    unsafe {
+      let container: IConnectionPointContainer = source.as_raw().cast()?;
+      let points = container.EnumConnectionPoints()?;
+      let mut fetched = [None];
+      points.Next(&mut fetched, std::ptr::null_mut()).ok()?;
+      let point = fetched[0]
+        .take()
+        .ok_or_else(|| Error::Internal("Object does not raise events".to_owned()))?;
+      let iid = point.GetConnectionInterface()?;
+      let sink = Box::into_raw(Box::new(EventSink {
+        vtable: &VTABLE,
+        refs: AtomicU32::new(1),
+        events: event_names(source, &iid)?,
+        iid,
+        callback: RefCell::new(callback)
+      }));
+      let sink = IUnknown::from_raw(sink as *mut c_void);
+      let cookie = point.Advise(&sink)?;
+      Ok(Self { point, cookie })
+    }
+  }
+}
+
+impl Drop for EventConnection {
+  fn drop(&mut self) {
+    if let Err(e) = unsafe { self.point.Unadvise(self.cookie) } {
+      log::error!("event sink: failed to disconnect: {e}");
+    }
+  }
+}
@@ -1,11 +1,13 @@
 mod class_id;
 mod dispatch;
+mod event_sink;
 mod variant;
-mod constants;
 
+pub(crate) mod constants;
 pub mod runtime;
 pub mod records;
 
 pub use class_id::ClassID;
 pub use dispatch::Dispatch;
+pub use event_sink::EventConnection;
 pub use variant::Variant;
\ No newline at end of file
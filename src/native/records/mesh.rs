@@ -0,0 +1,46 @@
+use crate::{
+  Result,
+  native::Variant
+};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FloatPoint3D {
+  pub x: f32,
+  pub y: f32,
+  pub z: f32
+}
+
+impl FloatPoint3D {
+  pub unsafe fn from_variant(variant: &Variant) -> Result<Self> {
+    variant.as_record_unchecked::<Self>()
+  }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FloatVector3D {
+  pub x: f32,
+  pub y: f32,
+  pub z: f32
+}
+
+impl FloatVector3D {
+  pub unsafe fn from_variant(variant: &Variant) -> Result<Self> {
+    variant.as_record_unchecked::<Self>()
+  }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Triangle {
+  pub v0: u32,
+  pub v1: u32,
+  pub v2: u32
+}
+
+impl Triangle {
+  pub unsafe fn from_variant(variant: &Variant) -> Result<Self> {
+    variant.as_record_unchecked::<Self>()
+  }
+}
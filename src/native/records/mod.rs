@@ -1,3 +1,19 @@
 mod version;
+mod point;
+mod vector;
+mod mesh;
 
-pub use version::Version;
\ No newline at end of file
+pub use version::Version;
+pub use point::{
+  Point2D,
+  Point3D
+};
+pub use vector::{
+  Vector2D,
+  Vector3D
+};
+pub use mesh::{
+  FloatPoint3D,
+  FloatVector3D,
+  Triangle
+};
\ No newline at end of file
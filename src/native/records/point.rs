@@ -0,0 +1,30 @@
+use crate::{
+  Result,
+  native::Variant
+};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Point3D {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64
+}
+
+impl Point3D {
+  pub unsafe fn from_variant(variant: &Variant) -> Result<Self> {
+    variant.as_record_unchecked::<Self>()
+  }
+}
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Point2D {
+  pub x: f64,
+  pub y: f64
+}
+
+impl Point2D {
+  pub unsafe fn from_variant(variant: &Variant) -> Result<Self> {
+    variant.as_record_unchecked::<Self>()
+  }
+}
@@ -4,12 +4,42 @@ use std::{
 };
 use windows::{
   core::{
+    Interface,
+    IUnknown,
     BSTR,
+    GUID,
+    HSTRING,
+    PCWSTR,
     VARIANT
   },
-  Win32::System::Com::IDispatch
+  Win32::System::{
+    Com::{
+      IDispatch,
+      SAFEARRAY
+    },
+    Ole::{
+      IRecordInfo,
+      SafeArrayAccessData,
+      SafeArrayGetLBound,
+      SafeArrayGetUBound,
+      SafeArrayUnaccessData
+    },
+    Variant::{
+      InitVariantFromBuffer,
+      InitVariantFromInt32Array,
+      InitVariantFromStringArray,
+      VT_ARRAY,
+      VT_DISPATCH,
+      VT_EMPTY,
+      VT_NULL,
+      VT_RECORD
+    }
+  }
+};
+use crate::{
+  Result,
+  Error
 };
-use crate::Result;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Variant(VARIANT);
@@ -39,8 +69,23 @@ impl From<f64> for Variant { fn from(value: f64) -> Self { Self(VARIANT::from(va
 impl From<&str> for Variant { fn from(value: &str) -> Self { Self(BSTR::from(value).into()) } }
 impl From<&String> for Variant { fn from(value: &String) -> Self { Self(BSTR::from(value).into()) } }
 impl From<String> for Variant { fn from(value: String) -> Self { Self(BSTR::from(value).into()) } }
+impl From<super::Dispatch> for Variant { fn from(value: super::Dispatch) -> Self { Self(IUnknown::from(value.into_raw()).into()) } }
 
 impl Variant {
+  pub fn from_ints(values: &[i32]) -> Result<Self> { 
+    Ok(Self(unsafe { InitVariantFromInt32Array(values)? })) 
+  }
+
+  pub fn from_bytes(values: &[u8]) -> Result<Self> {
+    Ok(Self(unsafe { InitVariantFromBuffer(values.as_ptr() as *const c_void, values.len() as u32)? }))
+  }
+
+  pub fn from_strings(values: &[String]) -> Result<Self> {
+    let wide: Vec<HSTRING> = values.iter().map(HSTRING::from).collect();
+    let raw: Vec<PCWSTR> = wide.iter().map(|value| PCWSTR::from_raw(value.as_ptr())).collect();
+    Ok(Self(unsafe { InitVariantFromStringArray(&raw)? }))
+  }
+
   pub fn as_bool(&self) -> Result<bool> { Ok(bool::try_from(&self.0)?) }
   pub fn as_u16(&self) -> Result<u16> { Ok(u16::try_from(&self.0)?) }
   pub fn as_u32(&self) -> Result<u32> { Ok(u32::try_from(&self.0)?) }
@@ -53,6 +98,27 @@ impl Variant {
   pub fn as_unsigned(&self) -> Result<u32> { Ok(u32::try_from(&self.0)?) }
   pub fn as_double(&self) -> Result<f64> { Ok(f64::try_from(&self.0)?) }
 
+  /// Creates `VT_RECORD` variant holding a copy of `value`, which is described by `record_info`.
+  pub unsafe fn from_record<T>(value: &T, record_info: IRecordInfo) -> Result<Self> {
+    // Layout of `VARIANT` with `BRECORD` payload.
+    #[repr(C)]
+    struct RecordVariant {
+      vt: u16,
+      reserved: [u16; 3],
+      record: *mut c_void,
+      record_info: *mut c_void
+    }
+    let mut record = std::ptr::null_mut();
+    record_info.RecordCreateCopy(value as *const T as *const c_void, &mut record)?;
+    let raw = RecordVariant {
+      vt: VT_RECORD.0,
+      reserved: [0; 3],
+      record,
+      record_info: record_info.into_raw()
+    };
+    Ok(Self(std::mem::transmute::<RecordVariant, VARIANT>(raw)))
+  }
+
   pub unsafe fn as_record_ptr_unchecked(&self) -> Result<(*mut c_void, *mut c_void)> {
     Ok((
       self.0.as_raw().Anonymous.Anonymous.Anonymous.Anonymous.pvRecord,
@@ -71,6 +137,51 @@ impl Variant {
     Ok(super::Dispatch::from(IDispatch::try_from(&self.0)?))
   }
 
+  /// Same as [Variant::into_dispatch], but returns `None` for empty variant or null dispatch pointer.
+  pub fn as_optional_dispatch(&self) -> Result<Option<super::Dispatch>> {
+    let raw = self.0.as_raw();
+    let is_null = unsafe {
+      match raw.Anonymous.Anonymous.vt {
+        vt if vt == VT_EMPTY.0 || vt == VT_NULL.0 => true,
+        vt if vt == VT_DISPATCH.0 => raw.Anonymous.Anonymous.Anonymous.pdispVal.is_null(),
+        _ => false
+      }
+    };
+    match is_null {
+      true => Ok(None),
+      false => Ok(Some(self.into_dispatch()?))
+    }
+  }
+
+  pub fn as_int_vec(&self) -> Result<Vec<i32>> { unsafe { self.map_array(|value: &i32| *value) } }
+  pub fn as_guid_vec(&self) -> Result<Vec<GUID>> { unsafe { self.map_array(|value: &GUID| *value) } }
+  pub fn as_string_vec(&self) -> Result<Vec<String>> { unsafe { self.map_array(|value: &BSTR| value.to_string()) } }
+
+  unsafe fn map_array<T, U>(&self, f: impl Fn(&T) -> U) -> Result<Vec<U>> {
+    let vt = self.vt();
+    if vt & VT_ARRAY.0 == 0 {
+      return Err(Error::Internal(format!("Variant is not an array (vt = {vt})")));
+    }
+    let array = self.0.as_raw().Anonymous.Anonymous.Anonymous.parray as *const SAFEARRAY;
+    if array.is_null() {
+      return Ok(Vec::new());
+    }
+    if (*array).cbElements as usize != std::mem::size_of::<T>() {
+      return Err(Error::Internal(format!("Unexpected array element size: {}", (*array).cbElements)));
+    }
+    let lower = SafeArrayGetLBound(array, 1)?;
+    let upper = SafeArrayGetUBound(array, 1)?;
+    let len = (upper - lower + 1).max(0) as usize;
+    let mut data = std::ptr::null_mut();
+    SafeArrayAccessData(array, &mut data)?;
+    let result = std::slice::from_raw_parts(data as *const T, len)
+      .iter()
+      .map(f)
+      .collect();
+    SafeArrayUnaccessData(array)?;
+    Ok(result)
+  }
+
   pub unsafe fn vt(&self) -> u16 {
     self.0.as_raw().Anonymous.Anonymous.vt
   }
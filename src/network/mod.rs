@@ -0,0 +1,549 @@
+//! Connectivity graph of engineering systems.
+//!
+//! Nodes of a [Network] are model objects with ports (pipes, ducts, fittings, accessories,
+//! equipment), edges are connections between their ports. The network is usually built from
+//! a project model with [crate::Model::network], but can be assembled by hand as well:
+//! ```
+//! use renga_api_rs::network::*;
+//!
+//! let port = |name: &str, flow_direction| NetworkPort::new(format!("00000000-0000-0000-0000-00000000000{}", name.len()).parse().unwrap(), name, flow_direction);
+//! let mut network = Network::new();
+//! network.add_node(Node::new(1, "Pump", vec![port("out", FlowDirection::Outlet)]));
+//! network.add_node(Node::new(2, "Pipe", vec![port("a", FlowDirection::InletAndOutlet), port("bb", FlowDirection::InletAndOutlet)]));
+//! network.connect(&network.port_ref(1, "out").unwrap(), &network.port_ref(2, "a").unwrap()).unwrap();
+//!
+//! assert_eq!(network.downstream(1), vec![2]);
+//! assert_eq!(network.disconnected_ports().len(), 1);
+//! ```
+//!
+//! Flow is assumed to go from outlet ports to inlet ports. Bidirectional (transit) ports of
+//! pipes and fittings are oriented once by walking from [Network::sources]: flow enters such
+//! node only through the port it is first reached by, and all queries follow this orientation.
+
+use std::collections::{
+  BTreeMap,
+  HashMap,
+  HashSet,
+  VecDeque
+};
+use crate::{
+  Result,
+  Error,
+  UUID
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Direction of flow through a port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum FlowDirection {
+  Inlet,
+  Outlet,
+  InletAndOutlet,
+
+  /// Direction, not known to this library, with its native value.
+  Unknown(i32)
+}
+
+/// Reference to a port of a model object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct PortRef {
+  pub object_id: i32,
+  pub port_id: UUID
+}
+
+/// Port of a [Node].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct NetworkPort {
+  pub id: UUID,
+  pub name: String,
+  pub flow_direction: FlowDirection,
+
+  /// Port of another object, connected to this one.
+  pub connected_to: Option<PortRef>
+}
+
+/// Model object with ports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Node {
+  /// Identifier of the model object.
+  pub id: i32,
+  pub name: String,
+  pub ports: Vec<NetworkPort>
+}
+
+/// Connection between two ports. Each connection is reported once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Edge {
+  pub from: PortRef,
+  pub to: PortRef
+}
+
+/// Connectivity graph of model objects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Network {
+  nodes: BTreeMap<i32, Node>
+}
+
+impl FlowDirection {
+  /// Returns `true` if flow can enter an object through the port.
+  ///
+  /// Returns `false` for [FlowDirection::Unknown].
+  pub fn is_inlet(&self) -> bool {
+    matches!(self, Self::Inlet | Self::InletAndOutlet)
+  }
+
+  /// Returns `true` if flow can leave an object through the port.
+  ///
+  /// Returns `false` for [FlowDirection::Unknown].
+  pub fn is_outlet(&self) -> bool {
+    matches!(self, Self::Outlet | Self::InletAndOutlet)
+  }
+}
+
+impl NetworkPort {
+  /// Creates not connected port.
+  pub fn new(id: UUID, name: &str, flow_direction: FlowDirection) -> Self {
+    Self {
+      id,
+      name: name.to_owned(),
+      flow_direction,
+      connected_to: None
+    }
+  }
+}
+
+impl Node {
+  pub fn new(id: i32, name: &str, ports: Vec<NetworkPort>) -> Self {
+    Self {
+      id,
+      name: name.to_owned(),
+      ports
+    }
+  }
+
+  /// Returns port with given identifier.
+  pub fn port(&self, id: &UUID) -> Option<&NetworkPort> {
+    self
+      .ports
+      .iter()
+      .find(|port| port.id == *id)
+  }
+}
+
+impl Network {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds node to the network, replacing existing node with the same identifier.
+  pub fn add_node(&mut self, node: Node) {
+    self.nodes.insert(node.id, node);
+  }
+
+  pub fn node(&self, id: i32) -> Option<&Node> {
+    self.nodes.get(&id)
+  }
+
+  /// Returns all nodes, ordered by identifier.
+  pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+    self.nodes.values()
+  }
+
+  pub fn len(&self) -> usize {
+    self.nodes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.nodes.is_empty()
+  }
+
+  /// Returns reference to the port of node `object_id` with given name.
+  pub fn port_ref(&self, object_id: i32, name: &str) -> Option<PortRef> {
+    self
+      .node(object_id)?
+      .ports
+      .iter()
+      .find(|port| port.name == name)
+      .map(|port| PortRef {
+        object_id,
+        port_id: port.id.clone()
+      })
+  }
+
+  /// Connects two ports, disconnecting them from previously connected ports.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if any of the ports does not exist.
+  pub fn connect(&mut self, a: &PortRef, b: &PortRef) -> Result<()> {
+    for port in [a, b] {
+      if self.port(port).is_none() {
+        return Err(Error::InvalidOperation(format!("Port {} of object #{} does not exist", port.port_id, port.object_id)));
+      }
+    }
+    for (port, other) in [(a, b), (b, a)] {
+      if let Some(previous) = self.port(port).and_then(|port| port.connected_to.clone()) {
+        if let Some(previous) = self.port_mut(&previous) {
+          previous.connected_to = None;
+        }
+      }
+      if let Some(port) = self.port_mut(port) {
+        port.connected_to = Some(other.clone());
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns all connections between nodes of the network.
+  ///
+  /// Connections to objects, which are not in the network, are skipped.
+  pub fn edges(&self) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for node in self.nodes.values() {
+      for port in &node.ports {
+        let Some(to) = &port.connected_to else {
+          continue;
+        };
+        let from = PortRef {
+          object_id: node.id,
+          port_id: port.id.clone()
+        };
+        let Some(other) = self.port(to) else {
+          continue;
+        };
+        // Mutual connection is reported from the side with lesser key.
+        let mutual = other.connected_to.as_ref() == Some(&from);
+        if !mutual || (from.object_id, from.port_id.to_string()) < (to.object_id, to.port_id.to_string()) {
+          edges.push(Edge { from, to: to.clone() });
+        }
+      }
+    }
+    edges
+  }
+
+  /// Returns identifiers of nodes directly connected to given node.
+  pub fn neighbors(&self, id: i32) -> Vec<i32> {
+    let mut neighbors: Vec<i32> = self
+      .node(id)
+      .map(|node| node
+        .ports
+        .iter()
+        .filter_map(|port| port.connected_to.as_ref())
+        .filter(|to| self.nodes.contains_key(&to.object_id))
+        .map(|to| to.object_id)
+        .collect())
+      .unwrap_or_default();
+    neighbors.sort_unstable();
+    neighbors.dedup();
+    neighbors
+  }
+
+  /// Returns ports which are not connected to any port of the network.
+  pub fn disconnected_ports(&self) -> Vec<PortRef> {
+    self
+      .nodes
+      .values()
+      .flat_map(|node| node
+        .ports
+        .iter()
+        .filter(|port| port.connected_to.as_ref().and_then(|to| self.port(to)).is_none())
+        .map(|port| PortRef {
+          object_id: node.id,
+          port_id: port.id.clone()
+        }))
+      .collect()
+  }
+
+  /// Returns groups of connected nodes. Nodes in groups and groups are ordered by identifier.
+  pub fn components(&self) -> Vec<Vec<i32>> {
+    let mut visited = HashSet::new();
+    let mut components = Vec::new();
+    for &start in self.nodes.keys() {
+      if !visited.insert(start) {
+        continue;
+      }
+      let mut component = vec![start];
+      let mut queue = VecDeque::from([start]);
+      while let Some(id) = queue.pop_front() {
+        for neighbor in self.neighbors(id) {
+          if visited.insert(neighbor) {
+            component.push(neighbor);
+            queue.push_back(neighbor);
+          }
+        }
+      }
+      component.sort_unstable();
+      components.push(component);
+    }
+    components
+  }
+
+  /// Returns nodes flow starts from: nodes with outlet-only ports and without connected
+  /// inlet-only ports, e.g. a pump or a fan at the beginning of a system. Ordered by identifier.
+  pub fn sources(&self) -> Vec<i32> {
+    self
+      .nodes
+      .values()
+      .filter(|node| node.ports.iter().any(|port| port.flow_direction == FlowDirection::Outlet))
+      .filter(|node| !node.ports.iter().any(|port| port.flow_direction == FlowDirection::Inlet
+        && port.connected_to.as_ref().and_then(|to| self.port(to)).is_some()))
+      .map(|node| node.id)
+      .collect()
+  }
+
+  /// Returns nodes, which flow reaches after leaving through given port, ordered by identifier.
+  ///
+  /// Flow enters a node through a port allowing inflow and leaves it through any other port
+  /// allowing outflow. Nodes reached from [Network::sources] are entered only through their
+  /// entry port, so flow does not go against the orientation. Origin node of the port is never included.
+  pub fn reachable_from(&self, port: &PortRef) -> Vec<i32> {
+    self.flow_from(port.object_id, std::slice::from_ref(port), &self.entry_ports())
+  }
+
+  /// Returns nodes downstream of given node, ordered by identifier.
+  ///
+  /// Flow leaves the node through its outlet-only ports. If there are none, bidirectional
+  /// ports are oriented by flow from [Network::sources]: all ports except the
+  /// one flow enters through are used. Returns empty list if node is not reachable from a source.
+  pub fn downstream(&self, id: i32) -> Vec<i32> {
+    self.downstream_with(id, &self.entry_ports())
+  }
+
+  /// Returns nodes upstream of given node: nodes which have it downstream. Ordered by identifier.
+  pub fn upstream(&self, id: i32) -> Vec<i32> {
+    let entries = self.entry_ports();
+    self
+      .nodes
+      .keys()
+      .copied()
+      .filter(|&other| other != id && self.downstream_with(other, &entries).contains(&id))
+      .collect()
+  }
+
+  fn downstream_with(&self, id: i32, entries: &HashMap<i32, UUID>) -> Vec<i32> {
+    let Some(node) = self.node(id) else {
+      return Vec::new();
+    };
+    let outlets: Vec<PortRef> = node
+      .ports
+      .iter()
+      .filter(|port| port.flow_direction == FlowDirection::Outlet)
+      .map(|port| PortRef {
+        object_id: id,
+        port_id: port.id.clone()
+      })
+      .collect();
+    if !outlets.is_empty() {
+      return self.flow_from(id, &outlets, entries);
+    }
+    let Some(entry) = entries.get(&id) else {
+      return Vec::new();
+    };
+    let exits: Vec<PortRef> = node
+      .ports
+      .iter()
+      .filter(|port| port.id != *entry && port.flow_direction.is_outlet())
+      .map(|port| PortRef {
+        object_id: id,
+        port_id: port.id.clone()
+      })
+      .collect();
+    self.flow_from(id, &exits, entries)
+  }
+
+  /// Orients the network: finds ports through which flow from sources first enters each node.
+  fn entry_ports(&self) -> HashMap<i32, UUID> {
+    let mut entries = HashMap::new();
+    let mut visited: HashSet<i32> = HashSet::new();
+    let mut queue = VecDeque::new();
+    for source in self.sources().into_iter().filter_map(|id| self.node(id)) {
+      visited.insert(source.id);
+      for port in source.ports.iter().filter(|port| port.flow_direction.is_outlet()) {
+        queue.push_back(port.clone());
+      }
+    }
+    while let Some(port) = queue.pop_front() {
+      let Some((node, entered)) = self.enter(&port, &entries) else {
+        continue;
+      };
+      if visited.insert(node.id) {
+        entries.insert(node.id, entered.id.clone());
+        queue.extend(self.exits(node, &entered.id).cloned());
+      }
+    }
+    entries
+  }
+
+  fn flow_from(&self, origin: i32, ports: &[PortRef], entries: &HashMap<i32, UUID>) -> Vec<i32> {
+    let mut visited = HashSet::from([origin]);
+    let mut queue: VecDeque<NetworkPort> = ports
+      .iter()
+      .filter_map(|port| self.port(port))
+      .filter(|port| port.flow_direction.is_outlet())
+      .cloned()
+      .collect();
+    while let Some(port) = queue.pop_front() {
+      let Some((node, entered)) = self.enter(&port, entries) else {
+        continue;
+      };
+      if visited.insert(node.id) {
+        queue.extend(self.exits(node, &entered.id).cloned());
+      }
+    }
+    visited.remove(&origin);
+    let mut result: Vec<i32> = visited.into_iter().collect();
+    result.sort_unstable();
+    result
+  }
+
+  /// Follows connection of `port` and returns the node and the port flow enters through.
+  ///
+  /// Bidirectional port of an oriented node can only be entered if it is the entry port of the node.
+  fn enter(&self, port: &NetworkPort, entries: &HashMap<i32, UUID>) -> Option<(&Node, &NetworkPort)> {
+    let to = port.connected_to.as_ref()?;
+    let node = self.node(to.object_id)?;
+    let entered = node.port(&to.port_id)?;
+    let oriented = entered.flow_direction != FlowDirection::InletAndOutlet
+      || entries.get(&node.id).map_or(true, |entry| *entry == entered.id);
+    (entered.flow_direction.is_inlet() && oriented).then_some((node, entered))
+  }
+
+  fn exits<'a>(&self, node: &'a Node, entered: &'a UUID) -> impl Iterator<Item = &'a NetworkPort> {
+    node
+      .ports
+      .iter()
+      .filter(move |port| port.id != *entered && port.flow_direction.is_outlet())
+  }
+
+  fn port(&self, port: &PortRef) -> Option<&NetworkPort> {
+    self.node(port.object_id)?.port(&port.port_id)
+  }
+
+  fn port_mut(&mut self, port: &PortRef) -> Option<&mut NetworkPort> {
+    self
+      .nodes
+      .get_mut(&port.object_id)?
+      .ports
+      .iter_mut()
+      .find(|p| p.id == port.port_id)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    network::*,
+    uuid
+  };
+
+  fn port(index: usize, name: &str, flow_direction: FlowDirection) -> NetworkPort {
+    NetworkPort::new(uuid(index), name, flow_direction)
+  }
+
+  fn transit(id: i32, name: &str, count: usize) -> Node {
+    let ports = (0..count)
+      .map(|i| port(i, &format!("T{i}"), FlowDirection::InletAndOutlet))
+      .collect();
+    Node::new(id, name, ports)
+  }
+
+  /// Pump (1) -> pipe (2) -> tee (3) -> pipe (4) -> valve (5) -> pipe (6) -> sink (7)
+  ///                                 \-> pipe (8), open end
+  /// Pipe (9) is not connected at all.
+  fn network() -> Network {
+    let mut network = Network::new();
+    network.add_node(Node::new(1, "Pump", vec![port(0, "In", FlowDirection::Inlet), port(1, "Out", FlowDirection::Outlet)]));
+    network.add_node(transit(2, "Pipe", 2));
+    network.add_node(transit(3, "Tee", 3));
+    network.add_node(transit(4, "Pipe", 2));
+    network.add_node(transit(5, "Valve", 2));
+    network.add_node(transit(6, "Pipe", 2));
+    network.add_node(Node::new(7, "Sink", vec![port(0, "In", FlowDirection::Inlet)]));
+    network.add_node(transit(8, "Pipe", 2));
+    network.add_node(transit(9, "Pipe", 2));
+    for (a, a_port, b, b_port) in [
+      (1, "Out", 2, "T0"),
+      (2, "T1", 3, "T0"),
+      (3, "T1", 4, "T1"),
+      (4, "T0", 5, "T0"),
+      (5, "T1", 6, "T0"),
+      (6, "T1", 7, "In"),
+      (3, "T2", 8, "T0")
+    ] {
+      let a = network.port_ref(a, a_port).unwrap();
+      let b = network.port_ref(b, b_port).unwrap();
+      network.connect(&a, &b).unwrap();
+    }
+    network
+  }
+
+  #[test]
+  fn test_edges_and_neighbors() {
+    let network = network();
+
+    assert_eq!(network.len(), 9);
+    assert_eq!(network.edges().len(), 7);
+    assert_eq!(network.neighbors(3), vec![2, 4, 8]);
+    assert_eq!(network.components(), vec![vec![1, 2, 3, 4, 5, 6, 7, 8], vec![9]]);
+    assert_eq!(network.sources(), vec![1]);
+  }
+
+  #[test]
+  fn test_disconnected_ports() {
+    let network = network();
+    let disconnected: Vec<(i32, String)> = network
+      .disconnected_ports()
+      .iter()
+      .map(|port| (port.object_id, network.node(port.object_id).unwrap().port(&port.port_id).unwrap().name.clone()))
+      .collect();
+
+    assert_eq!(disconnected, vec![
+      (1, "In".to_owned()),
+      (8, "T1".to_owned()),
+      (9, "T0".to_owned()),
+      (9, "T1".to_owned())
+    ]);
+  }
+
+  #[test]
+  fn test_downstream() {
+    let network = network();
+
+    assert_eq!(network.downstream(1), vec![2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(network.downstream(5), vec![6, 7]);
+    assert_eq!(network.downstream(3), vec![4, 5, 6, 7, 8]);
+    assert_eq!(network.downstream(7), Vec::<i32>::new());
+    assert_eq!(network.downstream(9), Vec::<i32>::new());
+    assert_eq!(network.upstream(5), vec![1, 2, 3, 4]);
+    assert_eq!(network.reachable_from(&network.port_ref(3, "T2").unwrap()), vec![8]);
+    assert_eq!(network.reachable_from(&network.port_ref(5, "T0").unwrap()), Vec::<i32>::new());
+  }
+
+  #[test]
+  fn test_connect() {
+    let mut network = network();
+    let valve = network.port_ref(5, "T1").unwrap();
+    let open_end = network.port_ref(8, "T1").unwrap();
+    network.connect(&valve, &open_end).unwrap();
+
+    // Flow reaches pipe 8 from the tee, so the valve can not push flow back into it.
+    assert_eq!(network.downstream(5), Vec::<i32>::new());
+    assert_eq!(network.upstream(5), vec![1, 2, 3, 4]);
+    assert_eq!(network.upstream(8), vec![1, 2, 3]);
+    assert!(network.disconnected_ports().contains(&network.port_ref(6, "T0").unwrap()));
+
+    let missing = PortRef {
+      object_id: 42,
+      port_id: valve.port_id.clone()
+    };
+    assert!(network.connect(&valve, &missing).is_err());
+  }
+}
@@ -0,0 +1,156 @@
+use std::{
+  ffi::c_void,
+  marker::PhantomData,
+  panic::{
+    catch_unwind,
+    AssertUnwindSafe
+  },
+  path::PathBuf
+};
+use crate::{
+  Application,
+  Result
+};
+use super::{
+  Plugin,
+  PluginContext
+};
+
+/// Virtual table of Renga `plugins::IPlugin` C++ interface (MSVC x64 layout).
+#[repr(C)]
+struct PluginVtbl {
+  /// Scalar deleting destructor.
+  destroy: unsafe extern "C" fn(*mut c_void, u32) -> *mut c_void,
+  initialize: unsafe extern "C" fn(*mut c_void, *const u16) -> bool,
+  stop: unsafe extern "C" fn(*mut c_void)
+}
+
+/// C++ object, handed to Renga: vtable pointer followed by the Rust plugin.
+#[repr(C)]
+struct RawPlugin<P> {
+  vtable: *const PluginVtbl,
+  plugin: P
+}
+
+struct Vtable<P>(PhantomData<P>);
+
+impl<P: Plugin> Vtable<P> {
+  const VTABLE: PluginVtbl = PluginVtbl {
+    destroy: destroy::<P>,
+    initialize: initialize::<P>,
+    stop: stop::<P>
+  };
+}
+
+/// Creates plugin object for `CreatePlugin` export. Used by [crate::export_plugin].
+#[doc(hidden)]
+pub fn create_plugin<P: Plugin>() -> *mut c_void {
+  match catch_unwind(P::default) {
+    Ok(plugin) => Box::into_raw(Box::new(RawPlugin {
+      vtable: &Vtable::<P>::VTABLE,
+      plugin
+    })) as *mut c_void,
+    Err(_) => {
+      log::error!("plugin: constructor panicked");
+      std::ptr::null_mut()
+    }
+  }
+}
+
+unsafe extern "C" fn destroy<P: Plugin>(this: *mut c_void, flags: u32) -> *mut c_void {
+  if flags & 1 != 0 {
+    drop(Box::from_raw(this as *mut RawPlugin<P>));
+  } else {
+    std::ptr::drop_in_place(this as *mut RawPlugin<P>);
+  }
+  this
+}
+
+unsafe extern "C" fn initialize<P: Plugin>(this: *mut c_void, plugin_path: *const u16) -> bool {
+  let raw = &mut *(this as *mut RawPlugin<P>);
+  let plugin_dir = PathBuf::from(wide_to_string(plugin_path));
+  let result = catch_unwind(AssertUnwindSafe(|| -> Result<()> {
+    let context = PluginContext {
+      plugin_dir,
+      application: Application::host()?
+    };
+    raw.plugin.initialize(context)
+  }));
+  match result {
+    Ok(Ok(())) => true,
+    Ok(Err(e)) => {
+      log::error!("plugin: initialization failed: {e}");
+      false
+    },
+    Err(_) => {
+      log::error!("plugin: initialization panicked");
+      false
+    }
+  }
+}
+
+unsafe extern "C" fn stop<P: Plugin>(this: *mut c_void) {
+  let raw = &mut *(this as *mut RawPlugin<P>);
+  if catch_unwind(AssertUnwindSafe(|| raw.plugin.stop())).is_err() {
+    log::error!("plugin: stop panicked");
+  }
+}
+
+unsafe fn wide_to_string(ptr: *const u16) -> String {
+  if ptr.is_null() {
+    return String::new();
+  }
+  let mut len = 0;
+  while *ptr.add(len) != 0 {
+    len += 1;
+  }
+  String::from_utf16_lossy(std::slice::from_raw_parts(ptr, len))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering
+  };
+  use super::*;
+
+  static STOPPED: AtomicUsize = AtomicUsize::new(0);
+  static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+  #[derive(Default)]
+  struct TestPlugin;
+
+  impl Plugin for TestPlugin {
+    fn initialize(&mut self, _context: PluginContext) -> Result<()> {
+      Ok(())
+    }
+
+    fn stop(&mut self) {
+      STOPPED.fetch_add(1, Ordering::SeqCst);
+      panic!("stop must not unwind into the host");
+    }
+  }
+
+  impl Drop for TestPlugin {
+    fn drop(&mut self) {
+      DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+  }
+
+  crate::export_plugin!(TestPlugin);
+
+  #[test]
+  fn test_lifecycle() {
+    let raw = CreatePlugin();
+    assert!(!raw.is_null());
+    unsafe {
+      let vtable = &*(*(raw as *const *const PluginVtbl));
+      (vtable.stop)(raw);
+      assert_eq!(STOPPED.load(Ordering::SeqCst), 1);
+      (vtable.destroy)(raw, 1);
+    }
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 1);
+    assert_eq!(unsafe { wide_to_string([0x52, 0x65, 0x6E, 0x67, 0x61, 0].as_ptr()) }, "Renga");
+  }
+}
@@ -0,0 +1,234 @@
+#[cfg(feature="serde")]
+use std::path::Path;
+
+#[cfg(feature="serde")]
+use crate::{
+  Result,
+  Error
+};
+
+/// Version of Renga API required by plugins by default.
+pub const DEFAULT_REQUIRED_API_VERSION: &str = "2.0";
+
+/// Plugin description file (`.rndesc`), which tells Renga how to load the plugin.
+///
+/// See [Official documentation](https://help.rengabim.com/api/plugin_description.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+  /// Name of the plugin, also used as a name of the plugin folder.
+  pub name: String,
+  pub version: String,
+  pub vendor: String,
+  pub vendor_link: Option<String>,
+  pub copyright: Option<String>,
+  pub description: Option<String>,
+
+  /// Minimal version of Renga API, in `major.minor` form.
+  pub required_api_version: String,
+
+  /// File name of the plugin library, relative to the plugin folder.
+  pub filename: String
+}
+
+impl Manifest {
+  pub fn new(name: &str, version: &str, filename: &str) -> Self {
+    Self {
+      name: name.to_owned(),
+      version: version.to_owned(),
+      vendor: String::new(),
+      vendor_link: None,
+      copyright: None,
+      description: None,
+      required_api_version: DEFAULT_REQUIRED_API_VERSION.to_owned(),
+      filename: filename.to_owned()
+    }
+  }
+
+  /// Reads manifest from `Cargo.toml` of the plugin crate.
+  ///
+  /// Values are taken from `[package.metadata.renga]` table, falling back to package fields:
+  /// ```toml
+  /// [package]
+  /// name = "export-tools"                  # library file name, `export_tools.dll`
+  /// version = "1.2.0"                      # version
+  /// authors = ["ACME <info@acme.com>"]     # vendor, `ACME`
+  /// description = "Export tools"           # description
+  /// homepage = "https://acme.com"          # vendor link, or `repository`
+  ///
+  /// [package.metadata.renga]
+  /// name = "Export Tools"                  # defaults to package name
+  /// vendor = "ACME Corp."
+  /// vendor_link = "https://acme.com/renga"
+  /// copyright = "(c) ACME Corp."
+  /// required_api_version = "2.30"          # defaults to DEFAULT_REQUIRED_API_VERSION
+  /// ```
+  /// Library name is taken from `[lib]` table, if specified. Returns [crate::Error::ParseError]
+  /// if the file is not a valid package manifest.
+  #[cfg(feature="serde")]
+  pub fn from_cargo_toml(path: &Path) -> Result<Self> {
+    if !path.exists() {
+      return Err(Error::NonexistentPath(path.display().to_string()));
+    }
+    Self::parse_cargo_toml(&std::fs::read_to_string(path)?)
+  }
+
+  /// Parses manifest from contents of `Cargo.toml`, see [Manifest::from_cargo_toml].
+  #[cfg(feature="serde")]
+  pub fn parse_cargo_toml(contents: &str) -> Result<Self> {
+    let cargo: toml::Table = toml::from_str(contents).map_err(|e| Error::ParseError(e.to_string()))?;
+    let package = cargo
+      .get("package")
+      .and_then(toml::Value::as_table)
+      .ok_or_else(|| Error::ParseError("Cargo.toml has no [package] table".to_owned()))?;
+    let field = |table: Option<&toml::Table>, key: &str| -> Result<Option<String>> {
+      match table.and_then(|table| table.get(key)) {
+        None => Ok(None),
+        Some(toml::Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(Error::ParseError(format!("Field `{key}` must be a string, inherited fields are not supported")))
+      }
+    };
+    let metadata = package
+      .get("metadata")
+      .and_then(|metadata| metadata.get("renga"))
+      .and_then(toml::Value::as_table);
+    let package = Some(package);
+
+    let package_name = field(package, "name")?
+      .ok_or_else(|| Error::ParseError("Cargo.toml has no package name".to_owned()))?;
+    let library = field(cargo.get("lib").and_then(toml::Value::as_table), "name")?
+      .unwrap_or_else(|| package_name.replace('-', "_"));
+    let author = package
+      .and_then(|package| package.get("authors"))
+      .and_then(toml::Value::as_array)
+      .and_then(|authors| authors.first())
+      .and_then(toml::Value::as_str)
+      .map(|author| author.split('<').next().unwrap_or_default().trim().to_owned());
+
+    Ok(Self {
+      name: field(metadata, "name")?.unwrap_or(package_name),
+      version: field(package, "version")?.unwrap_or_else(|| "0.0.0".to_owned()),
+      vendor: field(metadata, "vendor")?.or(author).unwrap_or_default(),
+      vendor_link: match field(metadata, "vendor_link")? {
+        Some(link) => Some(link),
+        None => field(package, "homepage")?.or(field(package, "repository")?)
+      },
+      copyright: field(metadata, "copyright")?,
+      description: match field(metadata, "description")? {
+        Some(description) => Some(description),
+        None => field(package, "description")?
+      },
+      required_api_version: field(metadata, "required_api_version")?
+        .unwrap_or_else(|| DEFAULT_REQUIRED_API_VERSION.to_owned()),
+      filename: format!("{library}.dll")
+    })
+  }
+
+  /// Returns name of the description file.
+  pub fn file_name(&self) -> String {
+    format!("{}.rndesc", self.folder_name())
+  }
+
+  /// Returns name of the plugin folder: plugin name with characters, not allowed in file names, replaced.
+  pub fn folder_name(&self) -> String {
+    self
+      .name
+      .chars()
+      .map(|c| match c {
+        '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+        c if c.is_control() => '_',
+        c => c
+      })
+      .collect()
+  }
+
+  /// Returns contents of the description file.
+  pub fn to_xml(&self) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<RengaPlugin>\n");
+    let mut element = |name: &str, value: &str| {
+      xml.push_str(&format!("  <{name}>{}</{name}>\n", escape(value)));
+    };
+    element("Name", &self.name);
+    element("Version", &self.version);
+    element("Vendor", &self.vendor);
+    if let Some(link) = &self.vendor_link {
+      element("VendorLink", link);
+    }
+    element("RequiredAPIVersion", &self.required_api_version);
+    element("PluginFilename", &self.filename);
+    if let Some(copyright) = &self.copyright {
+      element("Copyright", copyright);
+    }
+    if let Some(description) = &self.description {
+      element("Description", description);
+    }
+    element("PluginType", "Cpp");
+    xml.push_str("</RengaPlugin>\n");
+    xml
+  }
+}
+
+fn escape(value: &str) -> String {
+  value
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::plugin::*;
+
+  #[test]
+  fn test_to_xml() {
+    let mut manifest = Manifest::new("Tools <beta>", "1.2.0", "tools.dll");
+    manifest.vendor = "R&D".to_owned();
+
+    assert_eq!(manifest.to_xml(), "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+      <RengaPlugin>\n  \
+      <Name>Tools &lt;beta&gt;</Name>\n  \
+      <Version>1.2.0</Version>\n  \
+      <Vendor>R&amp;D</Vendor>\n  \
+      <RequiredAPIVersion>2.0</RequiredAPIVersion>\n  \
+      <PluginFilename>tools.dll</PluginFilename>\n  \
+      <PluginType>Cpp</PluginType>\n\
+      </RengaPlugin>\n");
+    assert_eq!(manifest.folder_name(), "Tools _beta_");
+    assert_eq!(manifest.file_name(), "Tools _beta_.rndesc");
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_from_cargo_toml() -> anyhow::Result<()> {
+    let manifest = Manifest::from_cargo_toml(&crate::external_file("plugin_cargo.toml")?)?;
+
+    assert_eq!(manifest, Manifest {
+      name: "Export Tools".to_owned(),
+      version: "1.2.0".to_owned(),
+      vendor: "ACME".to_owned(),
+      vendor_link: Some("https://example.com/renga".to_owned()),
+      copyright: Some("(c) ACME".to_owned()),
+      description: Some("Exports model to external formats".to_owned()),
+      required_api_version: "2.30".to_owned(),
+      filename: "export_tools.dll".to_owned()
+    });
+
+    Ok(())
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_parse_cargo_toml_defaults() -> anyhow::Result<()> {
+    let manifest = Manifest::parse_cargo_toml("[package]\nname = \"tools\"\nversion = \"0.1.0\"\n\n[lib]\nname = \"renga_tools\"\n")?;
+
+    assert_eq!(manifest.name, "tools");
+    assert_eq!(manifest.vendor, "");
+    assert_eq!(manifest.required_api_version, DEFAULT_REQUIRED_API_VERSION);
+    assert_eq!(manifest.filename, "renga_tools.dll");
+    assert!(Manifest::parse_cargo_toml("[package]\nname = \"tools\"\nversion.workspace = true\n").is_err());
+    assert!(Manifest::parse_cargo_toml("[workspace]\n").is_err());
+
+    Ok(())
+  }
+}
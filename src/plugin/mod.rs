@@ -0,0 +1,90 @@
+//! In-process Renga plugins written in Rust.
+//!
+//! Plugin is a `cdylib` crate, which implements [Plugin] and exports it with
+//! [crate::export_plugin]. Renga loads the library, calls [Plugin::initialize] on start
+//! and [Plugin::stop] before exit:
+//! ```no_run
+//! use renga_api_rs as renga;
+//! use renga::{
+//!   plugin::{Plugin, PluginContext},
+//!   ui::{ActionSpec, Layout, Panel},
+//!   UiExtension
+//! };
+//!
+//! #[derive(Default)]
+//! struct Hello {
+//!   extension: Option<UiExtension>
+//! }
+//!
+//! impl Plugin for Hello {
+//!   fn initialize(&mut self, context: PluginContext) -> renga::Result<()> {
+//!     let layout = Layout::new()
+//!       .action(ActionSpec::new("hello", "Hello"))
+//!       .primary_panel(Panel::new().button("hello"));
+//!     let app = context.application;
+//!     self.extension = Some(app.ui()?.install(&layout, move |_, _| {
+//!       println!("Renga {}", app.version().unwrap());
+//!     })?);
+//!     Ok(())
+//!   }
+//! }
+//!
+//! renga::export_plugin!(Hello);
+//! ```
+//!
+//! Renga finds plugins by description files (`.rndesc`) in its `Plugins` folder. [Manifest]
+//! describes the plugin and can be read from `Cargo.toml`, [Package] writes the plugin
+//! folder with the library and the description file.
+
+mod entry;
+mod manifest;
+mod package;
+
+use std::path::PathBuf;
+use crate::{
+  Application,
+  Result
+};
+
+#[doc(hidden)]
+pub use entry::create_plugin;
+pub use manifest::{
+  Manifest,
+  DEFAULT_REQUIRED_API_VERSION
+};
+pub use package::Package;
+
+/// Data passed to [Plugin::initialize].
+#[derive(Debug)]
+pub struct PluginContext {
+  /// Folder from which the plugin was loaded.
+  pub plugin_dir: PathBuf,
+
+  /// Renga application hosting the plugin, see [Application::host].
+  pub application: Application
+}
+
+/// Renga plugin.
+///
+/// Plugin is created with [Default] when the library is loaded. All methods are called on
+/// the main thread of Renga. Panics are caught and logged, they never cross into Renga.
+pub trait Plugin: Default + 'static {
+  /// Called once on Renga start. Returned error is logged and Renga unloads the plugin.
+  fn initialize(&mut self, context: PluginContext) -> Result<()>;
+
+  /// Called before Renga exits or unloads the plugin.
+  fn stop(&mut self) {}
+}
+
+/// Exports type implementing [plugin::Plugin](crate::plugin::Plugin) as Renga plugin entry point.
+///
+/// Must be invoked once in a `cdylib` crate. See [crate::plugin] for an example.
+#[macro_export]
+macro_rules! export_plugin {
+  ($plugin:ty) => {
+    #[no_mangle]
+    pub extern "C" fn CreatePlugin() -> *mut ::std::ffi::c_void {
+      $crate::plugin::create_plugin::<$plugin>()
+    }
+  };
+}
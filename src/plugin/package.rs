@@ -0,0 +1,137 @@
+use std::{
+  fs,
+  path::{
+    Path,
+    PathBuf
+  }
+};
+use crate::{
+  ExportStatus,
+  OperationError,
+  OverwritePolicy,
+  Result,
+  Error
+};
+use super::Manifest;
+
+/// Plugin folder layout, ready to be copied into `Plugins` folder of Renga installation:
+/// ```text
+/// <folder_name>/
+///   <folder_name>.rndesc
+///   <filename>.dll
+///   <extra files>
+/// ```
+///
+/// Typically written from a build script or an `xtask` after `cargo build`:
+/// ```no_run
+/// use std::path::Path;
+/// use renga_api_rs::{plugin::{Manifest, Package}, OverwritePolicy};
+///
+/// let manifest = Manifest::from_cargo_toml(Path::new("Cargo.toml")).unwrap();
+/// let library = Path::new("target/release").join(&manifest.filename);
+/// Package::new(manifest, &library)
+///   .with_file(Path::new("icons/export.png"))
+///   .write(Path::new("target/plugin"), OverwritePolicy::Overwrite)
+///   .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+  pub manifest: Manifest,
+
+  /// Path to the built plugin library.
+  pub library: PathBuf,
+
+  /// Additional files, copied to the root of the plugin folder.
+  pub files: Vec<PathBuf>
+}
+
+impl Package {
+  pub fn new(manifest: Manifest, library: &Path) -> Self {
+    Self {
+      manifest,
+      library: library.to_path_buf(),
+      files: Vec::new()
+    }
+  }
+
+  pub fn with_file(mut self, path: &Path) -> Self {
+    self.files.push(path.to_path_buf());
+    self
+  }
+
+  /// Returns path of the plugin folder inside `out_dir`.
+  pub fn folder(&self, out_dir: &Path) -> PathBuf {
+    out_dir.join(self.manifest.folder_name())
+  }
+
+  /// Writes plugin folder into `out_dir`.
+  ///
+  /// Existing plugin folder is handled according to `overwrite`: it is removed entirely,
+  /// left untouched, or [crate::OperationError::FileAlreadyExists] is returned.
+  pub fn write(&self, out_dir: &Path, overwrite: OverwritePolicy) -> Result<ExportStatus> {
+    for path in std::iter::once(&self.library).chain(&self.files) {
+      if !path.is_file() {
+        return Err(Error::NonexistentPath(path.display().to_string()));
+      }
+    }
+    let folder = self.folder(out_dir);
+    if folder.exists() {
+      match overwrite {
+        OverwritePolicy::Overwrite => fs::remove_dir_all(&folder)?,
+        OverwritePolicy::Fail => return Err(OperationError::FileAlreadyExists.into()),
+        OverwritePolicy::Skip => return Ok(ExportStatus::Skipped)
+      }
+    }
+    fs::create_dir_all(&folder)?;
+    fs::write(folder.join(self.manifest.file_name()), self.manifest.to_xml())?;
+    fs::copy(&self.library, folder.join(&self.manifest.filename))?;
+    for file in &self.files {
+      let name = file
+        .file_name()
+        .ok_or_else(|| Error::NonexistentPath(file.display().to_string()))?;
+      fs::copy(file, folder.join(name))?;
+    }
+    log::debug!("plugin: packaged {} into {}", self.manifest.name, folder.display());
+    Ok(ExportStatus::Exported)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use crate::*;
+  use crate::plugin::*;
+
+  #[test]
+  fn test_write() -> anyhow::Result<()> {
+    let out = std::env::temp_dir().join("renga-api-rs-plugin");
+    let _ = fs::remove_dir_all(&out);
+    fs::create_dir_all(&out)?;
+    let library = out.join("build.dll");
+    fs::write(&library, b"MZ")?;
+
+    let package = Package::new(Manifest::new("Export Tools", "1.0.0", "export_tools.dll"), &library)
+      .with_file(&external_file("icon.png")?);
+    let folder = package.folder(&out);
+
+    assert_eq!(package.write(&out, OverwritePolicy::Fail)?, ExportStatus::Exported);
+    assert_eq!(folder, out.join("Export Tools"));
+    assert!(fs::read_to_string(folder.join("Export Tools.rndesc"))?.contains("<PluginFilename>export_tools.dll</PluginFilename>"));
+    assert_eq!(fs::read(folder.join("export_tools.dll"))?, b"MZ");
+    assert!(folder.join("icon.png").is_file());
+
+    assert_eq!(package.write(&out, OverwritePolicy::Skip)?, ExportStatus::Skipped);
+    assert!(matches!(
+      package.write(&out, OverwritePolicy::Fail),
+      Err(Error::Operation(OperationError::FileAlreadyExists))
+    ));
+    fs::write(folder.join("stale.txt"), "")?;
+    assert_eq!(package.write(&out, OverwritePolicy::Overwrite)?, ExportStatus::Exported);
+    assert!(!folder.join("stale.txt").exists());
+
+    let missing = Package::new(package.manifest.clone(), &out.join("missing.dll"));
+    assert!(matches!(missing.write(&out, OverwritePolicy::Overwrite), Err(Error::NonexistentPath(_))));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,219 @@
+//! Rebar schedules: bar counts, lengths and masses grouped by grade and diameter.
+//!
+//! Schedule of a project is built with [crate::Project::rebar_schedule], but can be
+//! computed from any list of [Rebar] items:
+//! ```
+//! use renga_api_rs::reinforcement::{Rebar, RebarSchedule};
+//!
+//! let schedule = RebarSchedule::from_rebars([
+//!   Rebar::new("A500C", 12.0, 4, 12_000.0),
+//!   Rebar::new("A500C", 12.0, 2, 6_000.0),
+//!   Rebar::new("A240", 8.0, 10, 9_000.0)
+//! ]);
+//!
+//! assert_eq!(schedule.rows.len(), 2);
+//! assert_eq!(schedule.rows[1].count, 6);
+//! assert!((schedule.mass_by_grade()["A500C"] - 15.98).abs() < 0.01);
+//! ```
+
+use std::collections::BTreeMap;
+use crate::table::{
+  Table,
+  Value
+};
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Density of reinforcing steel in kg/m³.
+pub const STEEL_DENSITY: f64 = 7850.0;
+
+/// Headers of the table produced by [RebarSchedule::to_table].
+pub const SCHEDULE_HEADERS: &[&str] = &[
+  "Grade",
+  "Diameter, mm",
+  "Count",
+  "Total length, m",
+  "Mass, kg"
+];
+
+/// Group of identical bars.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct Rebar {
+  /// Name of the reinforcement grade, e.g. `A500C`.
+  pub grade: String,
+
+  /// Nominal diameter of a bar in millimeters.
+  pub diameter: f64,
+
+  /// Number of bars.
+  pub count: u32,
+
+  /// Total length of all bars in millimeters.
+  pub total_length: f64
+}
+
+/// Row of [RebarSchedule]: all bars of the same grade and diameter.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct ScheduleRow {
+  pub grade: String,
+
+  /// Nominal diameter of a bar in millimeters.
+  pub diameter: f64,
+  pub count: u32,
+
+  /// Total length of all bars in meters.
+  pub total_length: f64,
+
+  /// Total mass of all bars in kilograms.
+  pub mass: f64
+}
+
+/// Rebar schedule, rows are ordered by grade and diameter.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub struct RebarSchedule {
+  pub rows: Vec<ScheduleRow>
+}
+
+impl Rebar {
+  pub fn new(grade: &str, diameter: f64, count: u32, total_length: f64) -> Self {
+    Self {
+      grade: grade.to_owned(),
+      diameter,
+      count,
+      total_length
+    }
+  }
+}
+
+/// Returns mass of one meter of a bar with given diameter in millimeters, in kilograms.
+pub fn linear_mass(diameter: f64) -> f64 {
+  let radius = diameter / 2000.0;
+  std::f64::consts::PI * radius * radius * STEEL_DENSITY
+}
+
+impl RebarSchedule {
+  /// Groups bars by grade and diameter and computes their lengths and masses.
+  pub fn from_rebars<I: IntoIterator<Item = Rebar>>(rebars: I) -> Self {
+    let mut groups: BTreeMap<(String, u64), (f64, u32, f64)> = BTreeMap::new();
+    for rebar in rebars {
+      let group = groups
+        .entry((rebar.grade, rebar.diameter.to_bits()))
+        .or_insert((rebar.diameter, 0, 0.0));
+      group.1 += rebar.count;
+      group.2 += rebar.total_length;
+    }
+    let mut rows: Vec<ScheduleRow> = groups
+      .into_iter()
+      .map(|((grade, _), (diameter, count, total_length))| {
+        let total_length = total_length / 1000.0;
+        ScheduleRow {
+          grade,
+          diameter,
+          count,
+          total_length,
+          mass: total_length * linear_mass(diameter)
+        }
+      })
+      .collect();
+    rows.sort_by(|a, b| a.grade.cmp(&b.grade).then(a.diameter.total_cmp(&b.diameter)));
+    Self { rows }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.rows.is_empty()
+  }
+
+  /// Returns total mass of bars of each grade in kilograms.
+  pub fn mass_by_grade(&self) -> BTreeMap<String, f64> {
+    let mut masses = BTreeMap::new();
+    for row in &self.rows {
+      *masses.entry(row.grade.clone()).or_insert(0.0) += row.mass;
+    }
+    masses
+  }
+
+  /// Returns total mass of all bars in kilograms.
+  pub fn total_mass(&self) -> f64 {
+    self.rows.iter().map(|row| row.mass).sum()
+  }
+
+  /// Converts schedule to a [Table] with [SCHEDULE_HEADERS]. Lengths and masses are rounded to 0.01.
+  pub fn to_table(&self, name: &str) -> Table {
+    let round = |value: f64| Value::Number((value * 100.0).round() / 100.0);
+    Table {
+      name: name.to_owned(),
+      headers: SCHEDULE_HEADERS.iter().map(|header| header.to_string()).collect(),
+      rows: self
+        .rows
+        .iter()
+        .map(|row| vec![
+          Value::Text(row.grade.clone()),
+          Value::Number(row.diameter),
+          Value::Integer(row.count.into()),
+          round(row.total_length),
+          round(row.mass)
+        ])
+        .collect()
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::reinforcement::*;
+
+  #[test]
+  fn test_linear_mass() {
+    assert!((linear_mass(12.0) - 0.888).abs() < 0.001);
+    assert!((linear_mass(25.0) - 3.853).abs() < 0.001);
+  }
+
+  #[test]
+  fn test_schedule() {
+    let schedule = RebarSchedule::from_rebars([
+      Rebar::new("A500C", 16.0, 2, 4000.0),
+      Rebar::new("A240", 8.0, 10, 9000.0),
+      Rebar::new("A500C", 12.0, 4, 12000.0),
+      Rebar::new("A500C", 12.0, 2, 6000.0)
+    ]);
+    let rows: Vec<(&str, f64, u32, f64)> = schedule
+      .rows
+      .iter()
+      .map(|row| (row.grade.as_str(), row.diameter, row.count, row.total_length))
+      .collect();
+
+    assert_eq!(rows, vec![
+      ("A240", 8.0, 10, 9.0),
+      ("A500C", 12.0, 6, 18.0),
+      ("A500C", 16.0, 2, 4.0)
+    ]);
+    let masses = schedule.mass_by_grade();
+    assert!((masses["A240"] - 3.551).abs() < 0.001);
+    assert!((masses["A500C"] - 22.294).abs() < 0.001);
+    assert!((schedule.total_mass() - 25.845).abs() < 0.001);
+    assert!(RebarSchedule::from_rebars([]).is_empty());
+  }
+
+  #[test]
+  fn test_to_table() {
+    let table = RebarSchedule::from_rebars([Rebar::new("A500C", 12.0, 4, 12000.0)]).to_table("Rebars");
+
+    assert_eq!(table.name, "Rebars");
+    assert_eq!(table.headers, SCHEDULE_HEADERS);
+    assert_eq!(table.rows, vec![vec![
+      Value::Text("A500C".to_owned()),
+      Value::Number(12.0),
+      Value::Integer(4),
+      Value::Number(12.0),
+      Value::Number(10.65)
+    ]]);
+    assert_eq!(table.row(0).unwrap().get("Count"), Some(&Value::Integer(4)));
+  }
+}
@@ -0,0 +1,167 @@
+use crate::{
+  Result,
+  Error
+};
+
+/// Windows-1251 code points for bytes `0x80..=0xBF`. Bytes `0xC0..=0xFF` map to `U+0410..=U+044F`.
+const CP1251_HIGH: [u16; 64] = [
+  0x0402, 0x0403, 0x201A, 0x0453, 0x201E, 0x2026, 0x2020, 0x2021,
+  0x20AC, 0x2030, 0x0409, 0x2039, 0x040A, 0x040C, 0x040B, 0x040F,
+  0x0452, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+  0xFFFD, 0x2122, 0x0459, 0x203A, 0x045A, 0x045C, 0x045B, 0x045F,
+  0x00A0, 0x040E, 0x045E, 0x0408, 0x00A4, 0x0490, 0x00A6, 0x00A7,
+  0x0401, 0x00A9, 0x0404, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x0407,
+  0x00B0, 0x00B1, 0x0406, 0x0456, 0x0491, 0x00B5, 0x00B6, 0x00B7,
+  0x0451, 0x2116, 0x0454, 0x00BB, 0x0458, 0x0405, 0x0455, 0x0457
+];
+
+/// Decodes raw file contents.
+///
+/// Renga writes UTF-8 with BOM. UTF-16 with BOM is accepted as well, and files
+/// which are not valid UTF-8 (e.g. re-saved by spreadsheet editors) are read as Windows-1251.
+pub(crate) fn decode(bytes: &[u8]) -> String {
+  match bytes {
+    [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+    [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, u16::from_le_bytes),
+    [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, u16::from_be_bytes),
+    _ => match std::str::from_utf8(bytes) {
+      Ok(text) => text.to_owned(),
+      Err(_) => decode_cp1251(bytes)
+    }
+  }
+}
+
+fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> String {
+  let units = bytes
+    .chunks_exact(2)
+    .map(|pair| unit([pair[0], pair[1]]));
+  char::decode_utf16(units)
+    .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    .collect()
+}
+
+fn decode_cp1251(bytes: &[u8]) -> String {
+  bytes
+    .iter()
+    .map(|&byte| match byte {
+      0x00..=0x7F => byte as char,
+      0x80..=0xBF => char::from_u32(CP1251_HIGH[(byte - 0x80) as usize] as u32)
+        .unwrap_or(char::REPLACEMENT_CHARACTER),
+      0xC0..=0xFF => char::from_u32(0x0410 + (byte - 0xC0) as u32)
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+    })
+    .collect()
+}
+
+/// Guesses field delimiter from the header line: the most frequent of `;`, `,` and tab
+/// outside of quotes. Renga uses `;`, which also wins ties.
+pub(crate) fn detect_delimiter(text: &str) -> char {
+  let mut counts = [(';', 0usize), (',', 0), ('\t', 0)];
+  let mut quoted = false;
+  for c in text.chars() {
+    match c {
+      '"' => quoted = !quoted,
+      '\n' | '\r' if !quoted => break,
+      c if !quoted => {
+        if let Some(entry) = counts.iter_mut().find(|(delimiter, _)| *delimiter == c) {
+          entry.1 += 1;
+        }
+      },
+      _ => {}
+    }
+  }
+  counts
+    .iter()
+    .fold((';', 0), |best, &entry| match entry.1 > best.1 {
+      true => entry,
+      false => best
+    })
+    .0
+}
+
+/// Splits text into records of raw fields. Quoted fields may contain delimiters,
+/// line breaks and doubled quotes. Blank lines are skipped.
+pub(crate) fn parse_records(text: &str, delimiter: char) -> Result<Vec<Vec<String>>> {
+  let mut records = Vec::new();
+  let mut record = Vec::new();
+  let mut field = String::new();
+  let mut quoted = false;
+  let mut line = 1;
+  let mut chars = text.chars().peekable();
+  while let Some(c) = chars.next() {
+    if quoted {
+      match c {
+        '"' if chars.peek() == Some(&'"') => {
+          chars.next();
+          field.push('"');
+        },
+        '"' => quoted = false,
+        c => {
+          if c == '\n' {
+            line += 1;
+          }
+          field.push(c);
+        }
+      }
+      continue;
+    }
+    match c {
+      '"' if field.is_empty() => quoted = true,
+      c if c == delimiter => record.push(std::mem::take(&mut field)),
+      '\r' if chars.peek() == Some(&'\n') => {},
+      '\r' | '\n' => {
+        line += 1;
+        record.push(std::mem::take(&mut field));
+        match record.len() == 1 && record[0].is_empty() {
+          true => record.clear(),
+          false => records.push(std::mem::take(&mut record))
+        }
+      },
+      c => field.push(c)
+    }
+  }
+  if quoted {
+    return Err(Error::ParseError(format!("unterminated quoted field at line {line}")));
+  }
+  if !field.is_empty() || !record.is_empty() {
+    record.push(field);
+    records.push(record);
+  }
+  Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_decode() {
+    assert_eq!(decode(b"\xEF\xBB\xBFabc"), "abc");
+    assert_eq!(decode(b"\xFF\xFEa\x00\x16\x04"), "aЖ");
+    assert_eq!(decode(b"\xFE\xFF\x00a\x04\x16"), "aЖ");
+    assert_eq!(decode("Стена".as_bytes()), "Стена");
+    assert_eq!(decode(b"\xD1\xF2\xE5\xED\xE0 \xB9 1 \xA8\xB8"), "Стена № 1 Ёё");
+  }
+
+  #[test]
+  fn test_detect_delimiter() {
+    assert_eq!(detect_delimiter("a;b;c\n1,5;2;3"), ';');
+    assert_eq!(detect_delimiter("a,b,\"c;d;e\"\n"), ',');
+    assert_eq!(detect_delimiter("a\tb\tc"), '\t');
+    assert_eq!(detect_delimiter("a"), ';');
+  }
+
+  #[test]
+  fn test_parse_records() -> crate::Result<()> {
+    let records = parse_records("a;\"b;c\";\"d \"\"e\"\"\"\r\n\r\n1;\"2\n3\";\n", ';')?;
+
+    assert_eq!(records, vec![
+      vec!["a".to_owned(), "b;c".to_owned(), "d \"e\"".to_owned()],
+      vec!["1".to_owned(), "2\n3".to_owned(), String::new()]
+    ]);
+    assert_eq!(parse_records("x;y", ';')?, vec![vec!["x".to_owned(), "y".to_owned()]]);
+    assert!(matches!(parse_records("a;\"b\n", ';'), Err(Error::ParseError(_))));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,374 @@
+//! Tables exported by Renga to CSV files.
+//!
+//! [crate::Project::export_csv] writes one CSV file per table (schedule) to a folder.
+//! Files can be read back into typed tables:
+//! ```no_run
+//! use renga_api_rs as renga;
+//! use renga::{table, OverwritePolicy};
+//!
+//! let mut app = renga::Application::new().unwrap();
+//! let project = app.project().unwrap().unwrap();
+//! let folder = std::env::temp_dir().join("schedules");
+//! project.export_csv(&folder, OverwritePolicy::Overwrite).unwrap();
+//! for table in table::read_dir(&folder).unwrap() {
+//!   for (unique_id, row) in table.by_unique_id().unwrap() {
+//!     println!("{unique_id}: {:?}", row.get("Mark"));
+//!   }
+//! }
+//! ```
+//!
+//! Renga writes UTF-8 files with BOM, `;` as field delimiter, decimal comma and a single
+//! header row. Delimiter and encoding are detected, so files re-saved by spreadsheet
+//! editors (comma-separated, Windows-1251 or UTF-16) are read as well.
+
+mod csv;
+mod value;
+
+use std::{
+  collections::HashMap,
+  path::{
+    Path,
+    PathBuf
+  }
+};
+use crate::{
+  Result,
+  Error,
+  UUID
+};
+
+pub use value::Value;
+
+/// Headers of the column holding unique ids of objects, see [Table::by_unique_id].
+pub const UNIQUE_ID_COLUMNS: &[&str] = &[
+  "UniqueId",
+  "Unique ID",
+  "GUID",
+  "Уникальный идентификатор"
+];
+
+/// Table parsed from CSV file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Table {
+  /// Name of the table, taken from file name without extension.
+  pub name: String,
+
+  /// Column headers.
+  pub headers: Vec<String>,
+
+  /// Rows of cells. Every row has exactly `headers.len()` cells.
+  pub rows: Vec<Vec<Value>>
+}
+
+/// Borrowed row of a [Table].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Row<'a> {
+  headers: &'a [String],
+  cells: &'a [Value]
+}
+
+impl Table {
+  /// Parses table from raw file contents.
+  pub fn parse(name: &str, bytes: &[u8]) -> Result<Self> {
+    let text = csv::decode(bytes);
+    let delimiter = csv::detect_delimiter(&text);
+    let mut records = csv::parse_records(&text, delimiter)
+      .map_err(|e| Error::ParseError(format!("{name}: {e}")))?
+      .into_iter();
+    let mut headers: Vec<String> = records
+      .next()
+      .unwrap_or_default()
+      .into_iter()
+      .map(|header| header.trim().to_owned())
+      .collect();
+    while headers.last().map_or(false, String::is_empty) {
+      headers.pop();
+    }
+
+    let decimal_comma = delimiter != ',';
+    let mut rows = Vec::new();
+    for (index, mut record) in records.enumerate() {
+      if record.len() > headers.len() {
+        if record[headers.len()..].iter().any(|field| !field.trim().is_empty()) {
+          return Err(Error::ParseError(format!(
+            "{name}: row {} has {} fields, expected {}",
+            index + 1,
+            record.len(),
+            headers.len()
+          )));
+        }
+        record.truncate(headers.len());
+      }
+      let mut row: Vec<Value> = record
+        .iter()
+        .map(|field| Value::parse(field, decimal_comma))
+        .collect();
+      row.resize(headers.len(), Value::Empty);
+      rows.push(row);
+    }
+    Ok(Self {
+      name: name.to_owned(),
+      headers,
+      rows
+    })
+  }
+
+  /// Reads table from CSV file.
+  pub fn read(path: &Path) -> Result<Self> {
+    let name = path
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().into_owned())
+      .unwrap_or_default();
+    Self::parse(&name, &std::fs::read(path)?)
+  }
+
+  pub fn len(&self) -> usize {
+    self.rows.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.rows.is_empty()
+  }
+
+  /// Returns index of column with given header. Comparison is case-insensitive.
+  pub fn column(&self, header: &str) -> Option<usize> {
+    self
+      .headers
+      .iter()
+      .position(|h| h.to_lowercase() == header.trim().to_lowercase())
+  }
+
+  /// Returns index of the column with unique ids of objects, see [UNIQUE_ID_COLUMNS].
+  pub fn unique_id_column(&self) -> Option<usize> {
+    UNIQUE_ID_COLUMNS
+      .iter()
+      .find_map(|header| self.column(header))
+  }
+
+  pub fn row(&self, index: usize) -> Option<Row<'_>> {
+    self
+      .rows
+      .get(index)
+      .map(|cells| Row { headers: &self.headers, cells })
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = Row<'_>> {
+    self
+      .rows
+      .iter()
+      .map(|cells| Row { headers: &self.headers, cells })
+  }
+
+  /// Returns rows keyed by unique ids of objects. Rows without id (e.g. totals) are skipped.
+  ///
+  /// Fails if table has no unique id column, an id can not be parsed or is repeated.
+  pub fn by_unique_id(&self) -> Result<HashMap<UUID, Row<'_>>> {
+    let column = self
+      .unique_id_column()
+      .ok_or_else(|| Error::ParseError(format!("{}: no unique id column", self.name)))?;
+    let mut rows = HashMap::new();
+    for row in self.iter() {
+      let text = row.cells[column].to_string();
+      if text.is_empty() {
+        continue;
+      }
+      let id = text
+        .trim_matches(['{', '}'])
+        .parse()
+        .map_err(|_| Error::ParseError(format!("{}: invalid unique id {text}", self.name)))?;
+      if rows.insert(id, row).is_some() {
+        return Err(Error::ParseError(format!("{}: duplicate unique id {text}", self.name)));
+      }
+    }
+    Ok(rows)
+  }
+
+  /// Converts rows to records. Fields are matched by column headers,
+  /// use `#[serde(rename = "...")]` for headers which are not valid identifiers.
+  #[cfg(feature="serde")]
+  pub fn records<T: serde::de::DeserializeOwned>(&self) -> Result<Vec<T>> {
+    self
+      .iter()
+      .map(|row| row.record())
+      .collect()
+  }
+}
+
+impl<'a> Row<'a> {
+  /// Returns cell by column header. Comparison is case-insensitive.
+  pub fn get(&self, header: &str) -> Option<&'a Value> {
+    self
+      .headers
+      .iter()
+      .position(|h| h.to_lowercase() == header.trim().to_lowercase())
+      .map(|index| &self.cells[index])
+  }
+
+  pub fn cells(&self) -> &'a [Value] {
+    self.cells
+  }
+
+  /// Returns pairs of column headers and cells.
+  pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a Value)> {
+    self
+      .headers
+      .iter()
+      .map(String::as_str)
+      .zip(self.cells.iter())
+  }
+
+  /// Converts row to record, see [Table::records].
+  #[cfg(feature="serde")]
+  pub fn record<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+    let object: serde_json::Map<String, serde_json::Value> = self
+      .iter()
+      .map(|(header, value)| Ok((header.to_owned(), serde_json::to_value(value)?)))
+      .collect::<std::result::Result<_, serde_json::Error>>()
+      .map_err(|e| Error::ParseError(e.to_string()))?;
+    serde_json::from_value(serde_json::Value::Object(object))
+      .map_err(|e| Error::ParseError(e.to_string()))
+  }
+}
+
+/// Returns paths of CSV files in directory, sorted by name.
+pub(crate) fn csv_files(dir: &Path) -> Result<Vec<PathBuf>> {
+  let mut files = Vec::new();
+  for entry in std::fs::read_dir(dir)? {
+    let path = entry?.path();
+    let is_csv = path
+      .extension()
+      .map_or(false, |ext| ext.eq_ignore_ascii_case("csv"));
+    if is_csv && path.is_file() {
+      files.push(path);
+    }
+  }
+  files.sort();
+  Ok(files)
+}
+
+/// Reads all CSV files in directory, sorted by name.
+pub fn read_dir(dir: &Path) -> Result<Vec<Table>> {
+  csv_files(dir)?
+    .iter()
+    .map(|path| Table::read(path))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use test_context::test_context;
+  use crate::*;
+  use crate::table::*;
+
+  #[test]
+  fn test_read_utf8() -> anyhow::Result<()> {
+    let table = Table::read(&external_file("walls.csv")?)?;
+
+    assert_eq!(table.name, "walls");
+    assert_eq!(table.headers.len(), 6);
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.unique_id_column(), Some(0));
+
+    let row = table.row(0).unwrap();
+    assert_eq!(row.get("марка"), Some(&Value::Text("С-1".to_owned())));
+    assert_eq!(row.get("Высота"), Some(&Value::Number(3000.5)));
+    assert_eq!(row.get("Количество"), Some(&Value::Integer(2)));
+    assert_eq!(row.get("Комментарий").and_then(Value::as_str), Some("Несущая; наружная"));
+
+    let row = table.row(1).unwrap();
+    assert_eq!(row.get("Марка"), Some(&Value::Text("007".to_owned())));
+    assert_eq!(row.get("Комментарий").and_then(Value::as_str), Some("Стена \"тип 2\""));
+
+    let row = table.row(2).unwrap();
+    assert!(row.cells()[..4].iter().all(Value::is_empty));
+    assert_eq!(row.get("Количество"), Some(&Value::Integer(3)));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_by_unique_id() -> anyhow::Result<()> {
+    let table = Table::read(&external_file("walls.csv")?)?;
+    let rows = table.by_unique_id()?;
+    let id: UUID = "0B7C2A4E-1F3D-4A6B-8C9D-7E6F5A4B3C2D".parse()?;
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[&id].get("Длина"), Some(&Value::Number(1200.25)));
+    assert!(Table::parse("empty", b"Mark;Count\nA;1\n")?.by_unique_id().is_err());
+    let duplicates = b"GUID;Mark\n{0B7C2A4E-1F3D-4A6B-8C9D-7E6F5A4B3C2D};A\n0b7c2a4e-1f3d-4a6b-8c9d-7e6f5a4b3c2d;B\n";
+    assert!(matches!(Table::parse("duplicates", duplicates)?.by_unique_id(), Err(Error::ParseError(_))));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_cp1251() -> anyhow::Result<()> {
+    let table = Table::read(&external_file("doors_cp1251.csv")?)?;
+
+    assert_eq!(table.headers, vec!["GUID", "Марка", "Ширина", "Высота"]);
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.row(1).unwrap().get("Марка"), Some(&Value::Text("ДГ 21-9".to_owned())));
+    assert_eq!(table.row(1).unwrap().get("ширина"), Some(&Value::Integer(900)));
+    assert_eq!(table.by_unique_id()?.len(), 2);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_parse_comma_separated() -> anyhow::Result<()> {
+    let table = Table::parse("t", b"Name,Area,,\n\"A, B\",12.5,,\nC,3\n")?;
+
+    assert_eq!(table.headers, vec!["Name", "Area"]);
+    assert_eq!(table.rows, vec![
+      vec![Value::Text("A, B".to_owned()), Value::Number(12.5)],
+      vec![Value::Text("C".to_owned()), Value::Integer(3)]
+    ]);
+    assert!(matches!(Table::parse("t", b"Name,Area\nA,1,2\n"), Err(Error::ParseError(_))));
+
+    Ok(())
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_records() -> anyhow::Result<()> {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Wall {
+      #[serde(rename = "Марка")]
+      mark: Option<String>,
+      #[serde(rename = "Длина")]
+      length: Option<f64>,
+      #[serde(rename = "Количество")]
+      count: i64
+    }
+
+    let table = Table::read(&external_file("walls.csv")?)?;
+    let walls: Vec<Wall> = table.records()?;
+
+    assert_eq!(walls.len(), 3);
+    assert_eq!(walls[0], Wall { mark: Some("С-1".to_owned()), length: Some(5000.0), count: 2 });
+    assert_eq!(walls[2], Wall { mark: None, length: None, count: 3 });
+
+    Ok(())
+  }
+
+  #[test_context(ProjectContext)]
+  #[test]
+  fn test_export_csv(ctx: &mut ProjectContext) -> anyhow::Result<()> {
+    let folder = std::env::temp_dir().join("renga-api-rs-csv");
+    std::fs::create_dir_all(&folder)?;
+    ctx.project.export_csv(&folder, OverwritePolicy::Overwrite)?;
+
+    for table in read_dir(&folder)? {
+      assert!(!table.headers.is_empty());
+    }
+    assert_eq!(
+      ctx.project.export_csv(&folder, OverwritePolicy::Skip)?,
+      match read_dir(&folder)?.is_empty() {
+        true => ExportStatus::Exported,
+        false => ExportStatus::Skipped
+      }
+    );
+
+    Ok(())
+  }
+}
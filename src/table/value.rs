@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+#[cfg(feature="serde")]
+use serde::{
+  Deserialize,
+  Serialize
+};
+
+/// Typed value of a table cell.
+///
+/// With `serde` feature enabled, values are (de)serialized as plain JSON values:
+/// `null`, numbers or strings.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature="serde", serde(untagged))]
+pub enum Value {
+  #[default]
+  Empty,
+  Integer(i64),
+  Number(f64),
+  Text(String)
+}
+
+impl Value {
+  /// Parses raw cell text.
+  ///
+  /// Numbers with decimal comma are recognized if `decimal_comma` is set. Values with
+  /// leading zeros (e.g. marks like `007`) are kept as text.
+  pub fn parse(raw: &str, decimal_comma: bool) -> Self {
+    let text = raw.trim();
+    if text.is_empty() {
+      return Self::Empty;
+    }
+    let digits = text.trim_start_matches(['-', '+']);
+    if digits.len() > 1 && digits.starts_with('0') && !digits[1..].starts_with(['.', ',']) {
+      return Self::Text(text.to_owned());
+    }
+    if let Ok(value) = text.parse::<i64>() {
+      return Self::Integer(value);
+    }
+    let numeric = text
+      .chars()
+      .all(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E') || (decimal_comma && c == ','));
+    if numeric && text.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.')) {
+      let normalized = match decimal_comma {
+        true => text.replace(',', "."),
+        false => text.to_owned()
+      };
+      if let Ok(value) = normalized.parse::<f64>() {
+        return Self::Number(value);
+      }
+    }
+    Self::Text(text.to_owned())
+  }
+
+  pub fn is_empty(&self) -> bool {
+    matches!(self, Self::Empty)
+  }
+
+  /// Returns integer value. Numbers are not truncated.
+  pub fn as_i64(&self) -> Option<i64> {
+    match self {
+      Self::Integer(value) => Some(*value),
+      _ => None
+    }
+  }
+
+  /// Returns numeric value, converting integers.
+  pub fn as_f64(&self) -> Option<f64> {
+    match self {
+      Self::Integer(value) => Some(*value as f64),
+      Self::Number(value) => Some(*value),
+      _ => None
+    }
+  }
+
+  /// Returns text value. Numbers are not converted, use [Display] instead.
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      Self::Text(value) => Some(value),
+      _ => None
+    }
+  }
+}
+
+impl Display for Value {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Empty => Ok(()),
+      Self::Integer(value) => write!(f, "{value}"),
+      Self::Number(value) => write!(f, "{value}"),
+      Self::Text(value) => write!(f, "{value}")
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::table::*;
+
+  #[test]
+  fn test_parse() {
+    assert_eq!(Value::parse("  ", true), Value::Empty);
+    assert_eq!(Value::parse("42", true), Value::Integer(42));
+    assert_eq!(Value::parse("-7", false), Value::Integer(-7));
+    assert_eq!(Value::parse("3000,5", true), Value::Number(3000.5));
+    assert_eq!(Value::parse("3000,5", false), Value::Text("3000,5".to_owned()));
+    assert_eq!(Value::parse("0,25", true), Value::Number(0.25));
+    assert_eq!(Value::parse("1.5e3", false), Value::Number(1500.0));
+    assert_eq!(Value::parse("007", true), Value::Text("007".to_owned()));
+    assert_eq!(Value::parse("0", true), Value::Integer(0));
+    assert_eq!(Value::parse("inf", false), Value::Text("inf".to_owned()));
+    assert_eq!(Value::parse("e5", false), Value::Text("e5".to_owned()));
+    assert_eq!(Value::parse("С-1", true), Value::Text("С-1".to_owned()));
+  }
+
+  #[test]
+  fn test_accessors() {
+    assert_eq!(Value::Integer(2).as_f64(), Some(2.0));
+    assert_eq!(Value::Number(2.5).as_i64(), None);
+    assert_eq!(Value::Text("a".to_owned()).as_str(), Some("a"));
+    assert_eq!(Value::Number(2.5).to_string(), "2.5");
+    assert!(Value::Empty.is_empty());
+  }
+}
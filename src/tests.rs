@@ -3,7 +3,8 @@ use std::path::PathBuf;
 use test_context::TestContext;
 use crate::{
   Application, 
-  Project
+  Project,
+  UUID
 };
 
 pub struct RengaContext {
@@ -27,6 +28,11 @@ pub fn external_file(name: &str) -> anyhow::Result<PathBuf> {
   }
 }
 
+/// Returns unique identifier, which is distinct for each `index`.
+pub fn uuid(index: usize) -> UUID {
+  format!("00000000-0000-0000-0000-{index:012}").parse().unwrap()
+}
+
 impl TestContext for RengaContext {
   fn setup() -> Self {
     let app = Application::new_hidden().unwrap();
@@ -0,0 +1,83 @@
+use std::path::Path;
+use crate::{
+  Result,
+  Error
+};
+
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+/// PNG image used as an icon of actions, drop-down buttons and submenus.
+///
+/// Only header of the image is validated. Renga scales icons to the size of a button,
+/// square images of 24x24 pixels or larger look best.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+  png: Vec<u8>,
+  width: u32,
+  height: u32
+}
+
+impl Icon {
+  /// Creates icon from contents of a PNG file.
+  ///
+  /// Returns [crate::Error::ParseError] if data is not a PNG image.
+  pub fn from_png(data: impl Into<Vec<u8>>) -> Result<Self> {
+    let png = data.into();
+    if png.len() < 24 || !png.starts_with(PNG_SIGNATURE) || &png[12..16] != b"IHDR" {
+      return Err(Error::ParseError("Icon is not a PNG image".to_owned()));
+    }
+    let dimension = |offset: usize| u32::from_be_bytes([png[offset], png[offset + 1], png[offset + 2], png[offset + 3]]);
+    let (width, height) = (dimension(16), dimension(20));
+    if width == 0 || height == 0 {
+      return Err(Error::ParseError(format!("Icon has invalid size {width}x{height}")));
+    }
+    Ok(Self { png, width, height })
+  }
+
+  /// Loads icon from PNG file.
+  pub fn from_file(path: &Path) -> Result<Self> {
+    if !path.exists() {
+      return Err(Error::NonexistentPath(path.display().to_string()));
+    }
+    Self::from_png(std::fs::read(path)?)
+  }
+
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+
+  /// Returns contents of the PNG file.
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.png
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::*;
+  use crate::ui::Icon;
+
+  #[test]
+  fn test_from_file() -> anyhow::Result<()> {
+    let icon = Icon::from_file(&external_file("icon.png")?)?;
+
+    assert_eq!((icon.width(), icon.height()), (24, 24));
+    assert_eq!(&icon.as_bytes()[1..4], b"PNG");
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_invalid() {
+    assert!(Icon::from_png(b"GIF89a".to_vec()).is_err());
+    assert!(Icon::from_png(Vec::new()).is_err());
+
+    let mut header = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    header.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 24]);
+    assert!(Icon::from_png(header).is_err());
+  }
+}
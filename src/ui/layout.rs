@@ -0,0 +1,394 @@
+use std::collections::HashSet;
+use crate::{
+  Result,
+  Error,
+  ViewType,
+  UUID
+};
+use super::Icon;
+
+/// Description of a UI action: command, which can be placed on panels and in context menus.
+///
+/// Action is referenced from panels and menus by its `id`, which is also passed to the event
+/// handler of [crate::UI::install].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionSpec {
+  pub id: String,
+  pub display_name: String,
+  pub tooltip: Option<String>,
+  pub icon: Option<Icon>,
+  pub enabled: bool,
+
+  /// `Some` for toggle actions, with the initial state.
+  pub checked: Option<bool>
+}
+
+/// Item of a [Menu].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuItem {
+  /// Action with given id.
+  Action(String),
+  Submenu {
+    display_name: String,
+    icon: Option<Icon>,
+    menu: Menu
+  },
+  Separator
+}
+
+/// Context menu, or a submenu of it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Menu {
+  pub items: Vec<MenuItem>
+}
+
+/// Drop-down button of a [Panel], which shows a list of actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropDown {
+  pub display_name: String,
+  pub tooltip: Option<String>,
+  pub icon: Option<Icon>,
+
+  /// Action ids. `None` stands for a separator.
+  pub items: Vec<Option<String>>
+}
+
+/// Item of a [Panel].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PanelItem {
+  /// Tool button of action with given id.
+  Button(String),
+  DropDown(DropDown)
+}
+
+/// Extension of a toolbar panel: the primary panel or the actions panel of a view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Panel {
+  pub items: Vec<PanelItem>
+}
+
+/// Case in which context menu is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContextMenuShowCase {
+  /// Right click on empty space of a view.
+  Scene,
+
+  /// Right click on selected objects.
+  Selection
+}
+
+/// Context menu of a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextMenu {
+  /// Unique id of the menu. Should be constant between runs.
+  pub id: UUID,
+  pub view_type: ViewType,
+  pub show_case: ContextMenuShowCase,
+  pub menu: Menu
+}
+
+/// Complete UI extension: actions and their placement on panels and in context menus.
+///
+/// Layout is a plain data model and can be built and validated without Renga. It is
+/// installed into Renga with [crate::UI::install]:
+/// ```no_run
+/// use renga_api_rs as renga;
+/// use renga::ui::{ActionSpec, ContextMenu, ContextMenuShowCase, DropDown, Icon, Layout, Menu, Panel};
+///
+/// let icon = Icon::from_file(std::path::Path::new("export.png")).unwrap();
+/// let layout = Layout::new()
+///   .action(ActionSpec::new("export", "Export").with_icon(icon))
+///   .action(ActionSpec::new("report", "Report").with_tooltip("Show report"))
+///   .primary_panel(Panel::new()
+///     .button("export")
+///     .drop_down(DropDown::new("More").action("report")))
+///   .context_menu(ContextMenu::new(
+///     "5C0F8A16-3A43-4E25-9C41-5E8B1A3C6F20".parse().unwrap(),
+///     renga::ViewType::View3D,
+///     ContextMenuShowCase::Selection,
+///     Menu::new().action("export").separator().action("report")
+///   ));
+///
+/// let app = renga::Application::new().unwrap();
+/// let _extension = app.ui().unwrap().install(&layout, |id, event| println!("{id}: {event:?}")).unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Layout {
+  pub actions: Vec<ActionSpec>,
+  pub primary_panel: Panel,
+  pub action_panels: Vec<(ViewType, Panel)>,
+  pub context_menus: Vec<ContextMenu>
+}
+
+impl ActionSpec {
+  /// Creates enabled, non-checkable action.
+  pub fn new(id: &str, display_name: &str) -> Self {
+    Self {
+      id: id.to_owned(),
+      display_name: display_name.to_owned(),
+      tooltip: None,
+      icon: None,
+      enabled: true,
+      checked: None
+    }
+  }
+
+  pub fn with_tooltip(mut self, tooltip: &str) -> Self {
+    self.tooltip = Some(tooltip.to_owned());
+    self
+  }
+
+  pub fn with_icon(mut self, icon: Icon) -> Self {
+    self.icon = Some(icon);
+    self
+  }
+
+  pub fn with_enabled(mut self, enabled: bool) -> Self {
+    self.enabled = enabled;
+    self
+  }
+
+  /// Makes action checkable, with given initial state.
+  pub fn with_checked(mut self, checked: bool) -> Self {
+    self.checked = Some(checked);
+    self
+  }
+
+  pub fn is_checkable(&self) -> bool {
+    self.checked.is_some()
+  }
+}
+
+impl Menu {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn action(mut self, id: &str) -> Self {
+    self.items.push(MenuItem::Action(id.to_owned()));
+    self
+  }
+
+  pub fn separator(mut self) -> Self {
+    self.items.push(MenuItem::Separator);
+    self
+  }
+
+  pub fn submenu(mut self, display_name: &str, icon: Option<Icon>, menu: Menu) -> Self {
+    self.items.push(MenuItem::Submenu {
+      display_name: display_name.to_owned(),
+      icon,
+      menu
+    });
+    self
+  }
+
+  /// Returns ids of all actions of the menu and its submenus.
+  pub fn action_ids(&self) -> Vec<&str> {
+    self
+      .items
+      .iter()
+      .flat_map(|item| match item {
+        MenuItem::Action(id) => vec![id.as_str()],
+        MenuItem::Submenu { menu, .. } => menu.action_ids(),
+        MenuItem::Separator => vec![]
+      })
+      .collect()
+  }
+}
+
+impl DropDown {
+  pub fn new(display_name: &str) -> Self {
+    Self {
+      display_name: display_name.to_owned(),
+      tooltip: None,
+      icon: None,
+      items: Vec::new()
+    }
+  }
+
+  pub fn with_tooltip(mut self, tooltip: &str) -> Self {
+    self.tooltip = Some(tooltip.to_owned());
+    self
+  }
+
+  pub fn with_icon(mut self, icon: Icon) -> Self {
+    self.icon = Some(icon);
+    self
+  }
+
+  pub fn action(mut self, id: &str) -> Self {
+    self.items.push(Some(id.to_owned()));
+    self
+  }
+
+  pub fn separator(mut self) -> Self {
+    self.items.push(None);
+    self
+  }
+}
+
+impl Panel {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn button(mut self, id: &str) -> Self {
+    self.items.push(PanelItem::Button(id.to_owned()));
+    self
+  }
+
+  pub fn drop_down(mut self, drop_down: DropDown) -> Self {
+    self.items.push(PanelItem::DropDown(drop_down));
+    self
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+
+  /// Returns ids of all actions of the panel, including drop-down buttons.
+  pub fn action_ids(&self) -> Vec<&str> {
+    self
+      .items
+      .iter()
+      .flat_map(|item| match item {
+        PanelItem::Button(id) => vec![id.as_str()],
+        PanelItem::DropDown(drop_down) => drop_down.items.iter().flatten().map(String::as_str).collect()
+      })
+      .collect()
+  }
+}
+
+impl ContextMenu {
+  pub fn new(id: UUID, view_type: ViewType, show_case: ContextMenuShowCase, menu: Menu) -> Self {
+    Self {
+      id,
+      view_type,
+      show_case,
+      menu
+    }
+  }
+}
+
+impl Layout {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn action(mut self, action: ActionSpec) -> Self {
+    self.actions.push(action);
+    self
+  }
+
+  pub fn primary_panel(mut self, panel: Panel) -> Self {
+    self.primary_panel = panel;
+    self
+  }
+
+  /// Adds panel to the actions panel of views of given type.
+  pub fn action_panel(mut self, view_type: ViewType, panel: Panel) -> Self {
+    self.action_panels.push((view_type, panel));
+    self
+  }
+
+  pub fn context_menu(mut self, menu: ContextMenu) -> Self {
+    self.context_menus.push(menu);
+    self
+  }
+
+  /// Returns action with given id.
+  pub fn get_action(&self, id: &str) -> Option<&ActionSpec> {
+    self
+      .actions
+      .iter()
+      .find(|action| action.id == id)
+  }
+
+  /// Checks that the layout can be installed.
+  ///
+  /// Returns [crate::Error::InvalidOperation] if action ids are empty or not unique,
+  /// display names are empty, or panels and menus reference undeclared actions.
+  pub fn validate(&self) -> Result<()> {
+    let mut ids = HashSet::new();
+    for action in &self.actions {
+      if action.id.is_empty() || action.display_name.is_empty() {
+        return Err(Error::InvalidOperation(format!("Action `{}` has empty id or display name", action.id)));
+      }
+      if !ids.insert(action.id.as_str()) {
+        return Err(Error::InvalidOperation(format!("Duplicate action id `{}`", action.id)));
+      }
+    }
+    let panels = std::iter::once(&self.primary_panel)
+      .chain(self.action_panels.iter().map(|(_, panel)| panel));
+    let referenced = panels
+      .flat_map(Panel::action_ids)
+      .chain(self.context_menus.iter().flat_map(|menu| menu.menu.action_ids()));
+    for id in referenced {
+      if !ids.contains(id) {
+        return Err(Error::InvalidOperation(format!("Unknown action `{id}`")));
+      }
+    }
+    let mut menus = HashSet::new();
+    for menu in &self.context_menus {
+      if !menus.insert(&menu.id) {
+        return Err(Error::InvalidOperation(format!("Duplicate context menu id {}", menu.id)));
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::*;
+  use crate::ui::*;
+
+  fn layout() -> Layout {
+    Layout::new()
+      .action(ActionSpec::new("export", "Export").with_tooltip("Export model"))
+      .action(ActionSpec::new("grid", "Show grid").with_checked(true))
+      .action(ActionSpec::new("report", "Report").with_enabled(false))
+      .primary_panel(Panel::new()
+        .button("export")
+        .drop_down(DropDown::new("More").action("grid").separator().action("report")))
+      .action_panel(ViewType::Level, Panel::new().button("grid"))
+      .context_menu(ContextMenu::new(
+        "5C0F8A16-3A43-4E25-9C41-5E8B1A3C6F20".parse().unwrap(),
+        ViewType::View3D,
+        ContextMenuShowCase::Selection,
+        Menu::new()
+          .action("export")
+          .separator()
+          .submenu("Tools", None, Menu::new().action("report"))
+      ))
+  }
+
+  #[test]
+  fn test_build() {
+    let layout = layout();
+
+    assert_eq!(layout.primary_panel.action_ids(), vec!["export", "grid", "report"]);
+    assert_eq!(layout.context_menus[0].menu.action_ids(), vec!["export", "report"]);
+    assert!(layout.get_action("grid").unwrap().is_checkable());
+    assert!(!layout.get_action("report").unwrap().enabled);
+    assert!(layout.get_action("missing").is_none());
+    assert!(layout.validate().is_ok());
+  }
+
+  #[test]
+  fn test_validate() {
+    let unknown = layout().primary_panel(Panel::new().button("missing"));
+    assert!(matches!(unknown.validate(), Err(Error::InvalidOperation(_))));
+
+    let duplicate = layout().action(ActionSpec::new("export", "Export again"));
+    assert!(duplicate.validate().is_err());
+
+    let empty = Layout::new().action(ActionSpec::new("empty", ""));
+    assert!(empty.validate().is_err());
+
+    let mut menus = layout();
+    menus.context_menus.push(menus.context_menus[0].clone());
+    assert!(menus.validate().is_err());
+  }
+}
@@ -0,0 +1,21 @@
+//! Layout of UI extensions: actions, toolbar panels and context menus.
+//!
+//! [Layout] describes what a plugin adds to Renga user interface. It is pure data and can be
+//! built, validated and tested without Renga. [crate::UI::install] creates the actions,
+//! places them and routes their events to a single handler, keyed by action id.
+
+mod icon;
+mod layout;
+
+pub use icon::Icon;
+pub use layout::{
+  ActionSpec,
+  ContextMenu,
+  ContextMenuShowCase,
+  DropDown,
+  Layout,
+  Menu,
+  MenuItem,
+  Panel,
+  PanelItem
+};